@@ -1,60 +1,181 @@
 use std::{
     collections::{HashMap, HashSet},
-    fmt, io,
-    net::SocketAddr,
-    sync::Arc,
-    time::{Duration, SystemTime},
+    fmt,
+    io::{self, Write},
+    net::{IpAddr, SocketAddr},
+    path::{Path as FsPath, PathBuf},
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant, SystemTime},
 };
 
 use axum::{
     Json, Router,
     extract::{
-        Path, State,
-        ws::{Message, WebSocket, WebSocketUpgrade},
+        ConnectInfo, Path, Query, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderMap, StatusCode, header},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
     },
-    http::StatusCode,
-    response::{IntoResponse, Response},
-    routing::{get, post},
+    routing::{get, patch, post},
 };
-use futures::{SinkExt, StreamExt};
-use rand::{Rng, distributions::Alphanumeric, seq::SliceRandom, thread_rng};
-use serde::{Deserialize, Serialize};
+use flate2::{Compression, write::GzEncoder};
+use futures::{Sink, SinkExt, StreamExt};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom, thread_rng};
+use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
-use tokio::sync::{RwLock, broadcast};
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
-use tracing::{info, warn};
+use tokio::{
+    sync::{RwLock, broadcast},
+    task::AbortHandle,
+};
+use tower_http::{
+    cors::CorsLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
+use tracing::{Instrument, Span, info, info_span, warn};
 use uuid::Uuid;
 
 type SharedState = Arc<AppState>;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct LocationDefinition {
     id: u32,
     name: String,
     roles: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl LocationDefinition {
+    fn has_any_tag(&self, tags: &[String]) -> bool {
+        self.tags
+            .iter()
+            .any(|tag| tags.iter().any(|wanted| wanted.eq_ignore_ascii_case(tag)))
+    }
+
+    /// Whether every civilian in a lobby of `player_count` can be handed a role.
+    fn supports(&self, player_count: usize, allow_duplicate_roles: bool) -> bool {
+        if allow_duplicate_roles {
+            !self.roles.is_empty()
+        } else {
+            self.roles.len() + 1 >= player_count
+        }
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct QuestionPrompt {
     id: String,
     text: String,
     categories: Vec<String>,
 }
 
+/// Relative weight given to questions that share a category with the previous
+/// question when `diversify_questions` is enabled.
+const REPEAT_CATEGORY_WEIGHT: f64 = 0.25;
+
+/// Upper bound for `GameRules::max_rounds`.
+const MAX_ROUNDS_LIMIT: u32 = 100;
+
+/// Upper bound for `GameRules::max_questions_per_round`.
+const MAX_QUESTIONS_PER_ROUND_LIMIT: u32 = 200;
+
+/// Upper bound for any single `ScoringRules` award.
+const MAX_POINTS_PER_OUTCOME: u32 = 100;
+
+/// How long the crew has to accuse someone once the question cap is spent.
+const FORCED_VOTE_WINDOW: Duration = Duration::from_secs(30);
+
+/// How long a wrongly accused imposter has to guess the location (or the crew
+/// to accuse again) when `imposter_last_chance` is enabled.
+const LAST_CHANCE_WINDOW: Duration = Duration::from_secs(20);
+
+/// Upper bound for a single entry in `GameRules::category_weights`.
+const MAX_CATEGORY_WEIGHT: u32 = 100;
+
 #[derive(Clone)]
 struct GameContent {
+    /// Only the lobby's own entries when `base` is set; read through
+    /// `locations()` and `questions()` to see everything.
     locations: Vec<LocationDefinition>,
     questions: Vec<QuestionPrompt>,
+    /// Lowercased category keys, used for every comparison.
     categories: Vec<String>,
+    /// Lowercased key to the spelling it was first written with.
+    category_labels: HashMap<String, String>,
+    /// Server-wide ceiling on `max_players`, however large a location is.
+    player_cap: u8,
+    /// Other locales' content; only filled on the shared default content.
+    translations: HashMap<String, Arc<GameContent>>,
+    /// The shared catalog a lobby's custom content extends.
+    base: Option<Arc<GameContent>>,
+}
+
+const DEFAULT_LOCALE: &str = "en";
+
+/// Embedded translations as `(locale, locations, questions)`, matched by id.
+const TRANSLATIONS: &[(&str, &str, &str)] = &[(
+    "es",
+    include_str!("../data/locations.es.json"),
+    include_str!("../data/questions.es.json"),
+)];
+
+#[derive(Deserialize)]
+struct LocationTranslation {
+    id: u32,
+    name: String,
+    roles: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct QuestionTranslation {
+    id: String,
+    text: String,
+}
+
+/// Compares secrets without exiting at the first differing byte, so response
+/// timing does not reveal how much of a guess was right.
+fn constant_time_eq(expected: &[u8], provided: &[u8]) -> bool {
+    expected.len() == provided.len()
+        && expected
+            .iter()
+            .zip(provided)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Resolves a locale to one with content, falling back to its primary subtag.
+fn normalize_locale(requested: &str) -> Result<String, AppError> {
+    let requested = requested.trim().to_lowercase().replace('_', "-");
+    if requested.is_empty() {
+        return Ok(DEFAULT_LOCALE.to_owned());
+    }
+    let primary = requested.split('-').next().unwrap_or_default();
+    std::iter::once(DEFAULT_LOCALE)
+        .chain(TRANSLATIONS.iter().map(|(locale, _, _)| *locale))
+        .find(|locale| *locale == requested || *locale == primary)
+        .map(str::to_owned)
+        .ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::UnknownLocale,
+                format!("unknown locale: {}", requested),
+            )
+        })
 }
 
 impl GameContent {
     fn load() -> Result<Self, AppError> {
         let locations: Vec<LocationDefinition> =
-            serde_json::from_str(include_str!("../data/locations.json"))
+            serde_json::from_str(include_str!("../data/locations.en.json"))
                 .map_err(|err| AppError::Unexpected(Box::new(err)))?;
         let questions: Vec<QuestionPrompt> =
-            serde_json::from_str(include_str!("../data/questions.json"))
+            serde_json::from_str(include_str!("../data/questions.en.json"))
                 .map_err(|err| AppError::Unexpected(Box::new(err)))?;
 
         if locations.is_empty() {
@@ -70,32 +191,186 @@ impl GameContent {
             ))));
         }
 
-        let mut categories: Vec<String> = questions
+        let categories = Self::collect_categories(&questions);
+        let category_labels = Self::collect_category_labels(&questions);
+
+        let mut content = Self {
+            locations,
+            questions,
+            categories,
+            category_labels,
+            player_cap: DEFAULT_PLAYER_CAP,
+            translations: HashMap::new(),
+            base: None,
+        };
+        for (locale, locations, questions) in TRANSLATIONS {
+            let locations: Vec<LocationTranslation> = serde_json::from_str(locations)
+                .map_err(|err| AppError::Unexpected(Box::new(err)))?;
+            let questions: Vec<QuestionTranslation> = serde_json::from_str(questions)
+                .map_err(|err| AppError::Unexpected(Box::new(err)))?;
+            let translated = content.translated(locations, questions);
+            content
+                .translations
+                .insert((*locale).to_owned(), Arc::new(translated));
+        }
+        Ok(content)
+    }
+
+    /// This content with translated text; ids, categories, tags, and role
+    /// counts stay put so rules hold across locales.
+    fn translated(
+        &self,
+        locations: Vec<LocationTranslation>,
+        questions: Vec<QuestionTranslation>,
+    ) -> Self {
+        let mut translated = Self {
+            translations: HashMap::new(),
+            ..self.clone()
+        };
+        for entry in locations {
+            let Some(location) = translated
+                .locations
+                .iter_mut()
+                .find(|location| location.id == entry.id)
+            else {
+                continue;
+            };
+            location.name = entry.name;
+            if entry.roles.len() == location.roles.len() {
+                location.roles = entry.roles;
+            }
+        }
+        for entry in questions {
+            if let Some(question) = translated
+                .questions
+                .iter_mut()
+                .find(|question| question.id == entry.id)
+            {
+                question.text = entry.text;
+            }
+        }
+        translated
+    }
+
+    /// The content a lobby playing in `locale` draws from.
+    fn for_locale(self: &Arc<Self>, locale: &str) -> Arc<Self> {
+        self.translations
+            .get(locale)
+            .cloned()
+            .unwrap_or_else(|| Arc::clone(self))
+    }
+
+    fn with_player_cap(mut self, cap: u8) -> Self {
+        self.player_cap = cap.max(MIN_PLAYER_CAP);
+        for translation in self.translations.values_mut() {
+            Arc::make_mut(translation).player_cap = self.player_cap;
+        }
+        self
+    }
+
+    fn locations(&self) -> impl Iterator<Item = &LocationDefinition> + Clone {
+        self.base
+            .iter()
+            .flat_map(|base| base.locations.iter())
+            .chain(&self.locations)
+    }
+
+    fn questions(&self) -> impl Iterator<Item = &QuestionPrompt> + Clone {
+        self.base
             .iter()
+            .flat_map(|base| base.questions.iter())
+            .chain(&self.questions)
+    }
+
+    fn collect_categories<'a>(
+        questions: impl IntoIterator<Item = &'a QuestionPrompt>,
+    ) -> Vec<String> {
+        let mut categories: Vec<String> = questions
+            .into_iter()
             .flat_map(|question| question.categories.iter().cloned())
             .map(|value| value.to_lowercase())
             .collect();
         categories.sort();
         categories.dedup();
+        categories
+    }
 
-        Ok(Self {
-            locations,
-            questions,
+    /// Questions tagged with any of `categories`, or every question when empty.
+    fn questions_in(&self, categories: &[String]) -> Vec<&QuestionPrompt> {
+        let normalized_categories: HashSet<String> = categories
+            .iter()
+            .map(|value| value.to_lowercase())
+            .collect();
+        self.questions()
+            .filter(|question| {
+                normalized_categories.is_empty()
+                    || question
+                        .categories
+                        .iter()
+                        .any(|category| normalized_categories.contains(&category.to_lowercase()))
+            })
+            .collect()
+    }
+
+    fn collect_category_labels<'a>(
+        questions: impl IntoIterator<Item = &'a QuestionPrompt>,
+    ) -> HashMap<String, String> {
+        let mut labels = HashMap::new();
+        for category in questions
+            .into_iter()
+            .flat_map(|question| &question.categories)
+        {
+            labels
+                .entry(category.to_lowercase())
+                .or_insert_with(|| category.trim().to_owned());
+        }
+        labels
+    }
+
+    fn with_custom(
+        self: &Arc<Self>,
+        custom_questions: &[QuestionPrompt],
+        custom_locations: &[LocationDefinition],
+    ) -> Self {
+        let questions = self.questions().chain(custom_questions);
+        let categories = Self::collect_categories(questions.clone());
+        let category_labels = Self::collect_category_labels(questions);
+        Self {
+            locations: custom_locations.to_vec(),
+            questions: custom_questions.to_vec(),
             categories,
-        })
+            category_labels,
+            player_cap: self.player_cap,
+            translations: HashMap::new(),
+            base: Some(Arc::clone(self)),
+        }
+    }
+
+    fn next_location_id(&self) -> u32 {
+        self.locations()
+            .map(|location| location.id)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1)
     }
 
     fn random_location_pool(
         &self,
         pool_size: usize,
         player_count: usize,
+        allow_duplicate_roles: bool,
+        tags: &[String],
+        excluded_ids: &[u32],
         rng: &mut impl Rng,
     ) -> Vec<LocationDefinition> {
         let mut candidates: Vec<_> = self
-            .locations
-            .iter()
-            .filter(|loc| loc.roles.len() + 1 >= player_count)
+            .locations()
+            .filter(|loc| !excluded_ids.contains(&loc.id))
+            .filter(|loc| loc.supports(player_count, allow_duplicate_roles))
             .collect();
+        if !tags.is_empty() && candidates.iter().any(|loc| loc.has_any_tag(tags)) {
+            candidates.retain(|loc| loc.has_any_tag(tags));
+        }
         candidates.shuffle(rng);
         candidates.into_iter().take(pool_size).cloned().collect()
     }
@@ -105,6 +380,8 @@ impl GameContent {
         categories: &[String],
         allow_repeats: bool,
         used_question_ids: &HashSet<String>,
+        avoid_categories: &[String],
+        category_weights: &HashMap<String, u32>,
         rng: &mut impl Rng,
     ) -> Option<&'a QuestionPrompt> {
         let normalized_categories: HashSet<String> = categories
@@ -112,16 +389,7 @@ impl GameContent {
             .map(|value| value.to_lowercase())
             .collect();
 
-        let mut pool: Vec<&QuestionPrompt> =
-            self.questions
-                .iter()
-                .filter(|question| {
-                    normalized_categories.is_empty()
-                        || question.categories.iter().any(|category| {
-                            normalized_categories.contains(&category.to_lowercase())
-                        })
-                })
-                .collect();
+        let mut pool = self.questions_in(categories);
 
         if !allow_repeats {
             pool.retain(|question| !used_question_ids.contains(&question.id));
@@ -131,11 +399,45 @@ impl GameContent {
             return None;
         }
 
+        if !avoid_categories.is_empty() || !category_weights.is_empty() {
+            let weighted = pool.choose_weighted(rng, |question| {
+                let base = question
+                    .categories
+                    .iter()
+                    .map(|category| category.to_lowercase())
+                    .filter(|category| {
+                        normalized_categories.is_empty() || normalized_categories.contains(category)
+                    })
+                    .map(|category| category_weights.get(&category).copied().unwrap_or(1))
+                    .max()
+                    .unwrap_or(1);
+                let repeats = question.categories.iter().any(|category| {
+                    avoid_categories
+                        .iter()
+                        .any(|avoided| avoided.eq_ignore_ascii_case(category))
+                });
+                let penalty = if repeats { REPEAT_CATEGORY_WEIGHT } else { 1.0 };
+                f64::from(base) * penalty
+            });
+            if let Ok(question) = weighted {
+                return Some(*question);
+            }
+        }
+
         Some(pool.choose(rng).copied().unwrap())
     }
 
-    fn default_categories(&self) -> Vec<String> {
-        self.categories.clone()
+    /// Every category in its display spelling, ordered by the normalized key.
+    fn display_categories(&self) -> Vec<String> {
+        self.categories
+            .iter()
+            .map(|category| {
+                self.category_labels
+                    .get(category)
+                    .cloned()
+                    .unwrap_or_else(|| category.clone())
+            })
+            .collect()
     }
 
     fn normalize_categories(&self, requested: &[String]) -> Result<Vec<String>, AppError> {
@@ -151,10 +453,10 @@ impl GameContent {
                 continue;
             }
             if !valid.contains(normalized.as_str()) {
-                return Err(AppError::BadRequest(format!(
-                    "unknown category: {}",
-                    category
-                )));
+                return Err(AppError::BadRequest(
+                    ErrorCode::UnknownCategory,
+                    format!("unknown category: {}", category),
+                ));
             }
             if !cleaned.iter().any(|value: &String| value == &normalized) {
                 cleaned.push(normalized);
@@ -168,26 +470,135 @@ impl GameContent {
         }
     }
 
+    fn normalize_category_weights(
+        &self,
+        requested: &HashMap<String, u32>,
+    ) -> Result<HashMap<String, u32>, AppError> {
+        let valid: HashSet<&str> = self.categories.iter().map(String::as_str).collect();
+        let mut cleaned = HashMap::new();
+        for (category, weight) in requested {
+            let normalized = category.trim().to_lowercase();
+            if !valid.contains(normalized.as_str()) {
+                return Err(AppError::BadRequest(
+                    ErrorCode::UnknownCategory,
+                    format!("unknown category: {}", category),
+                ));
+            }
+            cleaned.insert(normalized, (*weight).min(MAX_CATEGORY_WEIGHT));
+        }
+        Ok(cleaned)
+    }
+
+    fn location_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .locations()
+            .flat_map(|location| location.tags.iter())
+            .map(|tag| tag.to_lowercase())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Unlike categories, an empty tag list means "no restriction".
+    fn normalize_location_tags(&self, requested: &[String]) -> Result<Vec<String>, AppError> {
+        let valid = self.location_tags();
+        let mut cleaned = Vec::new();
+        for tag in requested {
+            let normalized = tag.trim().to_lowercase();
+            if normalized.is_empty() {
+                continue;
+            }
+            if !valid.contains(&normalized) {
+                return Err(AppError::BadRequest(
+                    ErrorCode::UnknownLocationTag,
+                    format!("unknown location tag: {}", tag),
+                ));
+            }
+            if !cleaned.contains(&normalized) {
+                cleaned.push(normalized);
+            }
+        }
+        Ok(cleaned)
+    }
+
     fn max_location_pool(&self) -> usize {
-        self.locations.len()
+        self.locations().count()
     }
 
     fn max_player_capacity(&self) -> u8 {
-        self.locations
-            .iter()
-            .map(|location| (location.roles.len() + 1) as u8)
+        self.locations()
+            .map(|location| (location.roles.len() + 1).min(u8::MAX as usize) as u8)
             .max()
             .unwrap_or(8)
     }
+
+    fn stats(&self) -> ContentStats {
+        let max_player_capacity = self.max_player_capacity().min(self.player_cap);
+        let labels = self.display_categories();
+        let categories = self
+            .categories
+            .iter()
+            .zip(labels)
+            .map(|(category, label)| CategoryStats {
+                category: label,
+                questions: self.questions_in(std::slice::from_ref(category)).len() as u32,
+            })
+            .collect();
+        let locations = self
+            .locations()
+            .map(|location| LocationStats {
+                id: location.id,
+                name: location.name.clone(),
+                roles: location.roles.len() as u32,
+                supports_max_players: location.supports(usize::from(max_player_capacity), false),
+            })
+            .collect();
+        ContentStats {
+            question_count: self.questions().count() as u32,
+            location_count: self.locations().count() as u32,
+            max_player_capacity,
+            categories,
+            locations,
+        }
+    }
+}
+
+/// Balance report over the built-in content, computed once at startup.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ContentStats {
+    question_count: u32,
+    location_count: u32,
+    max_player_capacity: u8,
+    categories: Vec<CategoryStats>,
+    locations: Vec<LocationStats>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CategoryStats {
+    category: String,
+    questions: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct LocationStats {
+    id: u32,
+    name: String,
+    roles: u32,
+    /// Whether a lobby at `max_player_capacity` can play here without duplicate roles.
+    supports_max_players: bool,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, Default)]
 struct PlayerWins {
     crew: u32,
     imposter: u32,
+    /// Weighted by how each round was won, per `GameRules::scoring`.
+    #[serde(default)]
+    points: u32,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 enum RoundWinner {
     Crew,
     Imposter,
@@ -215,6 +626,40 @@ enum RoundOutcome {
         actual_location_id: u32,
         actual_location_name: String,
     },
+    ImposterLeft {
+        impostor: Uuid,
+    },
+    VoteDeadlocked {
+        impostors: Vec<Uuid>,
+    },
+    TimeExpired {
+        impostors: Vec<Uuid>,
+    },
+    CrewIdentifiedImposters {
+        accuser: Uuid,
+        impostors: Vec<Uuid>,
+    },
+    CrewMisdirectedImposters {
+        accuser: Uuid,
+        accused: Uuid,
+        impostors: Vec<Uuid>,
+    },
+    ImpostersIdentifiedLocation {
+        impostor: Uuid,
+        impostors: Vec<Uuid>,
+        location_id: u32,
+        location_name: String,
+    },
+    ImpostersFailedLocationGuess {
+        impostor: Uuid,
+        impostors: Vec<Uuid>,
+        guessed_location_id: u32,
+        actual_location_id: u32,
+        actual_location_name: String,
+    },
+    QuestionsExhausted {
+        impostors: Vec<Uuid>,
+    },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -222,29 +667,111 @@ struct RoundResolution {
     winner: RoundWinner,
     outcome: RoundOutcome,
     ended_at_ms: u64,
+    /// Civilian roles, filled in only when the round concludes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    revealed_roles: Vec<RevealedRole>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RevealedRole {
+    player_id: Uuid,
+    role: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct RoundSummary {
     round_number: u32,
     resolution: RoundResolution,
+    #[serde(default)]
+    started_at_ms: u64,
+    #[serde(default)]
+    participants: Vec<Uuid>,
+}
+
+impl RoundSummary {
+    fn duration_ms(&self) -> Option<u64> {
+        if self.started_at_ms == 0 {
+            return None;
+        }
+        self.resolution.ended_at_ms.checked_sub(self.started_at_ms)
+    }
+}
+
+impl RoundOutcome {
+    /// The civilian whose accusation caught the imposters, if any.
+    fn successful_accuser(&self) -> Option<Uuid> {
+        match self {
+            RoundOutcome::CrewIdentifiedImposter { accuser, .. }
+            | RoundOutcome::CrewIdentifiedImposters { accuser, .. } => Some(*accuser),
+            _ => None,
+        }
+    }
+
+    fn is_misdirection(&self) -> bool {
+        matches!(
+            self,
+            RoundOutcome::CrewMisdirected { .. } | RoundOutcome::CrewMisdirectedImposters { .. }
+        )
+    }
 }
 
 #[derive(Clone)]
 struct RoundState {
     round_number: u32,
     location: LocationDefinition,
-    imposter_id: Uuid,
+    imposter_ids: Vec<Uuid>,
+    identified_imposters: HashSet<Uuid>,
     assignments: HashMap<Uuid, PlayerRoleAssignment>,
     turn_order: Vec<Uuid>,
     current_turn_index: usize,
+    /// Where the current pass through `turn_order` begins in `asked_questions`.
+    cycle_start: usize,
     current_question: Option<QuestionPrompt>,
     used_question_ids: HashSet<String>,
     asked_questions: Vec<AskedQuestion>,
-    started_at: SystemTime,
+    votes: HashMap<Uuid, Uuid>,
+    /// Bots seated when the round began; their ballots never decide a vote.
+    bot_ids: HashSet<Uuid>,
+    started_at: Instant,
+    time_limit: Duration,
+    turn_time_limit: Option<Duration>,
+    /// `max_questions_per_round` as it stood when the round began.
+    question_cap: Option<u32>,
+    /// `hide_history_from_imposter` as it stood when the round began.
+    hide_history: bool,
+    turn_started_at: Instant,
+    turn_serial: u64,
+    skip_question_votes: HashSet<Uuid>,
+    paused_at: Option<Instant>,
+    paused_total: Duration,
+    peek_used: bool,
+    /// Keyed by `(player_id, idempotency_key)`.
+    processed_guesses: HashMap<(Uuid, Uuid), RoundResolution>,
+    /// Who was asked the latest question and has yet to answer it.
+    awaiting_answer: Option<Uuid>,
+    last_chance: Option<LastChance>,
+    /// Set once `max_questions_per_round` is spent: draws stop and the crew
+    /// must accuse before this deadline or the imposter wins.
+    forced_vote_deadline: Option<Instant>,
+    /// The host picked the imposter rather than leaving it to the draw.
+    imposter_forced: bool,
+    location_forced: bool,
+    /// The imposter's shortlist and its strike schedule under `imposter_narrowing`.
+    imposter_candidates: Vec<LocationOption>,
+    narrowing_removals: Vec<u32>,
+    narrowing_interval: Option<Duration>,
     resolution: Option<RoundResolution>,
 }
 
+/// A misdirected accusation held open under `imposter_last_chance`: the
+/// imposter may still guess the location and the crew may accuse once more
+/// before the deadline, after which the pending result stands.
+#[derive(Clone)]
+struct LastChance {
+    pending: RoundResolution,
+    deadline: Instant,
+}
+
 impl RoundState {
     fn new(
         round_number: u32,
@@ -252,44 +779,77 @@ impl RoundState {
         players: &HashMap<Uuid, Player>,
         rules: &GameRules,
         content: &GameContent,
+        previous_imposters: &[Uuid],
         rng: &mut impl Rng,
     ) -> Result<Self, AppError> {
         let player_count = players.len();
-        if player_count < 3 {
+        let min_players = usize::from(rules.min_players);
+        if player_count < min_players {
             return Err(AppError::BadRequest(
-                "at least three players are required to start a round".into(),
+                ErrorCode::NotEnoughPlayers,
+                format!(
+                    "at least {} players are required to start a round",
+                    min_players
+                ),
             ));
         }
 
-        if player_count - 1 > location.roles.len() {
+        if !location.supports(player_count, rules.allow_duplicate_roles) {
             return Err(AppError::BadRequest(
+                ErrorCode::LocationTooSmall,
                 "selected location does not support this many players".into(),
             ));
         }
 
+        let imposter_total = usize::from(rules.imposter_count)
+            .min((player_count - 1) / 2)
+            .max(1);
+        // Names are unique per lobby, so ordering by them keeps seeded games
+        // reproducible regardless of map iteration order.
         let mut player_ids: Vec<Uuid> = players.keys().cloned().collect();
+        player_ids.sort_by_key(|id| players[id].name.to_lowercase());
         player_ids.shuffle(rng);
-        let imposter_index = rng.gen_range(0..player_ids.len());
-        let imposter_id = player_ids[imposter_index];
+        if rules.avoid_repeat_imposter {
+            // Stable, so last round's imposters only fill seats nobody else can.
+            player_ids.sort_by_key(|id| previous_imposters.contains(id));
+        }
+        let imposter_ids: Vec<Uuid> = player_ids.iter().take(imposter_total).copied().collect();
 
         let mut assignments = HashMap::new();
         let mut available_roles = location.roles.clone();
         available_roles.shuffle(rng);
-        let mut role_iter = available_roles.into_iter();
+        let mut civilians_assigned = 0;
 
         for player_id in &player_ids {
-            if *player_id == imposter_id {
+            if imposter_ids.contains(player_id) {
                 assignments.insert(*player_id, PlayerRoleAssignment::Imposter);
             } else {
-                let role = role_iter
-                    .next()
-                    .ok_or_else(|| AppError::BadRequest("not enough roles available".into()))?;
+                let role = duplicate_role(
+                    &available_roles,
+                    civilians_assigned,
+                    rules.allow_duplicate_roles,
+                )
+                .ok_or_else(|| {
+                    AppError::BadRequest(
+                        ErrorCode::LocationTooSmall,
+                        "not enough roles available".into(),
+                    )
+                })?;
+                civilians_assigned += 1;
                 assignments.insert(*player_id, PlayerRoleAssignment::Civilian { role });
             }
         }
 
         let mut turn_order = player_ids.clone();
-        turn_order.shuffle(rng);
+        match rules.turn_order_mode {
+            TurnOrderMode::Shuffled => turn_order.shuffle(rng),
+            TurnOrderMode::JoinOrder => {
+                turn_order.sort_by_key(|id| players[id].join_seq);
+                // Seats stay put; the opening turn moves one seat along each round.
+                let opener = (round_number.saturating_sub(1) as usize) % turn_order.len();
+                turn_order.rotate_left(opener);
+            }
+        }
 
         let mut used_question_ids = HashSet::new();
         let initial_question = content
@@ -297,25 +857,59 @@ impl RoundState {
                 &rules.question_categories,
                 rules.allow_repeated_questions,
                 &used_question_ids,
+                &[],
+                &rules.category_weights,
                 rng,
             )
             .cloned()
             .ok_or_else(|| {
-                AppError::BadRequest("no questions available for selected categories".into())
+                AppError::BadRequest(
+                    ErrorCode::NoQuestionsAvailable,
+                    "no questions available for selected categories".into(),
+                )
             })?;
         used_question_ids.insert(initial_question.id.clone());
 
         Ok(Self {
             round_number,
             location,
-            imposter_id,
+            imposter_ids,
+            identified_imposters: HashSet::new(),
             assignments,
             turn_order,
             current_turn_index: 0,
+            cycle_start: 0,
             current_question: Some(initial_question),
             used_question_ids,
             asked_questions: Vec::new(),
-            started_at: SystemTime::now(),
+            votes: HashMap::new(),
+            bot_ids: players
+                .values()
+                .filter(|player| player.is_bot)
+                .map(|player| player.id)
+                .collect(),
+            started_at: Instant::now(),
+            time_limit: Duration::from_secs(u64::from(rules.round_time_seconds)),
+            turn_time_limit: rules
+                .turn_time_seconds
+                .map(|seconds| Duration::from_secs(u64::from(seconds))),
+            question_cap: rules.max_questions_per_round,
+            hide_history: rules.hide_history_from_imposter,
+            turn_started_at: Instant::now(),
+            turn_serial: 0,
+            skip_question_votes: HashSet::new(),
+            paused_at: None,
+            paused_total: Duration::ZERO,
+            peek_used: false,
+            processed_guesses: HashMap::new(),
+            awaiting_answer: None,
+            last_chance: None,
+            imposter_forced: false,
+            location_forced: false,
+            imposter_candidates: Vec::new(),
+            narrowing_removals: Vec::new(),
+            narrowing_interval: None,
+            forced_vote_deadline: None,
             resolution: None,
         })
     }
@@ -328,87 +922,541 @@ impl RoundState {
         self.turn_order.get(index).copied()
     }
 
+    fn upcoming_turns(&self) -> Vec<Uuid> {
+        if !self.is_active() || self.turn_order.len() < 2 {
+            return Vec::new();
+        }
+        let len = self.turn_order.len();
+        (1..len)
+            .map(|offset| self.turn_order[(self.current_turn_index + offset) % len])
+            .collect()
+    }
+
+    /// Players yet to take their turn in the current pass through the turn
+    /// order, starting with whoever holds it now. Empty once resolved.
+    fn upcoming_this_cycle(&self) -> Vec<Uuid> {
+        if !self.is_active() || self.turn_order.is_empty() {
+            return Vec::new();
+        }
+        let start = self.current_turn_index % self.turn_order.len();
+        self.turn_order[start..].to_vec()
+    }
+
     fn is_active(&self) -> bool {
         self.resolution.is_none()
     }
 
-    fn public_state(&self) -> RoundPublicState {
-        RoundPublicState {
-            round_number: self.round_number,
-            turn_order: self.turn_order.clone(),
-            current_turn_player_id: self.current_turn(),
-            current_question: self.current_question.as_ref().map(QuestionView::from),
-            asked_questions: self
-                .asked_questions
-                .iter()
-                .map(AskedQuestionView::from)
-                .collect(),
-            started_at_ms: timestamp_ms(self.started_at),
-            resolution: self.resolution.clone(),
-        }
+    fn is_imposter(&self, player_id: &Uuid) -> bool {
+        self.imposter_ids.contains(player_id)
     }
 
-    fn assignment_for(&self, player_id: &Uuid) -> Option<PlayerAssignmentView> {
-        let assignment = self.assignments.get(player_id)?;
-        match assignment {
-            PlayerRoleAssignment::Imposter => Some(PlayerAssignmentView {
-                round_number: self.round_number,
-                is_imposter: true,
-                location_id: None,
-                location_name: None,
-                role: None,
-            }),
-            PlayerRoleAssignment::Civilian { role } => Some(PlayerAssignmentView {
-                round_number: self.round_number,
-                is_imposter: false,
-                location_id: Some(self.location.id),
-                location_name: Some(self.location.name.clone()),
-                role: Some(role.clone()),
-            }),
-        }
+    fn remaining_imposters(&self) -> usize {
+        self.imposter_ids
+            .iter()
+            .filter(|id| !self.identified_imposters.contains(id))
+            .count()
     }
 
-    fn next_question(
-        &mut self,
-        player_id: Uuid,
-        rules: &GameRules,
+    fn deadline(&self) -> Instant {
+        self.started_at + self.time_limit + self.paused_total + self.current_pause()
+    }
+
+    fn current_pause(&self) -> Duration {
+        self.paused_at
+            .map(|paused_at| paused_at.elapsed())
+            .unwrap_or_default()
+    }
+
+    fn ensure_running(&self) -> Result<(), AppError> {
+        if self.paused_at.is_some() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundPaused,
+                "the round is paused".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn ensure_questioning(&self) -> Result<(), AppError> {
+        if self.last_chance.is_some() {
+            return Err(AppError::BadRequest(
+                ErrorCode::LastChancePending,
+                "the imposter's last chance is underway".into(),
+            ));
+        }
+        if self.forced_vote_deadline.is_some() {
+            return Err(AppError::BadRequest(
+                ErrorCode::QuestionCapReached,
+                "no questions left this round; the crew must accuse someone".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<(), AppError> {
+        self.ensure_running()?;
+        self.paused_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Ends the pause, pushing every open deadline back by its length.
+    fn resume(&mut self) -> Result<(), AppError> {
+        if self.paused_at.is_none() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundNotPaused,
+                "the round is not paused".into(),
+            ));
+        }
+        let paused = self.current_pause();
+        self.paused_at = None;
+        self.paused_total += paused;
+        self.turn_started_at += paused;
+        if let Some(deadline) = self.forced_vote_deadline.as_mut() {
+            *deadline += paused;
+        }
+        if let Some(last_chance) = self.last_chance.as_mut() {
+            last_chance.deadline += paused;
+        }
+        Ok(())
+    }
+
+    fn time_remaining(&self) -> Duration {
+        self.deadline().saturating_duration_since(Instant::now())
+    }
+
+    fn advance_turn(&mut self) {
+        if !self.turn_order.is_empty() {
+            self.current_turn_index = (self.current_turn_index + 1) % self.turn_order.len();
+            if self.current_turn_index == 0 {
+                self.cycle_start = self.asked_questions.len();
+            }
+        }
+        self.turn_started_at = Instant::now();
+        self.turn_serial = self.turn_serial.wrapping_add(1);
+    }
+
+    fn turn_deadline(&self) -> Option<Instant> {
+        if self.last_chance.is_some() || self.forced_vote_deadline.is_some() {
+            return None;
+        }
+        self.turn_time_limit
+            .map(|limit| self.turn_started_at + limit + self.current_pause())
+    }
+
+    /// Questions actually asked this round; skipped turns don't count.
+    fn questions_asked(&self) -> usize {
+        self.asked_questions
+            .iter()
+            .filter(|asked| !asked.skipped)
+            .count()
+    }
+
+    /// Draws left under the question cap, counting the one on screen as used.
+    fn questions_remaining(&self) -> Option<u32> {
+        let drawn = self.questions_asked() + usize::from(self.current_question.is_some());
+        self.question_cap
+            .map(|cap| cap.saturating_sub(u32::try_from(drawn).unwrap_or(u32::MAX)))
+    }
+
+    fn questions_exhausted(&self) -> bool {
+        self.question_cap
+            .is_some_and(|cap| self.questions_asked() >= cap as usize)
+    }
+
+    /// Queues every decoy but one to be struck, one per `interval` of play.
+    fn arm_narrowing(
+        &mut self,
+        pool: &[LocationDefinition],
+        interval: Duration,
+        rng: &mut impl Rng,
+    ) {
+        self.imposter_candidates = pool
+            .iter()
+            .map(|location| LocationOption {
+                id: location.id,
+                name: location.name.clone(),
+            })
+            .collect();
+        let mut decoys: Vec<u32> = pool
+            .iter()
+            .map(|location| location.id)
+            .filter(|id| *id != self.location.id)
+            .collect();
+        decoys.shuffle(rng);
+        decoys.pop();
+        self.narrowing_removals = decoys;
+        self.narrowing_interval = Some(interval);
+    }
+
+    fn played_time(&self) -> Duration {
+        self.time_limit.saturating_sub(self.time_remaining())
+    }
+
+    /// How many decoys have been struck so far.
+    fn narrowing_step(&self) -> usize {
+        let Some(interval) = self.narrowing_interval else {
+            return 0;
+        };
+        let steps = self.played_time().as_millis() / interval.as_millis().max(1);
+        usize::try_from(steps)
+            .unwrap_or(usize::MAX)
+            .min(self.narrowing_removals.len())
+    }
+
+    /// Play left until the next decoy is struck; `None` once none remain.
+    fn next_narrowing_in(&self) -> Option<Duration> {
+        let interval = self.narrowing_interval?;
+        let step = self.narrowing_step();
+        if step >= self.narrowing_removals.len() {
+            return None;
+        }
+        let next = interval.saturating_mul(u32::try_from(step + 1).unwrap_or(u32::MAX));
+        Some(next.saturating_sub(self.played_time()))
+    }
+
+    fn remaining_candidates(&self) -> Option<Vec<LocationOption>> {
+        self.narrowing_interval?;
+        let struck = &self.narrowing_removals[..self.narrowing_step()];
+        Some(
+            self.imposter_candidates
+                .iter()
+                .filter(|option| !struck.contains(&option.id))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Makes `player_id` an imposter, handing their civilian role to one of
+    /// the drawn imposters so the imposter count stays the same.
+    fn force_imposter(&mut self, player_id: Uuid) {
+        self.imposter_forced = true;
+        if self.imposter_ids.contains(&player_id) {
+            return;
+        }
+        let Some(displaced) = self.imposter_ids.first().copied() else {
+            return;
+        };
+        let Some(role) = self
+            .assignments
+            .insert(player_id, PlayerRoleAssignment::Imposter)
+        else {
+            return;
+        };
+        self.assignments.insert(displaced, role);
+        self.imposter_ids[0] = player_id;
+    }
+
+    /// With history hidden, the questions are withheld from the serialized
+    /// state and only `for_viewer` puts them back, for crew members.
+    fn public_state(&self) -> RoundPublicState {
+        let upcoming_turns = self.upcoming_turns();
+        let state = RoundPublicState {
+            round_number: self.round_number,
+            turn_order: self.turn_order.clone(),
+            current_turn_player_id: self.current_turn(),
+            next_turn_player_id: upcoming_turns.first().copied(),
+            upcoming_turns,
+            current_question: self.current_question.as_ref().map(QuestionView::from),
+            asked_questions: self
+                .asked_questions
+                .iter()
+                .map(AskedQuestionView::from)
+                .collect(),
+            asked_total: self.questions_asked() as u32,
+            questions_remaining: self.questions_remaining(),
+            history_hidden: false,
+            vote_tallies: self.vote_tallies(),
+            draw_counts: self.draw_counts(),
+            turns_taken: self.turns_taken(),
+            upcoming: self.upcoming_this_cycle(),
+            skip_question_votes: self.skip_question_votes.iter().copied().collect(),
+            imposter_count: self.imposter_ids.len() as u32,
+            identified_imposters: self
+                .imposter_ids
+                .iter()
+                .copied()
+                .filter(|id| self.identified_imposters.contains(id))
+                .collect(),
+            started_at_ms: instant_ms(self.started_at),
+            deadline_ms: instant_ms(self.deadline()),
+            turn_deadline_ms: self.turn_deadline().map(instant_ms),
+            awaiting_answer_from: self.awaiting_answer,
+            last_chance_deadline_ms: self
+                .last_chance
+                .as_ref()
+                .map(|last_chance| instant_ms(last_chance.deadline)),
+            forced_vote_deadline_ms: self.forced_vote_deadline.map(instant_ms),
+            paused: self.paused_at.is_some(),
+            imposter_chosen_by_host: self.imposter_forced,
+            location_chosen_by_host: self.location_forced,
+            resolution: self.resolution.clone(),
+            reveal: self.reveal(),
+            withheld_history: None,
+        };
+        if !self.hide_history || !self.is_active() {
+            return state;
+        }
+        let readers = self
+            .assignments
+            .keys()
+            .copied()
+            .filter(|id| !self.is_imposter(id))
+            .collect();
+        state.without_history(readers)
+    }
+
+    fn archive(&self, resolution: &RoundResolution) -> RoundArchive {
+        RoundArchive {
+            round_number: self.round_number,
+            location_id: self.location.id,
+            location_name: self.location.name.clone(),
+            imposter_ids: self.imposter_ids.clone(),
+            turn_order: self.turn_order.clone(),
+            asked_questions: self
+                .asked_questions
+                .iter()
+                .map(AskedQuestionView::from)
+                .collect(),
+            started_at_ms: instant_ms(self.started_at),
+            resolution: resolution.clone(),
+        }
+    }
+
+    /// Full disclosure of the round, available only once it has been resolved.
+    fn reveal(&self) -> Option<RoundReveal> {
+        self.resolution.as_ref()?;
+        Some(RoundReveal {
+            imposter_ids: self.imposter_ids.clone(),
+            location_id: self.location.id,
+            location_name: self.location.name.clone(),
+            assignments: self.all_assignments(),
+        })
+    }
+
+    fn assignment_for(&self, player_id: &Uuid) -> Option<PlayerAssignmentView> {
+        let assignment = self.assignments.get(player_id)?;
+        match assignment {
+            PlayerRoleAssignment::Imposter => Some(PlayerAssignmentView {
+                round_number: self.round_number,
+                is_imposter: true,
+                location_id: None,
+                location_name: None,
+                role: None,
+                candidate_locations: self.remaining_candidates(),
+            }),
+            PlayerRoleAssignment::Civilian { role } => Some(PlayerAssignmentView {
+                round_number: self.round_number,
+                is_imposter: false,
+                location_id: Some(self.location.id),
+                location_name: Some(self.location.name.clone()),
+                role: Some(role.clone()),
+                candidate_locations: None,
+            }),
+        }
+    }
+
+    fn civilian_roles(&self) -> Vec<RevealedRole> {
+        self.turn_order
+            .iter()
+            .filter_map(|player_id| match self.assignments.get(player_id)? {
+                PlayerRoleAssignment::Civilian { role } => Some(RevealedRole {
+                    player_id: *player_id,
+                    role: role.clone(),
+                }),
+                PlayerRoleAssignment::Imposter => None,
+            })
+            .collect()
+    }
+
+    fn all_assignments(&self) -> HashMap<Uuid, PlayerAssignmentView> {
+        self.assignments
+            .keys()
+            .filter_map(|player_id| {
+                self.assignment_for(player_id)
+                    .map(|assignment| (*player_id, assignment))
+            })
+            .collect()
+    }
+
+    fn next_question(
+        &mut self,
+        player_id: Uuid,
+        rules: &GameRules,
         content: &GameContent,
         rng: &mut impl Rng,
-    ) -> Result<(QuestionPrompt, Uuid), AppError> {
+    ) -> Result<Option<(QuestionPrompt, Uuid)>, AppError> {
         if !self.is_active() {
-            return Err(AppError::BadRequest("round already resolved".into()));
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
         }
 
-        let expected_turn = self
-            .current_turn()
-            .ok_or_else(|| AppError::BadRequest("no turn available".into()))?;
+        let expected_turn = self.current_turn().ok_or_else(|| {
+            AppError::BadRequest(ErrorCode::NoTurnAvailable, "no turn available".into())
+        })?;
         if expected_turn != player_id {
-            return Err(AppError::Forbidden("not your turn to draw".into()));
+            return Err(AppError::Forbidden(
+                ErrorCode::NotYourTurn,
+                "not your turn to draw".into(),
+            ));
+        }
+
+        if rules.require_answer_before_next && self.awaiting_answer == Some(player_id) {
+            return Err(AppError::BadRequest(
+                ErrorCode::AnswerPending,
+                "answer the question you were asked before drawing".into(),
+            ));
         }
 
+        let asked = self.current_question.is_some();
         if let Some(current) = self.current_question.take() {
             self.asked_questions.push(AskedQuestion {
                 id: current.id.clone(),
                 text: current.text.clone(),
-                categories: current
-                    .categories
-                    .iter()
-                    .map(|category| category.to_lowercase())
-                    .collect(),
+                categories: current.categories.clone(),
                 asked_by: player_id,
                 asked_at: SystemTime::now(),
+                skipped: false,
+                answers: Vec::new(),
             });
         }
 
-        if !self.turn_order.is_empty() {
-            self.current_turn_index = (self.current_turn_index + 1) % self.turn_order.len();
+        if self.questions_exhausted() {
+            self.awaiting_answer = None;
+            self.forced_vote_deadline = Some(Instant::now() + FORCED_VOTE_WINDOW);
+            return Ok(None);
+        }
+
+        self.advance_turn();
+        // The question just asked goes to whoever draws next.
+        self.awaiting_answer = if asked { self.current_turn() } else { None };
+
+        let question = self.draw_question(rules, content, rng)?;
+        let next_turn = self.current_turn().ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::NoTurnAvailable,
+                "unable to determine next turn".into(),
+            )
+        })?;
+        self.current_question = Some(question.clone());
+        Ok(Some((question, next_turn)))
+    }
+
+    fn skip_turn(
+        &mut self,
+        redraw: bool,
+        rules: &GameRules,
+        content: &GameContent,
+        rng: &mut impl Rng,
+    ) -> Result<Uuid, AppError> {
+        if !self.is_active() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
+        }
+        self.ensure_questioning()?;
+
+        let skipped = self.current_turn().ok_or_else(|| {
+            AppError::BadRequest(ErrorCode::NoTurnAvailable, "no turn available".into())
+        })?;
+        self.asked_questions.push(AskedQuestion {
+            id: format!("skip-{}", self.asked_questions.len() + 1),
+            text: String::new(),
+            categories: Vec::new(),
+            asked_by: skipped,
+            asked_at: SystemTime::now(),
+            skipped: true,
+            answers: Vec::new(),
+        });
+        self.awaiting_answer = None;
+        self.advance_turn();
+
+        if redraw {
+            let question = self.draw_question(rules, content, rng)?;
+            self.current_question = Some(question);
+        }
+
+        self.current_turn().ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::NoTurnAvailable,
+                "unable to determine next turn".into(),
+            )
+        })
+    }
+
+    fn record_answer(&mut self, player_id: Uuid, text: &str) -> Result<(), AppError> {
+        if !self.is_active() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
+        }
+        if !self.assignments.contains_key(&player_id) {
+            return Err(AppError::Forbidden(
+                ErrorCode::NotInRound,
+                "player is not part of this round".into(),
+            ));
         }
 
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(AppError::BadRequest(
+                ErrorCode::AnswerRequired,
+                "answer text required".into(),
+            ));
+        }
+        if text.chars().count() > MAX_ANSWER_LENGTH {
+            return Err(AppError::BadRequest(
+                ErrorCode::AnswerTooLong,
+                format!("answers must be at most {} characters", MAX_ANSWER_LENGTH),
+            ));
+        }
+
+        let question = self
+            .asked_questions
+            .iter_mut()
+            .rev()
+            .find(|question| !question.skipped)
+            .ok_or_else(|| {
+                AppError::BadRequest(
+                    ErrorCode::NoQuestionAsked,
+                    "no question has been asked yet".into(),
+                )
+            })?;
+        question
+            .answers
+            .retain(|answer| answer.player_id != player_id);
+        question.answers.push(QuestionAnswer {
+            player_id,
+            text: text.to_owned(),
+            answered_at: SystemTime::now(),
+        });
+        if self.awaiting_answer == Some(player_id) {
+            self.awaiting_answer = None;
+        }
+        Ok(())
+    }
+
+    fn draw_question(
+        &mut self,
+        rules: &GameRules,
+        content: &GameContent,
+        rng: &mut impl Rng,
+    ) -> Result<QuestionPrompt, AppError> {
+        let avoid_categories = if rules.diversify_questions {
+            self.previous_categories()
+        } else {
+            Vec::new()
+        };
         let mut question = content
             .random_question(
                 &rules.question_categories,
                 rules.allow_repeated_questions,
                 &self.used_question_ids,
+                &avoid_categories,
+                &rules.category_weights,
                 rng,
             )
             .cloned();
@@ -420,108 +1468,507 @@ impl RoundState {
                     &rules.question_categories,
                     rules.allow_repeated_questions,
                     &self.used_question_ids,
+                    &avoid_categories,
+                    &rules.category_weights,
                     rng,
                 )
                 .cloned();
         }
 
-        let question = question
-            .ok_or_else(|| AppError::BadRequest("no further questions available".into()))?;
+        let question = question.ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::NoQuestionsAvailable,
+                "no further questions available".into(),
+            )
+        })?;
 
         self.used_question_ids.insert(question.id.clone());
-        let next_turn = self
-            .current_turn()
-            .ok_or_else(|| AppError::BadRequest("unable to determine next turn".into()))?;
-        self.current_question = Some(question.clone());
-        Ok((question, next_turn))
+        self.skip_question_votes.clear();
+        Ok(question)
     }
 
-    fn resolve_guess(
+    /// Records a vote to replace the current question. Once a strict majority
+    /// of the round agrees it is swapped out without advancing the turn;
+    /// returns whether that happened.
+    fn vote_skip_question(
         &mut self,
         player_id: Uuid,
-        action: GuessAction,
-    ) -> Result<RoundResolution, AppError> {
+        rules: &GameRules,
+        content: &GameContent,
+        rng: &mut impl Rng,
+    ) -> Result<bool, AppError> {
         if !self.is_active() {
-            return Err(AppError::BadRequest("round already resolved".into()));
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
+        }
+        if !self.assignments.contains_key(&player_id) {
+            return Err(AppError::Forbidden(
+                ErrorCode::NotInRound,
+                "player is not part of this round".into(),
+            ));
+        }
+        if self.current_question.is_none() {
+            return Err(AppError::BadRequest(
+                ErrorCode::NoCurrentQuestion,
+                "there is no question to skip".into(),
+            ));
+        }
+        if !self.skip_question_votes.insert(player_id) {
+            return Err(AppError::BadRequest(
+                ErrorCode::AlreadyVoted,
+                "you already voted to skip this question".into(),
+            ));
         }
 
-        let assignment = self
-            .assignments
-            .get(&player_id)
-            .ok_or_else(|| AppError::BadRequest("player not part of this round".into()))?
-            .clone();
+        if self.skip_question_votes.len() * 2 <= self.assignments.len() {
+            return Ok(false);
+        }
+        let question = self.draw_question(rules, content, rng)?;
+        self.current_question = Some(question);
+        Ok(true)
+    }
 
-        let ended_at_ms = timestamp_ms(SystemTime::now());
+    /// Offers an imposter the real location alongside one decoy, once per round.
+    fn peek(
+        &mut self,
+        player_id: Uuid,
+        candidates: &[LocationDefinition],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<LocationOption>, AppError> {
+        if !self.is_active() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
+        }
+        if !self.is_imposter(&player_id) {
+            return Err(AppError::Forbidden(
+                ErrorCode::NotImposter,
+                "only the imposter can peek".into(),
+            ));
+        }
+        if self.peek_used {
+            return Err(AppError::BadRequest(
+                ErrorCode::PeekUsed,
+                "the peek has already been used this round".into(),
+            ));
+        }
 
-        let resolution = match (assignment, action) {
-            (PlayerRoleAssignment::Imposter, GuessAction::GuessLocation { location_id }) => {
-                let is_correct = location_id == self.location.id;
+        let decoys: Vec<&LocationDefinition> = candidates
+            .iter()
+            .filter(|location| location.id != self.location.id)
+            .collect();
+        let decoy = decoys.choose(rng).ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::NoLocationsAvailable,
+                "no decoy locations available".into(),
+            )
+        })?;
 
-                if is_correct {
-                    RoundResolution {
-                        winner: RoundWinner::Imposter,
-                        outcome: RoundOutcome::ImposterIdentifiedLocation {
+        self.peek_used = true;
+        let mut options = vec![
+            LocationOption {
+                id: self.location.id,
+                name: self.location.name.clone(),
+            },
+            LocationOption {
+                id: decoy.id,
+                name: decoy.name.clone(),
+            },
+        ];
+        options.shuffle(rng);
+        Ok(options)
+    }
+
+    /// Categories of the question players saw most recently, which the next
+    /// draw tries to steer away from.
+    fn previous_categories(&self) -> Vec<String> {
+        if let Some(current) = &self.current_question {
+            return current.categories.clone();
+        }
+        self.asked_questions
+            .iter()
+            .rev()
+            .find(|question| !question.skipped)
+            .map(|question| question.categories.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the player held the turn, which passes to the next seat.
+    fn remove_player(&mut self, player_id: &Uuid) -> bool {
+        self.assignments.remove(player_id);
+        self.imposter_ids.retain(|id| id != player_id);
+        self.identified_imposters.remove(player_id);
+        self.votes.remove(player_id);
+        let Some(index) = self.turn_order.iter().position(|id| id == player_id) else {
+            return false;
+        };
+        let held_turn = index == self.current_turn_index;
+        self.turn_order.remove(index);
+        if index < self.current_turn_index {
+            self.current_turn_index -= 1;
+        }
+        if self.turn_order.is_empty() {
+            self.current_turn_index = 0;
+        } else {
+            self.current_turn_index %= self.turn_order.len();
+        }
+        if held_turn {
+            if self.current_turn_index == 0 {
+                self.cycle_start = self.asked_questions.len();
+            }
+            self.turn_started_at = Instant::now();
+            self.turn_serial = self.turn_serial.wrapping_add(1);
+        }
+        held_turn
+    }
+
+    /// Questions drawn per player in turn order, including players who drew none.
+    /// Skipped turns don't count.
+    fn draw_counts(&self) -> Vec<DrawCount> {
+        self.draw_counts_in(&self.asked_questions)
+    }
+
+    fn draw_counts_in(&self, asked_questions: &[AskedQuestion]) -> Vec<DrawCount> {
+        self.turn_order
+            .iter()
+            .map(|player_id| DrawCount {
+                player_id: *player_id,
+                draws: asked_questions
+                    .iter()
+                    .filter(|asked| !asked.skipped && asked.asked_by == *player_id)
+                    .count() as u32,
+            })
+            .collect()
+    }
+
+    /// `draw_counts` for the current pass through the turn order, keyed by
+    /// player; everyone is back to zero once the order wraps.
+    fn turns_taken(&self) -> HashMap<Uuid, u32> {
+        let cycle = self
+            .asked_questions
+            .get(self.cycle_start..)
+            .unwrap_or_default();
+        self.draw_counts_in(cycle)
+            .into_iter()
+            .map(|count| (count.player_id, count.draws))
+            .collect()
+    }
+
+    fn vote_tallies(&self) -> Vec<VoteTally> {
+        let mut counts: HashMap<Uuid, u32> = HashMap::new();
+        for accused_id in self.votes.values() {
+            *counts.entry(*accused_id).or_default() += 1;
+        }
+        let mut tallies: Vec<VoteTally> = counts
+            .into_iter()
+            .map(|(accused_id, votes)| VoteTally { accused_id, votes })
+            .collect();
+        tallies.sort_by(|a, b| b.votes.cmp(&a.votes).then(a.accused_id.cmp(&b.accused_id)));
+        tallies
+    }
+
+    /// Bots back a random suspect once a vote is underway. Their ballots show
+    /// in the tallies but never resolve the round, so only a human vote can
+    /// tip the majority and be credited as the accuser.
+    fn record_bot_votes(&mut self, rng: &mut impl Rng) {
+        let mut bots: Vec<Uuid> = self.bot_ids.iter().copied().collect();
+        bots.sort_unstable();
+        for bot_id in &bots {
+            if self.votes.contains_key(bot_id)
+                || !matches!(
+                    self.assignments.get(bot_id),
+                    Some(PlayerRoleAssignment::Civilian { .. })
+                )
+            {
+                continue;
+            }
+            let suspects: Vec<Uuid> = self
+                .assignments
+                .keys()
+                .copied()
+                .filter(|id| id != bot_id && !self.identified_imposters.contains(id))
+                .collect();
+            if let Some(accused_id) = suspects.choose(rng) {
+                self.votes.insert(*bot_id, *accused_id);
+            }
+        }
+    }
+
+    /// Counts human ballots only, and resolves on the voter's own pick.
+    fn record_vote(
+        &mut self,
+        voter: Uuid,
+        accused_id: Uuid,
+        ended_at_ms: u64,
+    ) -> Option<RoundResolution> {
+        self.votes.insert(voter, accused_id);
+
+        let civilians = self
+            .assignments
+            .iter()
+            .filter(|(id, assignment)| {
+                !self.bot_ids.contains(id)
+                    && matches!(assignment, PlayerRoleAssignment::Civilian { .. })
+            })
+            .count();
+        let human_votes: Vec<Uuid> = self
+            .votes
+            .iter()
+            .filter(|(id, _)| !self.bot_ids.contains(id))
+            .map(|(_, accused)| *accused)
+            .collect();
+        let backing = human_votes
+            .iter()
+            .filter(|accused| **accused == accused_id)
+            .count();
+
+        if backing * 2 > civilians {
+            return self.accuse(voter, accused_id, ended_at_ms);
+        }
+
+        if human_votes.len() >= civilians {
+            return Some(RoundResolution {
+                winner: RoundWinner::Imposter,
+                outcome: RoundOutcome::VoteDeadlocked {
+                    impostors: self.imposter_ids.clone(),
+                },
+                ended_at_ms,
+                revealed_roles: Vec::new(),
+            });
+        }
+
+        None
+    }
+
+    /// Applies a crew accusation. Catching one of several imposters keeps the
+    /// round going; the crew only wins once every imposter is identified.
+    fn accuse(
+        &mut self,
+        accuser: Uuid,
+        accused_id: Uuid,
+        ended_at_ms: u64,
+    ) -> Option<RoundResolution> {
+        let single = self.imposter_ids.len() == 1;
+        if !self.is_imposter(&accused_id) {
+            let outcome = if single {
+                RoundOutcome::CrewMisdirected {
+                    accuser,
+                    accused: accused_id,
+                    impostor: self.imposter_ids[0],
+                }
+            } else {
+                RoundOutcome::CrewMisdirectedImposters {
+                    accuser,
+                    accused: accused_id,
+                    impostors: self.imposter_ids.clone(),
+                }
+            };
+            return Some(RoundResolution {
+                winner: RoundWinner::Imposter,
+                outcome,
+                ended_at_ms,
+                revealed_roles: Vec::new(),
+            });
+        }
+
+        self.identified_imposters.insert(accused_id);
+        if self.remaining_imposters() > 0 {
+            self.votes.clear();
+            return None;
+        }
+
+        let outcome = if single {
+            RoundOutcome::CrewIdentifiedImposter {
+                accuser,
+                impostor: accused_id,
+            }
+        } else {
+            RoundOutcome::CrewIdentifiedImposters {
+                accuser,
+                impostors: self.imposter_ids.clone(),
+            }
+        };
+        Some(RoundResolution {
+            winner: RoundWinner::Crew,
+            outcome,
+            ended_at_ms,
+            revealed_roles: Vec::new(),
+        })
+    }
+
+    fn resolve_guess(
+        &mut self,
+        player_id: Uuid,
+        action: GuessAction,
+        rules: &GameRules,
+    ) -> Result<Option<RoundResolution>, AppError> {
+        if !self.is_active() {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "round already resolved".into(),
+            ));
+        }
+        if self
+            .forced_vote_deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Err(AppError::BadRequest(
+                ErrorCode::RoundResolved,
+                "the vote window has closed".into(),
+            ));
+        }
+
+        let assignment = self
+            .assignments
+            .get(&player_id)
+            .ok_or_else(|| {
+                AppError::BadRequest(
+                    ErrorCode::NotInRound,
+                    "player not part of this round".into(),
+                )
+            })?
+            .clone();
+
+        // A spent question cap forces the vote, so the minimum no longer applies.
+        let gated = match action {
+            GuessAction::AccusePlayer { .. } => true,
+            GuessAction::GuessLocation { .. } => rules.gate_location_guess,
+        };
+        if gated && self.forced_vote_deadline.is_none() {
+            let asked = self.questions_asked() as u32;
+            let needed = rules.min_questions_before_guess.saturating_sub(asked);
+            if needed > 0 {
+                return Err(AppError::BadRequest(
+                    ErrorCode::TooFewQuestions,
+                    format!(
+                        "{} more question{} must be asked before guessing",
+                        needed,
+                        if needed == 1 { "" } else { "s" }
+                    ),
+                ));
+            }
+        }
+
+        let ended_at_ms = timestamp_ms(SystemTime::now());
+        let single = self.imposter_ids.len() == 1;
+
+        let resolution = match (assignment, action) {
+            (PlayerRoleAssignment::Imposter, GuessAction::GuessLocation { location_id }) => {
+                if self.identified_imposters.contains(&player_id) {
+                    return Err(AppError::BadRequest(
+                        ErrorCode::AlreadyIdentified,
+                        "identified imposters can no longer guess".into(),
+                    ));
+                }
+                let is_correct = location_id == self.location.id;
+
+                let (winner, outcome) = match (is_correct, single) {
+                    (true, true) => (
+                        RoundWinner::Imposter,
+                        RoundOutcome::ImposterIdentifiedLocation {
                             impostor: player_id,
                             location_id: self.location.id,
                             location_name: self.location.name.clone(),
                         },
-                        ended_at_ms,
-                    }
-                } else {
-                    RoundResolution {
-                        winner: RoundWinner::Crew,
-                        outcome: RoundOutcome::ImposterFailedLocationGuess {
+                    ),
+                    (true, false) => (
+                        RoundWinner::Imposter,
+                        RoundOutcome::ImpostersIdentifiedLocation {
+                            impostor: player_id,
+                            impostors: self.imposter_ids.clone(),
+                            location_id: self.location.id,
+                            location_name: self.location.name.clone(),
+                        },
+                    ),
+                    (false, true) => (
+                        RoundWinner::Crew,
+                        RoundOutcome::ImposterFailedLocationGuess {
                             impostor: player_id,
                             guessed_location_id: location_id,
                             actual_location_id: self.location.id,
                             actual_location_name: self.location.name.clone(),
                         },
-                        ended_at_ms,
-                    }
-                }
+                    ),
+                    (false, false) => (
+                        RoundWinner::Crew,
+                        RoundOutcome::ImpostersFailedLocationGuess {
+                            impostor: player_id,
+                            impostors: self.imposter_ids.clone(),
+                            guessed_location_id: location_id,
+                            actual_location_id: self.location.id,
+                            actual_location_name: self.location.name.clone(),
+                        },
+                    ),
+                };
+                Some(RoundResolution {
+                    winner,
+                    outcome,
+                    ended_at_ms,
+                    revealed_roles: Vec::new(),
+                })
             }
             (PlayerRoleAssignment::Imposter, GuessAction::AccusePlayer { .. }) => {
                 return Err(AppError::BadRequest(
+                    ErrorCode::InvalidGuess,
                     "imposter must guess the location".into(),
                 ));
             }
             (PlayerRoleAssignment::Civilian { .. }, GuessAction::AccusePlayer { accused_id }) => {
                 if !self.assignments.contains_key(&accused_id) {
-                    return Err(AppError::BadRequest("accused player not found".into()));
+                    return Err(AppError::BadRequest(
+                        ErrorCode::AccusedNotFound,
+                        "accused player not found".into(),
+                    ));
                 }
                 if accused_id == player_id {
-                    return Err(AppError::BadRequest("you cannot accuse yourself".into()));
+                    return Err(AppError::BadRequest(
+                        ErrorCode::SelfAccusation,
+                        "you cannot accuse yourself".into(),
+                    ));
+                }
+                if self.identified_imposters.contains(&accused_id) {
+                    return Err(AppError::BadRequest(
+                        ErrorCode::AlreadyIdentified,
+                        "player already identified as an imposter".into(),
+                    ));
                 }
 
-                if accused_id == self.imposter_id {
-                    RoundResolution {
-                        winner: RoundWinner::Crew,
-                        outcome: RoundOutcome::CrewIdentifiedImposter {
-                            accuser: player_id,
-                            impostor: self.imposter_id,
-                        },
-                        ended_at_ms,
-                    }
+                if rules.require_majority_vote {
+                    self.record_vote(player_id, accused_id, ended_at_ms)
                 } else {
-                    RoundResolution {
-                        winner: RoundWinner::Imposter,
-                        outcome: RoundOutcome::CrewMisdirected {
-                            accuser: player_id,
-                            accused: accused_id,
-                            impostor: self.imposter_id,
-                        },
-                        ended_at_ms,
-                    }
+                    self.accuse(player_id, accused_id, ended_at_ms)
                 }
             }
             (PlayerRoleAssignment::Civilian { .. }, GuessAction::GuessLocation { .. }) => {
                 return Err(AppError::BadRequest(
+                    ErrorCode::InvalidGuess,
                     "crew members must accuse a player".into(),
                 ));
             }
         };
 
-        self.resolution = Some(resolution.clone());
+        let resolution = match resolution {
+            Some(resolution)
+                if rules.imposter_last_chance
+                    && self.last_chance.is_none()
+                    && resolution.outcome.is_misdirection() =>
+            {
+                self.votes.clear();
+                self.last_chance = Some(LastChance {
+                    pending: resolution,
+                    deadline: Instant::now() + LAST_CHANCE_WINDOW,
+                });
+                None
+            }
+            resolution => resolution,
+        };
+
+        if let Some(resolution) = resolution.as_ref() {
+            self.resolution = Some(resolution.clone());
+        }
         Ok(resolution)
     }
 }
@@ -533,6 +1980,15 @@ struct AskedQuestion {
     categories: Vec<String>,
     asked_by: Uuid,
     asked_at: SystemTime,
+    skipped: bool,
+    answers: Vec<QuestionAnswer>,
+}
+
+#[derive(Clone)]
+struct QuestionAnswer {
+    player_id: Uuid,
+    text: String,
+    answered_at: SystemTime,
 }
 
 #[derive(Clone)]
@@ -541,6 +1997,19 @@ enum PlayerRoleAssignment {
     Civilian { role: String },
 }
 
+/// Role for the `index`-th civilian. Once the location's roles run out and
+/// duplicates are allowed they repeat with a suffix, e.g. "Chef 2".
+fn duplicate_role(roles: &[String], index: usize, allow_duplicates: bool) -> Option<String> {
+    if roles.is_empty() || (!allow_duplicates && index >= roles.len()) {
+        return None;
+    }
+    let role = &roles[index % roles.len()];
+    match index / roles.len() {
+        0 => Some(role.clone()),
+        lap => Some(format!("{} {}", role, lap + 1)),
+    }
+}
+
 #[derive(Clone)]
 enum GuessAction {
     AccusePlayer { accused_id: Uuid },
@@ -561,6 +2030,17 @@ struct AskedQuestionView {
     categories: Vec<String>,
     asked_by: Uuid,
     asked_at_ms: u64,
+    #[serde(default)]
+    skipped: bool,
+    #[serde(default)]
+    answers: Vec<AnswerView>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AnswerView {
+    player_id: Uuid,
+    text: String,
+    at_ms: u64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -568,10 +2048,124 @@ struct RoundPublicState {
     round_number: u32,
     turn_order: Vec<Uuid>,
     current_turn_player_id: Option<Uuid>,
+    next_turn_player_id: Option<Uuid>,
+    upcoming_turns: Vec<Uuid>,
     current_question: Option<QuestionView>,
     asked_questions: Vec<AskedQuestionView>,
+    #[serde(default)]
+    asked_total: u32,
+    /// Draws left before `max_questions_per_round` ends the round; absent
+    /// when the lobby has no cap.
+    #[serde(default)]
+    questions_remaining: Option<u32>,
+    #[serde(default)]
+    history_hidden: bool,
+    #[serde(skip)]
+    withheld_history: Option<Box<WithheldHistory>>,
+    #[serde(default)]
+    vote_tallies: Vec<VoteTally>,
+    #[serde(default)]
+    draw_counts: Vec<DrawCount>,
+    #[serde(default)]
+    turns_taken: HashMap<Uuid, u32>,
+    /// Who still has a turn coming before the order wraps around.
+    #[serde(default)]
+    upcoming: Vec<Uuid>,
+    #[serde(default)]
+    skip_question_votes: Vec<Uuid>,
+    imposter_count: u32,
+    #[serde(default)]
+    identified_imposters: Vec<Uuid>,
     started_at_ms: u64,
+    deadline_ms: u64,
+    #[serde(default)]
+    turn_deadline_ms: Option<u64>,
+    #[serde(default)]
+    awaiting_answer_from: Option<Uuid>,
+    #[serde(default)]
+    last_chance_deadline_ms: Option<u64>,
+    #[serde(default)]
+    forced_vote_deadline_ms: Option<u64>,
+    #[serde(default)]
+    paused: bool,
+    /// The host hand-picked the imposter, so this round isn't competitive.
+    #[serde(default)]
+    imposter_chosen_by_host: bool,
+    /// The host picked the location, so this round is for testing or demos.
+    #[serde(default)]
+    location_chosen_by_host: bool,
     resolution: Option<RoundResolution>,
+    #[serde(default)]
+    reveal: Option<RoundReveal>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RoundArchive {
+    round_number: u32,
+    location_id: u32,
+    location_name: String,
+    imposter_ids: Vec<Uuid>,
+    turn_order: Vec<Uuid>,
+    asked_questions: Vec<AskedQuestionView>,
+    started_at_ms: u64,
+    resolution: RoundResolution,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GameExport {
+    code: RoomCode,
+    rules: GameRules,
+    players: Vec<PlayerSummary>,
+    created_at_ms: u64,
+    rounds: Vec<RoundArchive>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RoundReveal {
+    imposter_ids: Vec<Uuid>,
+    location_id: u32,
+    location_name: String,
+    assignments: HashMap<Uuid, PlayerAssignmentView>,
+}
+
+/// Asked questions kept off the wire under `hide_history_from_imposter`.
+#[derive(Clone, Debug)]
+struct WithheldHistory {
+    questions: Vec<AskedQuestionView>,
+    readers: HashSet<Uuid>,
+}
+
+impl RoundPublicState {
+    fn without_history(mut self, readers: HashSet<Uuid>) -> Self {
+        self.withheld_history = Some(Box::new(WithheldHistory {
+            questions: std::mem::take(&mut self.asked_questions),
+            readers,
+        }));
+        self.history_hidden = true;
+        self
+    }
+
+    fn for_viewer(mut self, viewer: Option<Uuid>) -> Self {
+        if let Some(withheld) = self.withheld_history.take_if(|withheld| {
+            viewer.is_some_and(|player_id| withheld.readers.contains(&player_id))
+        }) {
+            self.asked_questions = withheld.questions;
+            self.history_hidden = false;
+        }
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DrawCount {
+    player_id: Uuid,
+    draws: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct VoteTally {
+    accused_id: Uuid,
+    votes: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -581,6 +2175,9 @@ struct PlayerAssignmentView {
     location_id: Option<u32>,
     location_name: Option<String>,
     role: Option<String>,
+    /// The imposter's shrinking shortlist under `imposter_narrowing`.
+    #[serde(default)]
+    candidate_locations: Option<Vec<LocationOption>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -594,11 +2191,7 @@ impl From<&QuestionPrompt> for QuestionView {
         Self {
             id: value.id.clone(),
             text: value.text.clone(),
-            categories: value
-                .categories
-                .iter()
-                .map(|category| category.to_lowercase())
-                .collect(),
+            categories: value.categories.clone(),
         }
     }
 }
@@ -611,6 +2204,16 @@ impl From<&AskedQuestion> for AskedQuestionView {
             categories: value.categories.clone(),
             asked_by: value.asked_by,
             asked_at_ms: timestamp_ms(value.asked_at),
+            skipped: value.skipped,
+            answers: value
+                .answers
+                .iter()
+                .map(|answer| AnswerView {
+                    player_id: answer.player_id,
+                    text: answer.text.clone(),
+                    at_ms: timestamp_ms(answer.answered_at),
+                })
+                .collect(),
         }
     }
 }
@@ -618,12 +2221,31 @@ impl From<&AskedQuestion> for AskedQuestionView {
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
     init_tracing();
-
-    let content = GameContent::load()?;
-    let state = Arc::new(AppState::new(content));
-    let lobby_ttl = lobby_ttl_duration();
+    clock_anchor();
+
+    let content = GameContent::load()?.with_player_cap(player_cap());
+    let state = Arc::new(
+        AppState::new(content)
+            .with_create_limit(RateLimiter::per_minute(create_rate_limit()))
+            .with_report_limit(RateLimiter::per_minute(report_rate_limit()))
+            .with_max_games(max_games())
+            .with_room_code_format(room_code_format())
+            .with_event_capacity(event_capacity())
+            .with_admin_token(admin_token())
+            .with_name_denylist(name_denylist())
+            .with_lobby_ttl(lobby_ttl_duration()),
+    );
+    if let Some(path) = persist_path() {
+        match state.load_games(&path).await {
+            Ok(count) => info!(count, path = %path.display(), "restored persisted lobbies"),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => warn!(error = %err, path = %path.display(), "failed to restore lobbies"),
+        }
+        state.spawn_persistence(path, persist_interval_duration());
+    }
     let cleanup_interval = cleanup_interval_duration();
-    state.spawn_cleanup(lobby_ttl, cleanup_interval);
+    state.spawn_cleanup(state.lobby_ttl, cleanup_interval);
+    state.spawn_idle_turn_sweep();
     let app = app_router(Arc::clone(&state));
 
     let port = std::env::var("PORT")
@@ -634,7 +2256,11 @@ async fn main() -> Result<(), AppError> {
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!("Listening on {}", addr);
 
-    axum::serve(tokio::net::TcpListener::bind(addr).await?, app).await?;
+    axum::serve(
+        tokio::net::TcpListener::bind(addr).await?,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
     Ok(())
 }
 
@@ -646,8 +2272,6 @@ fn init_tracing() {
 }
 
 fn lobby_ttl_duration() -> Duration {
-    const DEFAULT_TTL_SECS: u64 = 60 * 60;
-
     if let Some(seconds) = env_u64("LOBBY_TTL_SECONDS") {
         return Duration::from_secs(seconds);
     }
@@ -656,7 +2280,7 @@ fn lobby_ttl_duration() -> Duration {
         return Duration::from_secs(minutes.saturating_mul(60));
     }
 
-    Duration::from_secs(DEFAULT_TTL_SECS)
+    DEFAULT_LOBBY_TTL
 }
 
 fn cleanup_interval_duration() -> Duration {
@@ -669,6 +2293,99 @@ fn cleanup_interval_duration() -> Duration {
     Duration::from_secs(DEFAULT_INTERVAL_SECS)
 }
 
+fn max_games() -> usize {
+    env_u64("MAX_GAMES")
+        .map(|value| value.min(usize::MAX as u64) as usize)
+        .unwrap_or(DEFAULT_MAX_GAMES)
+}
+
+fn player_cap() -> u8 {
+    env_u64("MAX_PLAYERS_PER_GAME")
+        .map(|value| value.min(u8::MAX as u64) as u8)
+        .unwrap_or(DEFAULT_PLAYER_CAP)
+}
+
+fn event_capacity() -> usize {
+    env_u64("EVENT_CHANNEL_CAPACITY")
+        .map(|value| value.min(usize::MAX as u64) as usize)
+        .unwrap_or(DEFAULT_EVENT_CAPACITY)
+}
+
+fn report_rate_limit() -> u32 {
+    const DEFAULT_REPORTS_PER_MINUTE: u32 = 5;
+
+    env_u64("QUESTION_REPORT_RATE_PER_MINUTE")
+        .map(|value| value.min(u64::from(u32::MAX)) as u32)
+        .unwrap_or(DEFAULT_REPORTS_PER_MINUTE)
+}
+
+fn create_rate_limit() -> u32 {
+    const DEFAULT_GAMES_PER_MINUTE: u32 = 10;
+
+    env_u64("CREATE_GAME_RATE_PER_MINUTE")
+        .map(|value| value.min(u64::from(u32::MAX)) as u32)
+        .unwrap_or(DEFAULT_GAMES_PER_MINUTE)
+}
+
+fn room_code_format() -> RoomCodeFormat {
+    let length = env_u64("ROOM_CODE_LENGTH")
+        .map(|value| value.min(usize::MAX as u64) as usize)
+        .unwrap_or(RoomCode::LEGACY_LENGTH);
+    let alphabet = match std::env::var("ROOM_CODE_ALPHABET") {
+        Ok(raw) => RoomCodeAlphabet::parse(&raw).unwrap_or_else(|| {
+            warn!(value = %raw, "unknown ROOM_CODE_ALPHABET; using alphanumeric");
+            RoomCodeAlphabet::default()
+        }),
+        Err(_) => RoomCodeAlphabet::default(),
+    };
+    RoomCodeFormat::new(length, alphabet)
+}
+
+fn admin_token() -> Option<String> {
+    std::env::var("ADMIN_TOKEN")
+        .ok()
+        .filter(|raw| !raw.trim().is_empty())
+}
+
+/// Merges `NAME_DENYLIST` (comma-separated) with the JSON array of names at
+/// `NAME_DENYLIST_PATH`. An unreadable file is logged and skipped rather than
+/// refusing to start.
+fn name_denylist() -> NameDenylist {
+    let mut names: Vec<String> = std::env::var("NAME_DENYLIST")
+        .map(|raw| raw.split(',').map(str::to_owned).collect())
+        .unwrap_or_default();
+    if let Some(path) = std::env::var_os("NAME_DENYLIST_PATH").filter(|raw| !raw.is_empty()) {
+        let path = PathBuf::from(path);
+        let loaded = std::fs::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| {
+                serde_json::from_slice::<Vec<String>>(&bytes).map_err(|err| err.to_string())
+            });
+        match loaded {
+            Ok(file_names) => names.extend(file_names),
+            Err(error) => warn!(%error, path = %path.display(), "failed to load name deny-list"),
+        }
+    }
+    let denylist = NameDenylist::new(names);
+    if denylist.len() > 0 {
+        info!(count = denylist.len(), "loaded player name deny-list");
+    }
+    denylist
+}
+
+fn persist_path() -> Option<PathBuf> {
+    std::env::var_os("PERSIST_PATH")
+        .filter(|raw| !raw.is_empty())
+        .map(PathBuf::from)
+}
+
+fn persist_interval_duration() -> Duration {
+    const DEFAULT_INTERVAL_SECS: u64 = 30;
+
+    let seconds = env_u64("PERSIST_INTERVAL_SECONDS").unwrap_or(DEFAULT_INTERVAL_SECS);
+    Duration::from_secs(seconds.max(1))
+}
+
 fn env_u64(var: &str) -> Option<u64> {
     match std::env::var(var) {
         Ok(raw) => match raw.parse::<u64>() {
@@ -685,40 +2402,458 @@ fn env_u64(var: &str) -> Option<u64> {
 fn app_router(state: SharedState) -> Router {
     Router::new()
         .route("/healthz", get(health_check))
-        .route("/api/games", post(create_game))
+        .route("/api/status", get(server_status))
+        .route("/metrics", get(prometheus_metrics))
+        .route("/api/games", get(list_public_games).post(create_game))
         .route(
             "/api/games/:code",
-            get(fetch_game_details).patch(update_rules),
+            get(fetch_game_details)
+                .patch(update_rules)
+                .delete(force_close_game),
         )
         .route("/api/games/:code/join", post(join_game))
+        .route("/api/games/:code/ready", post(set_ready))
+        .route("/api/games/:code/bots", post(sync_bots))
+        .route("/api/games/:code/leave", post(leave_game))
+        .route("/api/games/:code/keepalive", post(keep_lobby_alive))
+        .route("/api/games/:code/reconnect", post(reconnect_player))
+        .route("/api/games/:code/kick", post(kick_player))
+        .route("/api/games/:code/leader", post(transfer_leader))
+        .route("/api/games/:code/host/transfer", post(transfer_host))
+        .route("/api/games/:code/host/claim", post(claim_host))
+        .route("/api/games/:code/rematch", post(rematch_game))
+        .route("/api/games/:code/rename", post(rename_player))
         .route("/api/games/:code/start", post(start_game))
+        .route("/api/games/:code/can-start", get(can_start_game))
         .route("/api/games/:code/abort", post(abort_game))
+        .route("/api/games/:code/history", get(get_round_history))
+        .route("/api/games/:code/export", get(export_game))
+        .route("/api/games/:code/scoreboard", get(get_scoreboard))
         .route("/api/games/:code/round", get(get_round_state))
+        .route("/api/games/:code/me", get(get_player_view))
         .route("/api/games/:code/stream", get(stream_game))
-        .route("/api/games/:code/round/question", post(draw_next_question))
+        .route("/api/games/:code/spectate", get(spectate_game))
+        .route("/api/games/:code/events", get(game_event_stream))
+        .route(
+            "/api/games/:code/round/question",
+            get(get_current_question).post(draw_next_question),
+        )
+        .route("/api/games/:code/round/skip", post(skip_turn))
+        .route(
+            "/api/games/:code/round/skip-question",
+            post(vote_skip_question),
+        )
+        .route("/api/games/:code/round/pause", post(pause_round))
+        .route("/api/games/:code/round/resume", post(resume_round))
+        .route("/api/games/:code/round/peek", post(peek_location))
+        .route("/api/games/:code/round/answer", post(submit_answer))
         .route("/api/games/:code/round/guess", post(submit_guess))
         .route("/api/games/:code/round/next", post(start_next_round))
         .route(
-            "/api/games/:code/round/assignment/:player_id",
-            get(get_assignment),
+            "/api/games/:code/round/my-assignment",
+            get(get_my_assignment),
+        )
+        .route(
+            "/api/games/:code/round/assignments",
+            post(get_all_assignments),
         )
         .route("/api/games/:code/locations", get(get_game_locations))
+        .route(
+            "/api/games/:code/content/questions",
+            patch(add_custom_questions),
+        )
+        .route(
+            "/api/games/:code/content/locations",
+            post(add_custom_locations),
+        )
         .route("/api/content/categories", get(get_question_categories))
+        .route("/api/content/questions", get(get_content_questions))
+        .route("/api/content/stats", get(get_content_stats))
+        .route("/api/content/questions/:id/report", post(report_question))
+        .route("/api/admin/question-reports", get(get_question_reports))
+        .route(
+            "/api/sessions/:id/leaderboard",
+            get(get_session_leaderboard),
+        )
+        .route("/api/content/locations", get(get_content_locations))
+        .fallback(route_not_found)
+        .method_not_allowed_fallback(method_not_allowed)
         .with_state(state)
         .layer(CorsLayer::permissive())
-        .layer(TraceLayer::new_for_http())
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+}
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Span for one HTTP request, tagged with its `x-request-id` (taken from the
+/// client or generated) and, on game routes, the room code so a lobby's
+/// actions can be followed across requests and sockets.
+fn request_span<B>(request: &axum::http::Request<B>) -> Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let span = info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id,
+        room = tracing::field::Empty,
+    );
+    if let Some(room) = room_from_path(request.uri().path()) {
+        span.record("room", room.to_ascii_uppercase());
+    }
+    span
+}
+
+fn room_from_path(path: &str) -> Option<&str> {
+    path.strip_prefix("/api/games/")?
+        .split('/')
+        .next()
+        .filter(|code| !code.is_empty())
 }
 
+const DEFAULT_MAX_GAMES: usize = 5_000;
+/// Idle time after which a lobby outside a round is swept; zero disables it.
+const DEFAULT_LOBBY_TTL: Duration = Duration::from_secs(60 * 60);
+const DEFAULT_PLAYER_CAP: u8 = 16;
+const MIN_PLAYER_CAP: u8 = 3;
+/// Per-game broadcast buffer. Subscribers that fall further behind than this
+/// are resynced with a full snapshot.
+const DEFAULT_EVENT_CAPACITY: usize = 64;
+const MIN_EVENT_CAPACITY: usize = 16;
+
 struct AppState {
     games: RwLock<HashMap<RoomCode, Game>>,
     content: Arc<GameContent>,
+    content_stats: ContentStats,
+    create_limiter: RateLimiter,
+    max_games: usize,
+    room_codes: RoomCodeFormat,
+    event_capacity: usize,
+    admin_token: Option<String>,
+    name_denylist: NameDenylist,
+    started_at: Instant,
+    metrics: Arc<Metrics>,
+    sessions: Arc<Mutex<HashMap<Uuid, Session>>>,
+    /// Player reports against built-in questions, keyed by question id.
+    question_reports: RwLock<HashMap<String, QuestionReports>>,
+    report_limiter: RateLimiter,
+    presence: PresenceRoster,
+    lobby_ttl: Duration,
+}
+
+/// Reports filed against one question: a running count plus the most
+/// recent reasons, for operators reviewing the catalog.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct QuestionReports {
+    count: u32,
+    recent_reasons: Vec<String>,
+}
+
+impl QuestionReports {
+    const MAX_RECENT_REASONS: usize = 20;
+
+    fn record(&mut self, reason: String) {
+        self.count = self.count.saturating_add(1);
+        if self.recent_reasons.len() >= Self::MAX_RECENT_REASONS {
+            self.recent_reasons.remove(0);
+        }
+        self.recent_reasons.push(reason);
+    }
+}
+
+/// Process-wide counters exported in Prometheus text format on `/metrics`.
+#[derive(Default)]
+struct Metrics {
+    games_created: AtomicU64,
+    crew_wins: AtomicU64,
+    imposter_wins: AtomicU64,
+    connected_subscribers: AtomicU64,
+}
+
+impl Metrics {
+    fn record_resolution(&self, resolution: &RoundResolution) {
+        let counter = match resolution.winner {
+            RoundWinner::Crew => &self.crew_wins,
+            RoundWinner::Imposter => &self.imposter_wins,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn render(&self, active_games: usize) -> String {
+        let crew = self.crew_wins.load(Ordering::Relaxed);
+        let imposter = self.imposter_wins.load(Ordering::Relaxed);
+        format!(
+            "# HELP imposter_games_created_total Lobbies created since the server started.\n\
+             # TYPE imposter_games_created_total counter\n\
+             imposter_games_created_total {}\n\
+             # HELP imposter_rounds_resolved_total Rounds resolved, by winning side.\n\
+             # TYPE imposter_rounds_resolved_total counter\n\
+             imposter_rounds_resolved_total{{winner=\"crew\"}} {}\n\
+             imposter_rounds_resolved_total{{winner=\"imposter\"}} {}\n\
+             # HELP imposter_active_games Lobbies currently held in memory.\n\
+             # TYPE imposter_active_games gauge\n\
+             imposter_active_games {}\n\
+             # HELP imposter_connected_subscribers Open realtime WebSocket connections.\n\
+             # TYPE imposter_connected_subscribers gauge\n\
+             imposter_connected_subscribers {}\n",
+            self.games_created.load(Ordering::Relaxed),
+            crew,
+            imposter,
+            active_games,
+            self.connected_subscribers.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// Per-IP token bucket. A limit of zero disables throttling entirely.
+struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+    fn per_minute(limit: u32) -> Self {
+        Self {
+            capacity: f64::from(limit),
+            refill_per_sec: f64::from(limit) / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn check(&self, client: IpAddr, refusal: &str) -> Result<(), AppError> {
+        if self.capacity <= 0.0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        if buckets.len() >= Self::MAX_TRACKED_CLIENTS {
+            let (capacity, refill) = (self.capacity, self.refill_per_sec);
+            buckets.retain(|_, bucket| {
+                let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+                bucket.tokens + elapsed * refill < capacity
+            });
+        }
+        if buckets.len() >= Self::MAX_TRACKED_CLIENTS {
+            // Still full of drained buckets: forget the least recently seen
+            // tenth rather than growing without bound.
+            let mut ages: Vec<Instant> = buckets.values().map(|bucket| bucket.updated_at).collect();
+            let cutoff_index = Self::MAX_TRACKED_CLIENTS / 10;
+            let (_, cutoff, _) = ages.select_nth_unstable(cutoff_index);
+            let cutoff = *cutoff;
+            buckets.retain(|_, bucket| bucket.updated_at > cutoff);
+        }
+
+        let bucket = buckets.entry(client).or_insert(TokenBucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.updated_at = now;
+
+        if bucket.tokens < 1.0 {
+            return Err(AppError::TooManyRequests(
+                ErrorCode::RateLimited,
+                refusal.to_owned(),
+            ));
+        }
+        bucket.tokens -= 1.0;
+        Ok(())
+    }
+}
+
+/// The address a request is rate limited under. Requests that arrive without
+/// one all share a single bucket instead of skipping the limiter.
+fn client_ip(client: Option<ConnectInfo<SocketAddr>>) -> IpAddr {
+    client.map_or(IpAddr::from([0, 0, 0, 0]), |ConnectInfo(addr)| addr.ip())
+}
+
+/// Wins carried across the sequential games a group plays under one `session_id`.
+struct Session {
+    games_played: u32,
+    /// Keyed by reconnect token, which players carry from game to game.
+    standings: HashMap<Uuid, SessionStanding>,
+    last_active: Instant,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            games_played: 0,
+            standings: HashMap::new(),
+            last_active: Instant::now(),
+        }
+    }
+}
+
+/// Where resolved rounds are tallied. Every lobby holds one, so
+/// `conclude_round` counts each round once however it ended.
+#[derive(Clone, Default)]
+struct RoundLedger {
+    metrics: Arc<Metrics>,
+    sessions: Arc<Mutex<HashMap<Uuid, Session>>>,
+}
+
+impl RoundLedger {
+    /// Counts the round in the metrics and folds it into the game's session,
+    /// if it belongs to one.
+    fn record(&self, game: &Game, resolution: &RoundResolution) {
+        self.metrics.record_resolution(resolution);
+        let Some(session_id) = game.session_id else {
+            return;
+        };
+        let Some(round) = game.current_round.as_ref() else {
+            return;
+        };
+        let mut sessions = self.sessions.lock().unwrap_or_else(|err| err.into_inner());
+        let session = sessions.entry(session_id).or_default();
+        session.last_active = Instant::now();
+        for (player_id, assignment) in &round.assignments {
+            let Some(player) = game.players.get(player_id) else {
+                continue;
+            };
+            if player.is_bot {
+                continue;
+            }
+            let standing = session
+                .standings
+                .entry(player.reconnect_token)
+                .or_insert_with(|| SessionStanding {
+                    name: player.name.clone(),
+                    crew_wins: 0,
+                    imposter_wins: 0,
+                    total_wins: 0,
+                    rounds_played: 0,
+                });
+            standing.name = player.name.clone();
+            standing.rounds_played = standing.rounds_played.saturating_add(1);
+            match (resolution.winner, assignment) {
+                (RoundWinner::Crew, PlayerRoleAssignment::Civilian { .. }) => {
+                    standing.crew_wins = standing.crew_wins.saturating_add(1);
+                }
+                (RoundWinner::Imposter, PlayerRoleAssignment::Imposter) => {
+                    standing.imposter_wins = standing.imposter_wins.saturating_add(1);
+                }
+                _ => continue,
+            }
+            standing.total_wins = standing.total_wins.saturating_add(1);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SessionStanding {
+    name: String,
+    crew_wins: u32,
+    imposter_wins: u32,
+    total_wins: u32,
+    rounds_played: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionLeaderboard {
+    session_id: Uuid,
+    games_played: u32,
+    players: Vec<SessionStanding>,
 }
 
 impl AppState {
     fn new(content: GameContent) -> Self {
         Self {
             games: RwLock::new(HashMap::new()),
+            content_stats: content.stats(),
             content: Arc::new(content),
+            create_limiter: RateLimiter::per_minute(0),
+            max_games: DEFAULT_MAX_GAMES,
+            room_codes: RoomCodeFormat::default(),
+            event_capacity: DEFAULT_EVENT_CAPACITY,
+            admin_token: None,
+            name_denylist: NameDenylist::default(),
+            started_at: Instant::now(),
+            metrics: Arc::default(),
+            sessions: Arc::default(),
+            question_reports: RwLock::new(HashMap::new()),
+            report_limiter: RateLimiter::per_minute(0),
+            presence: PresenceRoster::default(),
+            lobby_ttl: DEFAULT_LOBBY_TTL,
+        }
+    }
+
+    fn with_max_games(mut self, max_games: usize) -> Self {
+        self.max_games = max_games;
+        self
+    }
+
+    fn with_create_limit(mut self, limiter: RateLimiter) -> Self {
+        self.create_limiter = limiter;
+        self
+    }
+
+    fn with_report_limit(mut self, limiter: RateLimiter) -> Self {
+        self.report_limiter = limiter;
+        self
+    }
+
+    fn with_lobby_ttl(mut self, ttl: Duration) -> Self {
+        self.lobby_ttl = ttl;
+        self
+    }
+
+    /// When the sweep may reclaim `game` if nothing else happens; `None`
+    /// when lobbies never expire.
+    fn expires_at_ms(&self, game: &Game) -> Option<u64> {
+        (!self.lobby_ttl.is_zero()).then(|| instant_ms(game.last_active + self.lobby_ttl))
+    }
+
+    fn with_room_code_format(mut self, format: RoomCodeFormat) -> Self {
+        self.room_codes = format;
+        self
+    }
+
+    fn with_event_capacity(mut self, capacity: usize) -> Self {
+        self.event_capacity = capacity.max(MIN_EVENT_CAPACITY);
+        self
+    }
+
+    fn with_admin_token(mut self, token: Option<String>) -> Self {
+        self.admin_token = token;
+        self
+    }
+
+    fn with_name_denylist(mut self, denylist: NameDenylist) -> Self {
+        self.name_denylist = denylist;
+        self
+    }
+
+    /// Operator routes are refused outright when no `ADMIN_TOKEN` is configured.
+    fn ensure_admin(&self, headers: &HeaderMap) -> Result<(), AppError> {
+        let provided = headers
+            .get(ADMIN_TOKEN_HEADER)
+            .and_then(|value| value.to_str().ok());
+        match (self.admin_token.as_deref(), provided) {
+            (Some(expected), Some(provided))
+                if constant_time_eq(expected.as_bytes(), provided.as_bytes()) =>
+            {
+                Ok(())
+            }
+            _ => Err(AppError::Forbidden(
+                ErrorCode::InvalidAdminToken,
+                "a valid admin token is required".into(),
+            )),
         }
     }
 
@@ -726,30 +2861,82 @@ impl AppState {
         Arc::clone(&self.content)
     }
 
+    /// The built-in content in `locale`, or the default locale when absent.
+    fn localized_content(&self, locale: Option<&str>) -> Result<Arc<GameContent>, AppError> {
+        match locale {
+            Some(locale) => Ok(self.content.for_locale(&normalize_locale(locale)?)),
+            None => Ok(self.content()),
+        }
+    }
+
+    fn ledger(&self) -> RoundLedger {
+        RoundLedger {
+            metrics: Arc::clone(&self.metrics),
+            sessions: Arc::clone(&self.sessions),
+        }
+    }
+
+    fn attach_session(&self, session_id: Uuid) {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|err| err.into_inner());
+        let session = sessions.entry(session_id).or_default();
+        session.games_played = session.games_played.saturating_add(1);
+        session.last_active = Instant::now();
+    }
+
+    fn session_leaderboard(&self, session_id: Uuid) -> Result<SessionLeaderboard, AppError> {
+        let sessions = self.sessions.lock().unwrap_or_else(|err| err.into_inner());
+        let session = sessions.get(&session_id).ok_or_else(|| {
+            AppError::NotFound(ErrorCode::SessionNotFound, "session not found".into())
+        })?;
+        let mut players: Vec<SessionStanding> = session.standings.values().cloned().collect();
+        players.sort_by(|a, b| {
+            b.total_wins
+                .cmp(&a.total_wins)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        Ok(SessionLeaderboard {
+            session_id,
+            games_played: session.games_played,
+            players,
+        })
+    }
+
     async fn purge_expired_lobbies(&self, ttl: Duration) -> usize {
         if ttl.is_zero() {
             return 0;
         }
 
         let mut games = self.games.write().await;
-        let now = SystemTime::now();
+        let now = Instant::now();
         let expired: Vec<RoomCode> = games
             .iter()
             .filter_map(|(code, game)| {
-                if !matches!(game.phase, GamePhase::Lobby | GamePhase::AwaitingNextRound) {
+                if game.phase == GamePhase::InRound {
                     return None;
                 }
-                match now.duration_since(game.last_active) {
-                    Ok(elapsed) if elapsed >= ttl => Some(code.clone()),
-                    _ => None,
-                }
+                (now.saturating_duration_since(game.last_active) >= ttl).then(|| code.clone())
             })
             .collect();
 
         for code in &expired {
-            games.remove(code);
+            if let Some(game) = games.remove(code) {
+                let _ = game.events.send(GameEvent::Closed {
+                    reason: "this lobby expired after sitting idle".into(),
+                });
+            }
         }
 
+        // A session lives as long as one of its lobbies, then for one TTL more
+        // so the group can start the next game.
+        let live: HashSet<Uuid> = games.values().filter_map(|game| game.session_id).collect();
+        self.sessions
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .retain(|session_id, session| {
+                live.contains(session_id)
+                    || now.saturating_duration_since(session.last_active) < ttl
+            });
+
         if !expired.is_empty() {
             info!(count = expired.len(), "expired inactive lobbies");
         }
@@ -778,6 +2965,221 @@ impl AppState {
             }
         });
     }
+
+    /// Skips the turn of every current-turn player who has gone quiet, so one
+    /// dropped connection can't freeze a round.
+    async fn skip_idle_turns(self: &Arc<Self>) -> usize {
+        let shared = self.content();
+        let mut games = self.games.write().await;
+        let now = Instant::now();
+        let mut skipped = 0;
+        for game in games.values_mut() {
+            let Some(player_id) = game.idle_turn_player(now) else {
+                continue;
+            };
+            let content = game.content(&shared);
+            let Ok(round) = game.skip_turn(false, content.as_ref()) else {
+                continue;
+            };
+            info!(room = %game.code, player = %player_id, "skipped idle player's turn");
+            schedule_turn_timer(self, game);
+            announce_turn(game, &round);
+            let _ = game.events.send(GameEvent::Round { round: Some(round) });
+            skipped += 1;
+        }
+        skipped
+    }
+
+    fn spawn_idle_turn_sweep(self: &Arc<Self>) {
+        let state = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(IDLE_TURN_SWEEP_INTERVAL);
+            loop {
+                ticker.tick().await;
+                let _ = state.skip_idle_turns().await;
+            }
+        });
+    }
+
+    /// Writes every lobby that is not mid-round to `path`. Active rounds are
+    /// skipped because their timers and secret assignments cannot be resumed.
+    async fn save_games(&self, path: &FsPath) -> io::Result<usize> {
+        let persisted: Vec<PersistedGame> = {
+            let games = self.games.read().await;
+            games
+                .values()
+                .filter(|game| game.phase != GamePhase::InRound)
+                .map(PersistedGame::from)
+                .collect()
+        };
+        let encoded = serde_json::to_vec(&persisted).map_err(io::Error::other)?;
+        let staging = path.with_extension("tmp");
+        tokio::fs::write(&staging, encoded).await?;
+        tokio::fs::rename(&staging, path).await?;
+        Ok(persisted.len())
+    }
+
+    async fn load_games(&self, path: &FsPath) -> io::Result<usize> {
+        let raw = tokio::fs::read(path).await?;
+        let persisted: Vec<PersistedGame> =
+            serde_json::from_slice(&raw).map_err(io::Error::other)?;
+        let mut games = self.games.write().await;
+        let count = persisted.len();
+        for entry in persisted {
+            let game = entry.into_game(&self.content, self.event_capacity, self.ledger());
+            games.insert(game.code.clone(), game);
+        }
+        Ok(count)
+    }
+
+    fn spawn_persistence(self: &Arc<Self>, path: PathBuf, interval: Duration) {
+        info!(path = %path.display(), "persisting lobbies to disk");
+        let state = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = state.save_games(&path).await {
+                    warn!(error = %err, path = %path.display(), "failed to persist lobbies");
+                }
+            }
+        });
+    }
+}
+
+/// On-disk form of a [`Game`] between rounds. Broadcast channels, timers and
+/// spectator counts are rebuilt on load.
+#[derive(Serialize, Deserialize)]
+struct PersistedGame {
+    code: RoomCode,
+    host_token: Uuid,
+    rules: GameRules,
+    #[serde(default)]
+    rules_version: u32,
+    leader_id: Uuid,
+    #[serde(default)]
+    host_player_id: Option<Uuid>,
+    players: Vec<Player>,
+    created_at: SystemTime,
+    last_active: SystemTime,
+    round_counter: u32,
+    phase: GamePhase,
+    last_round: Option<RoundSummary>,
+    round_history: Vec<RoundSummary>,
+    #[serde(default)]
+    round_archives: Vec<RoundArchive>,
+    location_pool: Vec<LocationDefinition>,
+    used_location_ids: HashSet<u32>,
+    custom_questions: Vec<QuestionPrompt>,
+    custom_locations: Vec<LocationDefinition>,
+    #[serde(default)]
+    session_id: Option<Uuid>,
+    #[serde(default)]
+    departed_players: HashMap<Uuid, PlayerWins>,
+    #[serde(default)]
+    seed: Option<u64>,
+}
+
+impl From<&Game> for PersistedGame {
+    fn from(game: &Game) -> Self {
+        Self {
+            code: game.code.clone(),
+            host_token: game.host_token,
+            rules: game.rules.clone(),
+            rules_version: game.rules_version,
+            leader_id: game.leader_id,
+            host_player_id: Some(game.host_player_id),
+            players: game.players.values().cloned().collect(),
+            created_at: game.created_at,
+            last_active: wall_time(game.last_active),
+            round_counter: game.round_counter,
+            phase: game.phase,
+            last_round: game.last_round.clone(),
+            round_history: game.round_history.clone(),
+            round_archives: game.round_archives.clone(),
+            location_pool: game.location_pool.clone(),
+            used_location_ids: game.used_location_ids.clone(),
+            custom_questions: game.custom_questions.clone(),
+            custom_locations: game.custom_locations.clone(),
+            session_id: game.session_id,
+            departed_players: game.departed_players.clone(),
+            seed: game.seed,
+        }
+    }
+}
+
+/// Seeded games restart their generator at each reload, offset by the rounds
+/// already played so a replay does not repeat earlier draws.
+fn seeded_rng(seed: Option<u64>, round_counter: u32) -> StdRng {
+    seed.map_or_else(StdRng::from_entropy, |seed| {
+        StdRng::seed_from_u64(seed.wrapping_add(u64::from(round_counter)))
+    })
+}
+
+impl PersistedGame {
+    fn into_game(
+        self,
+        shared: &Arc<GameContent>,
+        event_capacity: usize,
+        ledger: RoundLedger,
+    ) -> Game {
+        let (events, _) = broadcast::channel(event_capacity);
+        let mut game = Game {
+            code: self.code,
+            host_token: self.host_token,
+            rules: self.rules,
+            rules_version: self.rules_version,
+            leader_id: self.leader_id,
+            host_player_id: self.host_player_id.unwrap_or(self.leader_id),
+            players: self
+                .players
+                .into_iter()
+                .map(|player| (player.id, player))
+                .collect(),
+            created_at: self.created_at,
+            last_active: instant_at(self.last_active),
+            round_counter: self.round_counter,
+            // The reveal lives on the dropped round state, so resume after it.
+            phase: match self.phase {
+                GamePhase::Reveal => GamePhase::AwaitingNextRound,
+                phase => phase,
+            },
+            current_round: None,
+            last_round: self.last_round,
+            round_history: self.round_history,
+            round_archives: self.round_archives,
+            location_pool: self.location_pool,
+            used_location_ids: self.used_location_ids,
+            events,
+            round_timer: None,
+            turn_timer: None,
+            bot_timer: None,
+            narrowing_timer: None,
+            rng: seeded_rng(self.seed, self.round_counter),
+            seed: self.seed,
+            spectator_count: 0,
+            custom_questions: self.custom_questions,
+            custom_locations: self.custom_locations,
+            custom_content: None,
+            session_id: self.session_id,
+            departed_players: self.departed_players,
+            ledger,
+        };
+        if !game.custom_questions.is_empty() || !game.custom_locations.is_empty() {
+            game.rebuild_custom_content(shared);
+        }
+        // The built-in catalog may have changed since this lobby was saved;
+        // nobody is around to fix the rules, so draw from every category.
+        if game
+            .content(shared)
+            .questions_in(&game.rules.question_categories)
+            .is_empty()
+        {
+            warn!(room = %game.code, "saved question categories match nothing; widening to all");
+            game.rules.question_categories.clear();
+        }
+        game
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -785,23 +3187,33 @@ impl AppState {
 struct RoomCode(String);
 
 impl RoomCode {
-    const LENGTH: usize = 4;
-
-    fn new(value: String) -> Result<Self, AppError> {
-        if value.len() != Self::LENGTH || !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+    /// Length of codes issued before the format became configurable; these
+    /// stay valid so a restart with new settings doesn't strand live lobbies.
+    const LEGACY_LENGTH: usize = 4;
+
+    fn new(value: String, format: &RoomCodeFormat) -> Result<Self, AppError> {
+        let value = value.to_ascii_uppercase();
+        let legacy =
+            value.len() == Self::LEGACY_LENGTH && value.chars().all(|c| c.is_ascii_alphanumeric());
+        if !legacy && !format.accepts(&value) {
             return Err(AppError::BadRequest(
-                "room codes are 4 alphanumeric characters".into(),
+                ErrorCode::InvalidRoomCode,
+                format!(
+                    "room codes are {} {} characters",
+                    format.length,
+                    format.alphabet.describe()
+                ),
             ));
         }
-        Ok(Self(value.to_uppercase()))
+        Ok(Self(value))
     }
 
-    fn generate(existing: &HashSet<RoomCode>) -> Self {
+    fn generate(existing: &HashSet<RoomCode>, format: &RoomCodeFormat) -> Self {
         let mut rng = thread_rng();
+        let alphabet = format.alphabet.characters();
         loop {
-            let candidate: String = (0..Self::LENGTH)
-                .map(|_| rng.sample(Alphanumeric) as char)
-                .map(|c| c.to_ascii_uppercase())
+            let candidate: String = (0..format.length)
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len())] as char)
                 .collect();
             let code = Self(candidate);
             if !existing.contains(&code) {
@@ -811,6 +3223,67 @@ impl RoomCode {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum RoomCodeAlphabet {
+    #[default]
+    Alphanumeric,
+    /// Crockford-style base32 without the look-alikes `0`, `1`, `I`, `L`, `O` and `U`.
+    Unambiguous,
+}
+
+impl RoomCodeAlphabet {
+    fn characters(self) -> &'static [u8] {
+        match self {
+            Self::Alphanumeric => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789",
+            Self::Unambiguous => b"23456789ABCDEFGHJKMNPQRSTVWXYZ",
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Alphanumeric => "alphanumeric",
+            Self::Unambiguous => "unambiguous alphanumeric",
+        }
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "alphanumeric" => Some(Self::Alphanumeric),
+            "unambiguous" | "crockford" => Some(Self::Unambiguous),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct RoomCodeFormat {
+    length: usize,
+    alphabet: RoomCodeAlphabet,
+}
+
+impl RoomCodeFormat {
+    const MIN_LENGTH: usize = 4;
+    const MAX_LENGTH: usize = 8;
+
+    fn new(length: usize, alphabet: RoomCodeAlphabet) -> Self {
+        Self {
+            length: length.clamp(Self::MIN_LENGTH, Self::MAX_LENGTH),
+            alphabet,
+        }
+    }
+
+    fn accepts(&self, value: &str) -> bool {
+        let alphabet = self.alphabet.characters();
+        value.len() == self.length && value.bytes().all(|byte| alphabet.contains(&byte))
+    }
+}
+
+impl Default for RoomCodeFormat {
+    fn default() -> Self {
+        Self::new(RoomCode::LEGACY_LENGTH, RoomCodeAlphabet::default())
+    }
+}
+
 impl fmt::Display for RoomCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.0)
@@ -822,40 +3295,160 @@ struct Game {
     code: RoomCode,
     host_token: Uuid,
     rules: GameRules,
+    /// Bumped on every rules change so concurrent editors can detect conflicts.
+    rules_version: u32,
     leader_id: Uuid,
+    /// The seat the host token was issued to; `leader_id` can move without it.
+    host_player_id: Uuid,
     players: HashMap<Uuid, Player>,
     created_at: SystemTime,
-    last_active: SystemTime,
+    last_active: Instant,
     round_counter: u32,
     phase: GamePhase,
     current_round: Option<RoundState>,
     last_round: Option<RoundSummary>,
     round_history: Vec<RoundSummary>,
+    /// Full record of each resolved round, served by the export endpoint.
+    round_archives: Vec<RoundArchive>,
     location_pool: Vec<LocationDefinition>,
     used_location_ids: HashSet<u32>,
     events: broadcast::Sender<GameEvent>,
+    round_timer: Option<AbortHandle>,
+    turn_timer: Option<AbortHandle>,
+    bot_timer: Option<AbortHandle>,
+    narrowing_timer: Option<AbortHandle>,
+    /// Drives every random choice in the game; seeded on request for replays.
+    rng: StdRng,
+    seed: Option<u64>,
+    spectator_count: u32,
+    custom_questions: Vec<QuestionPrompt>,
+    custom_locations: Vec<LocationDefinition>,
+    custom_content: Option<Arc<GameContent>>,
+    session_id: Option<Uuid>,
+    /// Records of players who left, keyed by reconnect token, kept only under
+    /// `restore_returning_players`.
+    departed_players: HashMap<Uuid, PlayerWins>,
+    ledger: RoundLedger,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GameSnapshot {
     lobby: GameLobby,
     round: Option<RoundPublicState>,
+    /// Set only on the first snapshot a socket receives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    protocol_version: Option<u16>,
+    /// How binary frames on this socket are encoded, when the client's
+    /// protocol allows them. Set alongside `protocol_version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    binary_encoding: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PlayerView {
+    lobby: GameLobby,
+    round: Option<RoundPublicState>,
+    assignment: Option<PlayerAssignmentView>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum GameEvent {
-    Snapshot(GameSnapshot),
-    Lobby { lobby: GameLobby },
-    Round { round: Option<RoundPublicState> },
+    Snapshot(Box<GameSnapshot>),
+    Lobby {
+        lobby: GameLobby,
+    },
+    Round {
+        round: Option<RoundPublicState>,
+    },
+    Kicked {
+        player_id: Uuid,
+    },
+    Votes {
+        round_number: u32,
+        tallies: Vec<VoteTally>,
+    },
+    Chat {
+        player_id: Uuid,
+        name: String,
+        text: String,
+        at_ms: u64,
+    },
+    QuestionDrawn {
+        question: QuestionView,
+        asked_by: Uuid,
+        next_turn_player_id: Uuid,
+    },
+    /// The turn moved on, by a draw or a skip; sent ahead of the round update.
+    TurnChanged {
+        current_turn_player_id: Uuid,
+        round_number: u32,
+    },
+    /// The host ended the round or reset the game, optionally saying why.
+    Aborted {
+        scope: AbortScope,
+        reason: Option<String>,
+    },
+    /// The question cap is spent; the crew must accuse before the deadline.
+    VoteForced {
+        round_number: u32,
+        deadline_ms: u64,
+    },
+    /// The host opened a rematch lobby; invited players reconnect to `code`
+    /// with their existing reconnect token.
+    Rematch {
+        code: RoomCode,
+    },
+    /// The lobby was shut down by an operator or emptied out; the socket
+    /// closes right after.
+    Closed {
+        reason: String,
+    },
+    /// A decoy was struck; imposter sockets get their `assignment` instead.
+    LocationsNarrowed {
+        round_number: u32,
+    },
+    /// Players with at least one realtime connection open, sent whenever a
+    /// connection opens or closes.
+    Presence {
+        connected: Vec<Uuid>,
+    },
+    /// Anonymous viewers watching the lobby, sent as they come and go.
+    Spectators {
+        count: u32,
+    },
+    /// Sent privately to its player after `whoami` or a narrowing.
+    Assignment {
+        assignment: PlayerAssignmentView,
+    },
+    /// Something went wrong with this connection. When `fatal` is false the
+    /// stream carries on (after lag, a fresh snapshot follows); otherwise
+    /// the socket closes right after with the matching close code.
+    Error {
+        code: ErrorCode,
+        message: String,
+        fatal: bool,
+    },
     Pong,
 }
 
-impl Game {
-    fn snapshot(&self) -> GameSnapshot {
-        GameSnapshot {
+const MAX_CHAT_LENGTH: usize = 280;
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum InboundMessage {
+    Chat { player_id: Uuid, text: String },
+    Whoami { player_id: Uuid },
+    Answer { player_id: Uuid, text: String },
+}
+
+impl Game {
+    fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
             lobby: self.lobby_view(),
             round: self.current_round_view(),
+            protocol_version: None,
+            binary_encoding: None,
         }
     }
 
@@ -865,11 +3458,66 @@ impl Game {
             .map(|round| round.public_state())
     }
 
+    fn snapshot_for(&self, viewer: Option<Uuid>) -> GameSnapshot {
+        let mut snapshot = self.snapshot();
+        snapshot.round = snapshot.round.map(|round| round.for_viewer(viewer));
+        snapshot
+    }
+
+    /// Everything one player needs on page load, read in a single pass.
+    fn player_view(&self, player_id: Uuid) -> Result<PlayerView, AppError> {
+        self.ensure_player(&player_id)?;
+        let GameSnapshot { lobby, round, .. } = self.snapshot_for(Some(player_id));
+        let assignment = self
+            .current_round
+            .as_ref()
+            .and_then(|round| round.assignment_for(&player_id));
+        Ok(PlayerView {
+            lobby,
+            round,
+            assignment,
+        })
+    }
+
+    fn chat_message(&self, player_id: Uuid, text: &str) -> Result<GameEvent, AppError> {
+        let player = self.players.get(&player_id).ok_or_else(|| {
+            AppError::Forbidden(ErrorCode::NotAPlayer, "player not part of this game".into())
+        })?;
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(AppError::BadRequest(
+                ErrorCode::ChatRequired,
+                "chat message required".into(),
+            ));
+        }
+        Ok(GameEvent::Chat {
+            player_id,
+            name: player.name.clone(),
+            text: text.chars().take(MAX_CHAT_LENGTH).collect(),
+            at_ms: timestamp_ms(SystemTime::now()),
+        })
+    }
+
+    /// Listing entry for the lobby browser; `None` unless the lobby is public and joinable.
+    fn public_summary(&self) -> Option<PublicGameSummary> {
+        if !self.rules.public || self.phase != GamePhase::Lobby {
+            return None;
+        }
+        Some(PublicGameSummary {
+            code: self.code.clone(),
+            player_count: self.players.len() as u32,
+            max_players: self.rules.max_players,
+            question_categories: self.rules.question_categories.clone(),
+            created_at_ms: timestamp_ms(self.created_at),
+        })
+    }
+
     fn lobby_view(&self) -> GameLobby {
         GameLobby {
             code: self.code.clone(),
             leader_id: self.leader_id,
             rules: self.rules.clone(),
+            rules_version: self.rules_version,
             players: self
                 .players
                 .values()
@@ -877,29 +3525,445 @@ impl Game {
                 .map(PlayerSummary::from)
                 .collect(),
             player_count: self.players.len() as u32,
+            spectator_count: self.spectator_count,
             created_at_ms: timestamp_ms(self.created_at),
             phase: self.phase,
             last_round: self.last_round.clone(),
-            round_history: self.round_history.clone(),
+            round_history: self.round_history
+                [self.round_history.len().saturating_sub(LOBBY_HISTORY_LIMIT)..]
+                .to_vec(),
+            total_rounds: self.round_history.len() as u32,
+            final_scoreboard: (self.phase == GamePhase::Finished).then(|| self.scoreboard()),
         }
     }
 
+    fn scoreboard(&self) -> Vec<ScoreboardEntry> {
+        let mut entries: Vec<ScoreboardEntry> = self
+            .players
+            .values()
+            .map(|player| {
+                let rounds = self
+                    .round_history
+                    .iter()
+                    .filter(|summary| summary.participants.contains(&player.id));
+                ScoreboardEntry {
+                    player_id: player.id,
+                    name: player.name.clone(),
+                    crew_wins: player.wins.crew,
+                    imposter_wins: player.wins.imposter,
+                    total_wins: player.wins.crew + player.wins.imposter,
+                    rounds_played: rounds.clone().count() as u32,
+                    correct_accusations: rounds
+                        .clone()
+                        .filter(|summary| {
+                            summary.resolution.outcome.successful_accuser() == Some(player.id)
+                        })
+                        .count() as u32,
+                    fastest_resolution_ms: rounds.filter_map(RoundSummary::duration_ms).min(),
+                }
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            b.total_wins
+                .cmp(&a.total_wins)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        entries
+    }
+
     fn ensure_host(&self, token: &Uuid) -> Result<(), AppError> {
         if &self.host_token != token {
-            return Err(AppError::Forbidden("host token invalid".into()));
+            return Err(AppError::Forbidden(
+                ErrorCode::InvalidHostToken,
+                "host token invalid".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_bot(&self, player_id: &Uuid) -> bool {
+        self.players
+            .get(player_id)
+            .is_some_and(|player| player.is_bot)
+    }
+
+    /// Adds or removes bots until the lobby holds `rules.bot_fill` of them,
+    /// never pushing it past `max_players`.
+    fn sync_bots(&mut self) -> Result<(), AppError> {
+        if self.phase != GamePhase::Lobby {
+            return Err(AppError::BadRequest(
+                ErrorCode::GameInProgress,
+                "bots can only be changed in the lobby".into(),
+            ));
+        }
+        let wanted = usize::from(self.rules.bot_fill);
+        let mut bots: Vec<Uuid> = self
+            .players
+            .values()
+            .filter(|player| player.is_bot && player.id != self.leader_id)
+            .map(|player| player.id)
+            .collect();
+        while bots.len() > wanted {
+            if let Some(bot_id) = bots.pop() {
+                self.players.remove(&bot_id);
+            }
+        }
+        let mut suffix = 1;
+        while bots.len() < wanted && self.players.len() < usize::from(self.rules.max_players) {
+            let name = format!("Bot {}", suffix);
+            suffix += 1;
+            if self.ensure_name_available(&name, None).is_err() {
+                continue;
+            }
+            let mut bot = Player::bot(name)?;
+            bot.join_seq = self.next_join_seq();
+            bots.push(bot.id);
+            self.players.insert(bot.id, bot);
         }
+        self.touch();
         Ok(())
     }
 
     fn ensure_player(&self, player_id: &Uuid) -> Result<(), AppError> {
         if !self.players.contains_key(player_id) {
-            return Err(AppError::Forbidden("player not part of this game".into()));
+            return Err(AppError::Forbidden(
+                ErrorCode::NotAPlayer,
+                "player not part of this game".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn new(
+        code: RoomCode,
+        host_token: Uuid,
+        host: Player,
+        rules: GameRules,
+        events: broadcast::Sender<GameEvent>,
+        seed: Option<u64>,
+        session_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            code,
+            host_token,
+            rules,
+            rules_version: 0,
+            leader_id: host.id,
+            host_player_id: host.id,
+            players: HashMap::from([(host.id, host)]),
+            created_at: SystemTime::now(),
+            last_active: Instant::now(),
+            round_counter: 0,
+            phase: GamePhase::Lobby,
+            current_round: None,
+            last_round: None,
+            round_history: Vec::new(),
+            round_archives: Vec::new(),
+            location_pool: Vec::new(),
+            used_location_ids: HashSet::new(),
+            events,
+            round_timer: None,
+            turn_timer: None,
+            bot_timer: None,
+            narrowing_timer: None,
+            rng: seeded_rng(seed, 0),
+            seed,
+            spectator_count: 0,
+            custom_questions: Vec::new(),
+            custom_locations: Vec::new(),
+            custom_content: None,
+            session_id,
+            departed_players: HashMap::new(),
+            ledger: RoundLedger::default(),
+        }
+    }
+
+    fn with_ledger(mut self, ledger: RoundLedger) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
+    /// Hands back a departed player's wins when they rejoin with their old
+    /// reconnect token, if the lobby restores returning players.
+    fn reclaim_departed(&mut self, reconnect_token: &Uuid) -> Option<PlayerWins> {
+        if !self.rules.restore_returning_players {
+            return None;
+        }
+        self.departed_players.remove(reconnect_token)
+    }
+
+    /// Whether only bots (or nobody) are left, so the lobby can be closed.
+    fn is_abandoned(&self) -> bool {
+        self.players.values().all(|player| player.is_bot)
+    }
+
+    /// Join sequence for the next player to take a seat. HashMap order is
+    /// arbitrary, so join order has to be recorded on each player.
+    fn next_join_seq(&self) -> u64 {
+        self.players
+            .values()
+            .map(|player| player.join_seq + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Checks that the caller holds `player_id`'s private action token.
+    /// `authorize` for callers that only read the game.
+    fn check_action_token(&self, player_id: &Uuid, action_token: &Uuid) -> Result<(), AppError> {
+        self.ensure_player(player_id)?;
+        if self
+            .players
+            .get(player_id)
+            .is_some_and(|player| &player.action_token == action_token)
+        {
+            return Ok(());
+        }
+        Err(AppError::Forbidden(
+            ErrorCode::InvalidActionToken,
+            "action token does not match this player".into(),
+        ))
+    }
+
+    /// Checks the action token and retires the seat's join id: once the
+    /// client has acted with its credentials, a retried join has nothing to
+    /// recover.
+    fn authorize(&mut self, player_id: &Uuid, action_token: &Uuid) -> Result<(), AppError> {
+        self.check_action_token(player_id, action_token)?;
+        if let Some(player) = self.players.get_mut(player_id) {
+            player.client_join_id = None;
+        }
+        Ok(())
+    }
+
+    fn player_for_join_id(&self, client_join_id: &Uuid, name: &str) -> Option<&Player> {
+        self.players.values().find(|player| {
+            player.client_join_id.as_ref() == Some(client_join_id) && player.name == name
+        })
+    }
+
+    fn player_for_token(&self, reconnect_token: &Uuid) -> Result<Uuid, AppError> {
+        self.players
+            .values()
+            .find(|player| &player.reconnect_token == reconnect_token)
+            .map(|player| player.id)
+            .ok_or_else(|| {
+                AppError::Forbidden(
+                    ErrorCode::InvalidReconnectToken,
+                    "reconnect token invalid".into(),
+                )
+            })
+    }
+
+    fn content(&self, shared: &Arc<GameContent>) -> Arc<GameContent> {
+        self.custom_content
+            .clone()
+            .unwrap_or_else(|| shared.for_locale(&self.rules.locale))
+    }
+
+    fn add_custom_questions(
+        &mut self,
+        shared: &Arc<GameContent>,
+        submissions: Vec<CustomQuestionInput>,
+    ) -> Result<(), AppError> {
+        if submissions.is_empty() {
+            return Err(AppError::BadRequest(
+                ErrorCode::NoQuestionsProvided,
+                "no questions provided".into(),
+            ));
+        }
+
+        let known: HashSet<String> = self.content(shared).categories.iter().cloned().collect();
+        // A host who narrowed the categories keeps that narrowing; new
+        // categories only join a selection that already covered everything.
+        let selects_everything = known
+            .iter()
+            .all(|category| self.rules.question_categories.contains(category));
+        let mut added_categories: Vec<String> = Vec::new();
+        let mut accepted: Vec<(String, Vec<String>)> = Vec::new();
+        for submission in submissions {
+            let text = submission.text.trim();
+            if text.is_empty() {
+                return Err(AppError::BadRequest(
+                    ErrorCode::QuestionRequired,
+                    "question text required".into(),
+                ));
+            }
+            if text.chars().count() > MAX_CUSTOM_QUESTION_LENGTH {
+                return Err(AppError::BadRequest(
+                    ErrorCode::QuestionTooLong,
+                    format!(
+                        "questions are limited to {} characters",
+                        MAX_CUSTOM_QUESTION_LENGTH
+                    ),
+                ));
+            }
+
+            let mut categories: Vec<String> = Vec::new();
+            for category in &submission.categories {
+                let display = category.trim();
+                if !display.is_empty()
+                    && !categories
+                        .iter()
+                        .any(|existing| existing.to_lowercase() == display.to_lowercase())
+                {
+                    categories.push(display.to_owned());
+                }
+            }
+            if categories.is_empty() {
+                categories.push(CUSTOM_QUESTION_CATEGORY.to_owned());
+            }
+            if self
+                .custom_questions
+                .iter()
+                .map(|existing| existing.text.as_str())
+                .chain(accepted.iter().map(|(existing, _)| existing.as_str()))
+                .any(|existing| existing.eq_ignore_ascii_case(text))
+            {
+                continue;
+            }
+            for category in &categories {
+                let normalized = category.to_lowercase();
+                if !known.contains(&normalized) && !added_categories.contains(&normalized) {
+                    added_categories.push(normalized);
+                }
+            }
+            accepted.push((text.to_owned(), categories));
+        }
+        if self.custom_questions.len() + accepted.len() > MAX_CUSTOM_QUESTIONS {
+            return Err(AppError::BadRequest(
+                ErrorCode::TooManyCustomQuestions,
+                format!(
+                    "lobbies can hold at most {} custom questions",
+                    MAX_CUSTOM_QUESTIONS
+                ),
+            ));
+        }
+
+        for (text, categories) in accepted {
+            self.custom_questions.push(QuestionPrompt {
+                id: format!("custom-{}", self.custom_questions.len() + 1),
+                text,
+                categories,
+            });
+        }
+
+        self.rebuild_custom_content(shared);
+        if selects_everything {
+            for category in added_categories {
+                if !self.rules.question_categories.contains(&category) {
+                    self.rules.question_categories.push(category);
+                }
+            }
+        }
+        self.touch();
+        Ok(())
+    }
+
+    fn add_custom_locations(
+        &mut self,
+        shared: &Arc<GameContent>,
+        submissions: Vec<CustomLocationInput>,
+    ) -> Result<(), AppError> {
+        if submissions.is_empty() {
+            return Err(AppError::BadRequest(
+                ErrorCode::NoLocationsProvided,
+                "no locations provided".into(),
+            ));
+        }
+        if self.custom_locations.len() + submissions.len() > MAX_CUSTOM_LOCATIONS {
+            return Err(AppError::BadRequest(
+                ErrorCode::TooManyCustomLocations,
+                format!(
+                    "lobbies can hold at most {} custom locations",
+                    MAX_CUSTOM_LOCATIONS
+                ),
+            ));
+        }
+
+        let mut next_id = self.content(shared).next_location_id();
+        let mut added = Vec::new();
+        for submission in submissions {
+            let name = submission.name.trim();
+            if name.is_empty() {
+                return Err(AppError::BadRequest(
+                    ErrorCode::LocationNameRequired,
+                    "location name required".into(),
+                ));
+            }
+            let mut roles: Vec<String> = Vec::new();
+            for role in &submission.roles {
+                let role = role.trim();
+                if !role.is_empty() && !roles.iter().any(|r| r.eq_ignore_ascii_case(role)) {
+                    roles.push(role.to_owned());
+                }
+            }
+            if roles.len() < 2 {
+                return Err(AppError::BadRequest(
+                    ErrorCode::NotEnoughRoles,
+                    format!("location {} needs at least two distinct roles", name),
+                ));
+            }
+            added.push(LocationDefinition {
+                id: next_id,
+                name: name.to_owned(),
+                roles,
+                tags: Vec::new(),
+            });
+            next_id = next_id.saturating_add(1);
+        }
+
+        let mut custom_locations = self.custom_locations.clone();
+        custom_locations.extend(added.iter().cloned());
+        let content = shared
+            .for_locale(&self.rules.locale)
+            .with_custom(&self.custom_questions, &custom_locations);
+        let rules = self.rules.clone().normalize(&content)?;
+
+        if !self.location_pool.is_empty() {
+            self.location_pool.extend(added);
         }
+        self.custom_locations = custom_locations;
+        self.custom_content = Some(Arc::new(content));
+        self.replace_rules(rules);
         Ok(())
     }
 
+    /// Bumps `rules_version` only when the rules actually changed, so an
+    /// untouched copy held elsewhere stays current.
+    fn replace_rules(&mut self, rules: GameRules) {
+        if rules != self.rules {
+            self.rules = rules;
+            self.rules_version = self.rules_version.wrapping_add(1);
+        }
+        self.touch();
+    }
+
+    fn rebuild_custom_content(&mut self, shared: &Arc<GameContent>) {
+        self.custom_content = Some(Arc::new(
+            shared
+                .for_locale(&self.rules.locale)
+                .with_custom(&self.custom_questions, &self.custom_locations),
+        ));
+    }
+
+    /// Re-reads custom content and the location pool after a locale change.
+    fn relocalize(&mut self, shared: &Arc<GameContent>) {
+        if self.custom_content.is_some() {
+            self.rebuild_custom_content(shared);
+        }
+        let content = self.content(shared);
+        for pooled in &mut self.location_pool {
+            if let Some(location) = content
+                .locations()
+                .find(|location| location.id == pooled.id)
+            {
+                *pooled = location.clone();
+            }
+        }
+    }
+
     fn touch(&mut self) {
-        self.last_active = SystemTime::now();
+        self.last_active = Instant::now();
     }
 
     fn location_options(&self) -> Vec<LocationOption> {
@@ -915,13 +3979,21 @@ impl Game {
     fn round_state(&self) -> Result<&RoundState, AppError> {
         self.current_round
             .as_ref()
-            .ok_or_else(|| AppError::BadRequest("no active round".into()))
+            .ok_or_else(|| AppError::BadRequest(ErrorCode::NoActiveRound, "no active round".into()))
+    }
+
+    /// The active round together with the game's RNG, borrowed side by side.
+    fn round_and_rng(&mut self) -> Result<(&mut RoundState, &mut StdRng), AppError> {
+        let round = self.current_round.as_mut().ok_or_else(|| {
+            AppError::BadRequest(ErrorCode::NoActiveRound, "no active round".into())
+        })?;
+        Ok((round, &mut self.rng))
     }
 
     fn round_state_mut(&mut self) -> Result<&mut RoundState, AppError> {
         self.current_round
             .as_mut()
-            .ok_or_else(|| AppError::BadRequest("no active round".into()))
+            .ok_or_else(|| AppError::BadRequest(ErrorCode::NoActiveRound, "no active round".into()))
     }
 
     fn public_round_state(&self) -> Result<RoundPublicState, AppError> {
@@ -932,31 +4004,112 @@ impl Game {
         self.ensure_player(&player_id)?;
         self.round_state()?
             .assignment_for(&player_id)
-            .ok_or_else(|| AppError::NotFound("assignment not found".into()))
+            .ok_or_else(|| {
+                AppError::NotFound(ErrorCode::AssignmentNotFound, "assignment not found".into())
+            })
+    }
+
+    /// Reveals every role at once; only the host may see this, for shared-device play.
+    fn host_assignments(
+        &self,
+        host_token: &Uuid,
+    ) -> Result<HashMap<Uuid, PlayerAssignmentView>, AppError> {
+        self.ensure_host(host_token)?;
+        Ok(self.round_state()?.all_assignments())
     }
 
-    fn begin_round(&mut self, content: &GameContent) -> Result<RoundPublicState, AppError> {
+    /// Every reason the next round cannot start yet, in the order `begin_round` checks them.
+    fn start_blockers(&self, content: &GameContent) -> Vec<(ErrorCode, String)> {
+        let mut blockers = Vec::new();
         match self.phase {
-            GamePhase::Lobby | GamePhase::AwaitingNextRound => {}
-            GamePhase::InRound => {
-                return Err(AppError::BadRequest("round already in progress".into()));
-            }
+            GamePhase::InRound => blockers.push((
+                ErrorCode::RoundInProgress,
+                "round already in progress".to_owned(),
+            )),
+            GamePhase::Finished => blockers.push((
+                ErrorCode::GameFinished,
+                "every round of this match has been played".to_owned(),
+            )),
+            GamePhase::Lobby | GamePhase::Reveal | GamePhase::AwaitingNextRound => {}
         }
 
-        if self.players.len() < 3 {
-            return Err(AppError::BadRequest(
-                "at least three players are required to start".into(),
+        if self.players.len() < usize::from(self.rules.min_players) {
+            blockers.push((
+                ErrorCode::NotEnoughPlayers,
+                format!(
+                    "at least {} players are required to start",
+                    self.rules.min_players
+                ),
             ));
         }
 
-        let mut rng = thread_rng();
+        if self.rules.require_all_ready && self.players.values().any(|player| !player.ready) {
+            blockers.push((
+                ErrorCode::PlayersNotReady,
+                "every player must be ready to start".to_owned(),
+            ));
+        }
+
+        let player_count = self.players.len();
+        let has_location = if self.location_pool.is_empty() {
+            content
+                .locations()
+                .any(|location| self.rules.allows_location(location, player_count))
+        } else {
+            self.location_pool
+                .iter()
+                .any(|location| self.rules.allows_location(location, player_count))
+        };
+        if !has_location {
+            blockers.push((
+                ErrorCode::NoLocationsAvailable,
+                "no locations support the current player count".to_owned(),
+            ));
+        }
+
+        if content
+            .questions_in(&self.rules.question_categories)
+            .is_empty()
+        {
+            blockers.push((
+                ErrorCode::NoQuestionsAvailable,
+                "no questions match the selected categories".to_owned(),
+            ));
+        }
+
+        blockers
+    }
+
+    fn begin_round(
+        &mut self,
+        content: &GameContent,
+        forced_imposter: Option<Uuid>,
+        forced_location: Option<u32>,
+    ) -> Result<RoundPublicState, AppError> {
+        if let Some((code, message)) = self.start_blockers(content).into_iter().next() {
+            return Err(AppError::BadRequest(code, message));
+        }
+        if forced_imposter.is_some_and(|player_id| !self.players.contains_key(&player_id)) {
+            return Err(AppError::BadRequest(
+                ErrorCode::InvalidPlayerId,
+                "forced imposter is not a player in this game".into(),
+            ));
+        }
 
         if self.location_pool.is_empty() {
             let pool_size =
                 usize::from(self.rules.location_pool_size).min(content.max_location_pool());
-            let pool = content.random_location_pool(pool_size, self.players.len(), &mut rng);
+            let pool = content.random_location_pool(
+                pool_size,
+                self.players.len(),
+                self.rules.allow_duplicate_roles,
+                &self.rules.location_tags,
+                &self.rules.excluded_location_ids,
+                &mut self.rng,
+            );
             if pool.is_empty() {
                 return Err(AppError::BadRequest(
+                    ErrorCode::NoLocationsAvailable,
                     "no locations available for the current player count".into(),
                 ));
             }
@@ -967,40 +4120,68 @@ impl Game {
         let mut candidates: Vec<LocationDefinition> = self
             .location_pool
             .iter()
-            .filter(|location| location.roles.len() + 1 >= self.players.len())
+            .filter(|location| self.rules.allows_location(location, self.players.len()))
             .cloned()
             .collect();
 
         if candidates.is_empty() {
             return Err(AppError::BadRequest(
+                ErrorCode::NoLocationsAvailable,
                 "no locations support the current player count".into(),
             ));
         }
 
-        candidates.shuffle(&mut rng);
-        let selected = if let Some(location) = candidates
+        candidates.shuffle(&mut self.rng);
+        let selected = if let Some(location_id) = forced_location {
+            candidates
+                .iter()
+                .find(|location| location.id == location_id)
+                .cloned()
+                .ok_or_else(|| {
+                    AppError::BadRequest(
+                        ErrorCode::UnknownLocation,
+                        "forced location is not in this lobby's location pool".into(),
+                    )
+                })?
+        } else if let Some(location) = candidates
             .iter()
             .find(|location| !self.used_location_ids.contains(&location.id))
         {
             location.clone()
         } else {
             self.used_location_ids.clear();
-            candidates
-                .first()
-                .cloned()
-                .ok_or_else(|| AppError::BadRequest("no locations available".into()))?
+            candidates.first().cloned().ok_or_else(|| {
+                AppError::BadRequest(
+                    ErrorCode::NoLocationsAvailable,
+                    "no locations available".into(),
+                )
+            })?
         };
 
         let next_round_number = self.round_counter.saturating_add(1);
         let selected_id = selected.id;
-        let round = RoundState::new(
+        let previous_imposters = self
+            .round_archives
+            .last()
+            .map(|archive| archive.imposter_ids.clone())
+            .unwrap_or_default();
+        let mut round = RoundState::new(
             next_round_number,
             selected,
             &self.players,
             &self.rules,
             content,
-            &mut rng,
+            &previous_imposters,
+            &mut self.rng,
         )?;
+        if let Some(player_id) = forced_imposter {
+            round.force_imposter(player_id);
+        }
+        round.location_forced = forced_location.is_some();
+        if self.rules.imposter_narrowing {
+            let interval = Duration::from_secs(u64::from(self.rules.narrowing_interval_seconds));
+            round.arm_narrowing(&self.location_pool, interval, &mut self.rng);
+        }
 
         self.round_counter = next_round_number;
         self.phase = GamePhase::InRound;
@@ -1016,8 +4197,7 @@ impl Game {
             return Ok(public_state);
         }
 
-        Err(AppError::Unexpected(Box::new(io::Error::new(
-            io::ErrorKind::Other,
+        Err(AppError::Unexpected(Box::new(io::Error::other(
             "round failed to initialize",
         ))))
     }
@@ -1028,30 +4208,132 @@ impl Game {
         content: &GameContent,
     ) -> Result<NextQuestionResponse, AppError> {
         self.ensure_player(&player_id)?;
-        let mut rng = thread_rng();
         let rules = self.rules.clone();
-        let round = self.round_state_mut()?;
-        let (question, next_player) = round.next_question(player_id, &rules, content, &mut rng)?;
-        let asked_total = round.asked_questions.len();
+        let (round, rng) = self.round_and_rng()?;
+        round.ensure_running()?;
+        round.ensure_questioning()?;
+        let drawn = round.next_question(player_id, &rules, content, rng)?;
+        let asked_total = round.questions_asked();
+        let Some((question, next_player)) = drawn else {
+            let vote_deadline_ms = round.forced_vote_deadline.map(instant_ms);
+            self.touch();
+            return Ok(NextQuestionResponse {
+                question: None,
+                next_turn_player_id: None,
+                asked_total,
+                forced_vote_deadline_ms: vote_deadline_ms,
+            });
+        };
+        if self.is_bot(&next_player) {
+            // Bots never answer, so they can't be held to the answer rule.
+            self.round_state_mut()?.awaiting_answer = None;
+        }
         self.touch();
         Ok(NextQuestionResponse {
-            question: QuestionView::from(&question),
-            next_turn_player_id: next_player,
+            question: Some(QuestionView::from(&question)),
+            next_turn_player_id: Some(next_player),
             asked_total,
+            forced_vote_deadline_ms: None,
         })
     }
 
-    fn abort(&mut self, scope: AbortScope) -> Result<GameLobby, AppError> {
+    fn vote_skip_question(
+        &mut self,
+        player_id: Uuid,
+        content: &GameContent,
+    ) -> Result<(bool, RoundPublicState), AppError> {
+        self.ensure_player(&player_id)?;
+        let rules = self.rules.clone();
+        let (round, rng) = self.round_and_rng()?;
+        round.ensure_running()?;
+        round.ensure_questioning()?;
+        let replaced = round.vote_skip_question(player_id, &rules, content, rng)?;
+        self.touch();
+        Ok((replaced, self.public_round_state()?))
+    }
+
+    fn submit_answer(&mut self, player_id: Uuid, text: &str) -> Result<RoundPublicState, AppError> {
+        self.ensure_player(&player_id)?;
+        self.round_state_mut()?.record_answer(player_id, text)?;
+        self.touch();
+        self.public_round_state()
+    }
+
+    fn peek_location(
+        &mut self,
+        player_id: Uuid,
+        content: &GameContent,
+    ) -> Result<Vec<LocationOption>, AppError> {
+        self.ensure_player(&player_id)?;
+        if !self.rules.imposter_peek {
+            return Err(AppError::BadRequest(
+                ErrorCode::PeekDisabled,
+                "the imposter peek is disabled for this game".into(),
+            ));
+        }
+        let candidates = if self.location_pool.len() > 1 {
+            self.location_pool.clone()
+        } else {
+            content.locations().cloned().collect()
+        };
+        let (round, rng) = self.round_and_rng()?;
+        let options = round.peek(player_id, &candidates, rng)?;
+        self.touch();
+        Ok(options)
+    }
+
+    fn skip_turn(
+        &mut self,
+        redraw: bool,
+        content: &GameContent,
+    ) -> Result<RoundPublicState, AppError> {
+        let rules = self.rules.clone();
+        let (round, rng) = self.round_and_rng()?;
+        round.ensure_running()?;
+        round.skip_turn(redraw, &rules, content, rng)?;
+        self.touch();
+        self.public_round_state()
+    }
+
+    fn set_paused(&mut self, paused: bool) -> Result<RoundPublicState, AppError> {
+        if self.phase != GamePhase::InRound {
+            return Err(AppError::BadRequest(
+                ErrorCode::NoActiveRound,
+                "no active round is currently running".into(),
+            ));
+        }
+        let round = self.round_state_mut()?;
+        if paused {
+            round.pause()?;
+            self.cancel_round_timer();
+            self.cancel_narrowing_timer();
+        } else {
+            round.resume()?;
+        }
+        self.touch();
+        self.public_round_state()
+    }
+
+    /// Unwinds the round (or the whole game) and returns the lobby with the
+    /// event telling players why.
+    fn abort(
+        &mut self,
+        scope: AbortScope,
+        reason: Option<&str>,
+    ) -> Result<(GameLobby, GameEvent), AppError> {
         match scope {
             AbortScope::Round => {
                 if self.phase != GamePhase::InRound {
                     return Err(AppError::BadRequest(
+                        ErrorCode::NoActiveRound,
                         "no active round is currently running".into(),
                     ));
                 }
                 if let Some(current) = self.current_round.as_ref() {
                     self.used_location_ids.remove(&current.location.id);
                 }
+                self.cancel_round_timer();
+                self.cancel_narrowing_timer();
                 self.current_round = None;
                 self.phase = GamePhase::AwaitingNextRound;
             }
@@ -1059,6 +4341,8 @@ impl Game {
                 if let Some(current) = self.current_round.as_ref() {
                     self.used_location_ids.remove(&current.location.id);
                 }
+                self.cancel_round_timer();
+                self.cancel_narrowing_timer();
                 self.current_round = None;
                 self.phase = GamePhase::Lobby;
                 self.last_round = None;
@@ -1066,40 +4350,118 @@ impl Game {
                 self.location_pool.clear();
                 self.used_location_ids.clear();
                 self.round_history.clear();
+                self.round_archives.clear();
             }
         }
 
         self.touch();
-        Ok(self.lobby_view())
+        let reason = reason
+            .map(|reason| {
+                reason
+                    .split(|c: char| c.is_whitespace() || c.is_control())
+                    .filter(|part| !part.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .chars()
+                    .take(MAX_ABORT_REASON_LENGTH)
+                    .collect::<String>()
+            })
+            .filter(|reason| !reason.is_empty());
+        Ok((self.lobby_view(), GameEvent::Aborted { scope, reason }))
     }
 
-    fn submit_guess(
-        &mut self,
+    fn replayed_guess(&self, player_id: Uuid, idempotency_key: Uuid) -> Option<RoundResolution> {
+        self.current_round.as_ref().and_then(|round| {
+            round
+                .processed_guesses
+                .get(&(player_id, idempotency_key))
+                .cloned()
+        })
+    }
+
+    fn submit_guess(
+        &mut self,
         player_id: Uuid,
         action: GuessAction,
-    ) -> Result<RoundResolution, AppError> {
+        idempotency_key: Option<Uuid>,
+    ) -> Result<Option<RoundResolution>, AppError> {
         self.ensure_player(&player_id)?;
-        let (round_number, assignments, impostor_id, resolution) = {
+        if let GuessAction::GuessLocation { location_id } = action
+            && !self
+                .location_pool
+                .iter()
+                .any(|location| location.id == location_id)
+        {
+            return Err(AppError::BadRequest(
+                ErrorCode::UnknownLocation,
+                "guessed location is not in this game's location pool".into(),
+            ));
+        }
+        self.round_state()?.ensure_running()?;
+        let rules = self.rules.clone();
+        match self
+            .round_state_mut()?
+            .resolve_guess(player_id, action, &rules)?
+        {
+            Some(resolution) => {
+                let resolution = self.conclude_round(resolution)?;
+                if let Some(key) = idempotency_key {
+                    self.round_state_mut()?
+                        .processed_guesses
+                        .insert((player_id, key), resolution.clone());
+                }
+                Ok(Some(resolution))
+            }
+            None => {
+                if rules.require_majority_vote && !self.is_bot(&player_id) {
+                    let (round, rng) = self.round_and_rng()?;
+                    round.record_bot_votes(rng);
+                }
+                self.touch();
+                Ok(None)
+            }
+        }
+    }
+
+    fn conclude_round(
+        &mut self,
+        mut resolution: RoundResolution,
+    ) -> Result<RoundResolution, AppError> {
+        self.cancel_round_timer();
+        self.cancel_narrowing_timer();
+        let (round_number, started_at, assignments, impostor_ids, archive) = {
             let round = self.round_state_mut()?;
-            let resolution = round.resolve_guess(player_id, action)?;
-            let assignments = round.assignments.clone();
-            let impostor_id = round.imposter_id;
-            (round.round_number, assignments, impostor_id, resolution)
+            resolution.revealed_roles = round.civilian_roles();
+            round.resolution = Some(resolution.clone());
+            let archive = round.archive(&resolution);
+            (
+                round.round_number,
+                round.started_at,
+                round.assignments.clone(),
+                round.imposter_ids.clone(),
+                archive,
+            )
         };
+        let participants: Vec<Uuid> = assignments.keys().copied().collect();
+        let points = self.rules.scoring.points_for(&resolution.outcome);
 
         match resolution.winner {
             RoundWinner::Crew => {
                 for (player_id, assignment) in assignments {
-                    if matches!(assignment, PlayerRoleAssignment::Civilian { .. }) {
-                        if let Some(player) = self.players.get_mut(&player_id) {
-                            player.wins.crew = player.wins.crew.saturating_add(1);
-                        }
+                    if matches!(assignment, PlayerRoleAssignment::Civilian { .. })
+                        && let Some(player) = self.players.get_mut(&player_id)
+                    {
+                        player.wins.crew = player.wins.crew.saturating_add(1);
+                        player.wins.points = player.wins.points.saturating_add(points);
                     }
                 }
             }
             RoundWinner::Imposter => {
-                if let Some(player) = self.players.get_mut(&impostor_id) {
-                    player.wins.imposter = player.wins.imposter.saturating_add(1);
+                for impostor_id in impostor_ids {
+                    if let Some(player) = self.players.get_mut(&impostor_id) {
+                        player.wins.imposter = player.wins.imposter.saturating_add(1);
+                        player.wins.points = player.wins.points.saturating_add(points);
+                    }
                 }
             }
         }
@@ -1107,20 +4469,318 @@ impl Game {
         let summary = RoundSummary {
             round_number,
             resolution: resolution.clone(),
+            started_at_ms: instant_ms(started_at),
+            participants,
         };
         self.last_round = Some(summary.clone());
         self.round_history.push(summary);
-        self.phase = GamePhase::AwaitingNextRound;
+        self.round_archives.push(archive);
+        for player in self.players.values_mut() {
+            player.ready = player.is_bot;
+        }
+        self.phase = if self
+            .rules
+            .max_rounds
+            .is_some_and(|max_rounds| round_number >= max_rounds)
+        {
+            GamePhase::Finished
+        } else {
+            GamePhase::Reveal
+        };
+        self.ledger.record(self, &resolution);
         self.touch();
         Ok(resolution)
     }
+
+    /// Cancels the round clock and any pending per-turn clock.
+    fn cancel_round_timer(&mut self) {
+        if let Some(handle) = self.round_timer.take() {
+            handle.abort();
+        }
+        self.cancel_turn_timer();
+    }
+
+    /// Separate from the round clock, since narrowing runs through forced votes.
+    fn cancel_narrowing_timer(&mut self) {
+        if let Some(handle) = self.narrowing_timer.take() {
+            handle.abort();
+        }
+    }
+
+    fn cancel_turn_timer(&mut self) {
+        if let Some(handle) = self.turn_timer.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.bot_timer.take() {
+            handle.abort();
+        }
+    }
+
+    fn expire_turn(
+        &mut self,
+        round_number: u32,
+        turn_serial: u64,
+        content: &GameContent,
+    ) -> Option<RoundPublicState> {
+        let round = self.current_round.as_ref()?;
+        if self.phase != GamePhase::InRound
+            || round.round_number != round_number
+            || round.turn_serial != turn_serial
+            || !round.is_active()
+        {
+            return None;
+        }
+        self.skip_turn(false, content).ok()
+    }
+
+    /// The current-turn player if they have gone quiet for the lobby's grace
+    /// period. Silence is measured from the later of their last socket
+    /// activity and the start of the turn, so every turn gets the full grace.
+    fn idle_turn_player(&self, now: Instant) -> Option<Uuid> {
+        let grace = Duration::from_secs(u64::from(self.rules.idle_turn_grace_seconds?));
+        if self.phase != GamePhase::InRound {
+            return None;
+        }
+        let round = self.current_round.as_ref()?;
+        if !round.is_active()
+            || round.paused_at.is_some()
+            || round.last_chance.is_some()
+            || round.forced_vote_deadline.is_some()
+        {
+            return None;
+        }
+        let player_id = round.current_turn()?;
+        let player = self.players.get(&player_id)?;
+        if player.is_bot {
+            return None;
+        }
+        let since = player.last_seen.map_or(round.turn_started_at, |seen| {
+            seen.max(round.turn_started_at)
+        });
+        (now.saturating_duration_since(since) >= grace).then_some(player_id)
+    }
+
+    fn in_last_chance(&self) -> bool {
+        self.current_round
+            .as_ref()
+            .is_some_and(|round| round.is_active() && round.last_chance.is_some())
+    }
+
+    /// Lets a held misdirection stand once the last-chance window closes unanswered.
+    fn expire_last_chance(&mut self, round_number: u32) -> Option<RoundResolution> {
+        let round = self.current_round.as_ref()?;
+        if self.phase != GamePhase::InRound
+            || round.round_number != round_number
+            || !round.is_active()
+            || round.paused_at.is_some()
+        {
+            return None;
+        }
+        let mut resolution = round.last_chance.as_ref()?.pending.clone();
+        resolution.ended_at_ms = timestamp_ms(SystemTime::now());
+        self.conclude_round(resolution).ok()
+    }
+
+    /// Hands the round to the imposter when the crew let the forced vote lapse.
+    fn expire_forced_vote(&mut self, round_number: u32) -> Option<RoundResolution> {
+        let round = self.current_round.as_ref()?;
+        if self.phase != GamePhase::InRound
+            || round.round_number != round_number
+            || !round.is_active()
+            || round.forced_vote_deadline.is_none()
+            || round.last_chance.is_some()
+            || round.paused_at.is_some()
+        {
+            return None;
+        }
+        let impostors = round.imposter_ids.clone();
+        self.conclude_round(RoundResolution {
+            winner: RoundWinner::Imposter,
+            outcome: RoundOutcome::QuestionsExhausted { impostors },
+            ended_at_ms: timestamp_ms(SystemTime::now()),
+            revealed_roles: Vec::new(),
+        })
+        .ok()
+    }
+
+    fn expire_round(&mut self, round_number: u32) -> Option<RoundResolution> {
+        let round = self.current_round.as_ref()?;
+        if self.phase != GamePhase::InRound
+            || round.round_number != round_number
+            || !round.is_active()
+            || round.paused_at.is_some()
+        {
+            return None;
+        }
+        let impostors = round.imposter_ids.clone();
+        self.conclude_round(RoundResolution {
+            winner: RoundWinner::Imposter,
+            outcome: RoundOutcome::TimeExpired { impostors },
+            ended_at_ms: timestamp_ms(SystemTime::now()),
+            revealed_roles: Vec::new(),
+        })
+        .ok()
+    }
+
+    fn ensure_name_available(&self, name: &str, except: Option<Uuid>) -> Result<(), AppError> {
+        let taken = self.players.values().any(|existing| {
+            Some(existing.id) != except && existing.name.to_lowercase() == name.to_lowercase()
+        });
+        if taken {
+            return Err(AppError::BadRequest(
+                ErrorCode::DuplicatePlayerName,
+                "that name is already taken in this lobby".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn rename_player(
+        &mut self,
+        player_id: Uuid,
+        name: String,
+        denylist: &NameDenylist,
+    ) -> Result<(), AppError> {
+        self.ensure_player(&player_id)?;
+        match self.phase {
+            GamePhase::Lobby | GamePhase::AwaitingNextRound => {}
+            GamePhase::InRound | GamePhase::Reveal => {
+                return Err(AppError::BadRequest(
+                    ErrorCode::RoundInProgress,
+                    "names cannot change during a round".into(),
+                ));
+            }
+            GamePhase::Finished => {
+                return Err(AppError::BadRequest(
+                    ErrorCode::GameFinished,
+                    "names cannot change once the match is over".into(),
+                ));
+            }
+        }
+        let name = validate_player_name(&name, denylist)?;
+        self.ensure_name_available(&name, Some(player_id))?;
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.name = name;
+        }
+        self.touch();
+        Ok(())
+    }
+
+    fn transfer_leader(&mut self, new_leader_id: Uuid) -> Result<(), AppError> {
+        if !self.players.contains_key(&new_leader_id) {
+            return Err(AppError::BadRequest(
+                ErrorCode::NotAPlayer,
+                "new leader must be a player in this game".into(),
+            ));
+        }
+        self.leader_id = new_leader_id;
+        self.touch();
+        Ok(())
+    }
+
+    /// Issues a fresh host token, optionally handing leadership to another
+    /// player first. The old token stops working immediately.
+    fn rotate_host_token(&mut self, new_host_id: Option<Uuid>) -> Result<Uuid, AppError> {
+        if let Some(player_id) = new_host_id {
+            self.transfer_leader(player_id)?;
+            self.host_player_id = player_id;
+        }
+        self.host_token = Uuid::new_v4();
+        self.touch();
+        Ok(self.host_token)
+    }
+
+    /// Hands the current host token to the seat it was issued to, so a
+    /// player made host by `rotate_host_token` can pick it up.
+    fn claim_host_token(&self, player_id: &Uuid, action_token: &Uuid) -> Result<Uuid, AppError> {
+        self.check_action_token(player_id, action_token)?;
+        if *player_id != self.host_player_id {
+            return Err(AppError::Forbidden(
+                ErrorCode::NotHost,
+                "only the host can claim the host token".into(),
+            ));
+        }
+        Ok(self.host_token)
+    }
+
+    /// Returns whether the turn passed to someone else, so the caller can
+    /// re-arm the turn timer and announce it, plus the `Aborted` event when
+    /// the departure left too few players to finish the round.
+    fn remove_player(&mut self, player_id: Uuid) -> Result<(bool, Option<GameEvent>), AppError> {
+        self.ensure_player(&player_id)?;
+        let mut turn_moved = false;
+        let mut aborted = None;
+        if let Some(player) = self.players.remove(&player_id)
+            && self.rules.restore_returning_players
+            && !player.is_bot
+        {
+            self.departed_players
+                .insert(player.reconnect_token, player.wins);
+        }
+
+        if self.leader_id == player_id
+            && let Some(next_leader) = self
+                .players
+                .values()
+                .filter(|player| !player.is_bot)
+                .min_by_key(|player| player.join_seq)
+        {
+            self.leader_id = next_leader.id;
+        }
+
+        if self.phase == GamePhase::InRound {
+            let imposter_left = self
+                .current_round
+                .as_ref()
+                .is_some_and(|round| round.is_imposter(&player_id));
+            let last_imposter = imposter_left
+                && self
+                    .current_round
+                    .as_ref()
+                    .is_some_and(|round| round.imposter_ids.len() == 1);
+            if last_imposter {
+                self.conclude_round(RoundResolution {
+                    winner: RoundWinner::Crew,
+                    outcome: RoundOutcome::ImposterLeft {
+                        impostor: player_id,
+                    },
+                    ended_at_ms: timestamp_ms(SystemTime::now()),
+                    revealed_roles: Vec::new(),
+                })?;
+            } else if self.players.len() < 3 {
+                let (_, event) = self.abort(AbortScope::Round, None)?;
+                aborted = Some(event);
+            } else {
+                let round = self.round_state_mut()?;
+                turn_moved = round.remove_player(&player_id);
+                if imposter_left && round.remaining_imposters() == 0 {
+                    self.conclude_round(RoundResolution {
+                        winner: RoundWinner::Crew,
+                        outcome: RoundOutcome::ImposterLeft {
+                            impostor: player_id,
+                        },
+                        ended_at_ms: timestamp_ms(SystemTime::now()),
+                        revealed_roles: Vec::new(),
+                    })?;
+                    turn_moved = false;
+                }
+            }
+        }
+
+        self.touch();
+        Ok((turn_moved, aborted))
+    }
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
 enum GamePhase {
     Lobby,
     InRound,
+    /// The round has resolved and its secrets are public until the next one starts.
+    Reveal,
     AwaitingNextRound,
+    /// `max_rounds` have been played; no further rounds can start.
+    Finished,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1128,45 +4788,228 @@ struct GameLobby {
     code: RoomCode,
     leader_id: Uuid,
     rules: GameRules,
+    /// Echo this back in a rules update to prove it was based on these rules.
+    #[serde(default)]
+    rules_version: u32,
     players: Vec<PlayerSummary>,
     player_count: u32,
+    #[serde(default)]
+    spectator_count: u32,
     created_at_ms: u64,
     phase: GamePhase,
     last_round: Option<RoundSummary>,
     round_history: Vec<RoundSummary>,
+    #[serde(default)]
+    total_rounds: u32,
+    /// Final standings, present once the match has `Finished`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    final_scoreboard: Option<Vec<ScoreboardEntry>>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Points each winner earns, by how the round was won. Harder wins pay more.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct ScoringRules {
+    /// Crew, for accusing the imposter.
+    crew_accusation: u32,
+    /// Crew, when the imposter names the wrong location.
+    crew_location_miss: u32,
+    /// Crew, when the imposter leaves mid-round.
+    crew_forfeit: u32,
+    /// Imposter, for naming the location.
+    imposter_location_guess: u32,
+    /// Imposter, when the crew accuses an innocent player.
+    imposter_misdirection: u32,
+    /// Imposter, for lasting until the clock, the question cap, or a split vote ends the round.
+    imposter_survival: u32,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            crew_accusation: 2,
+            crew_location_miss: 1,
+            crew_forfeit: 0,
+            imposter_location_guess: 3,
+            imposter_misdirection: 2,
+            imposter_survival: 1,
+        }
+    }
+}
+
+impl ScoringRules {
+    fn normalize(mut self) -> Self {
+        for points in [
+            &mut self.crew_accusation,
+            &mut self.crew_location_miss,
+            &mut self.crew_forfeit,
+            &mut self.imposter_location_guess,
+            &mut self.imposter_misdirection,
+            &mut self.imposter_survival,
+        ] {
+            *points = (*points).min(MAX_POINTS_PER_OUTCOME);
+        }
+        self
+    }
+
+    /// What each member of the winning side earns for `outcome`.
+    fn points_for(&self, outcome: &RoundOutcome) -> u32 {
+        match outcome {
+            RoundOutcome::CrewIdentifiedImposter { .. }
+            | RoundOutcome::CrewIdentifiedImposters { .. } => self.crew_accusation,
+            RoundOutcome::ImposterFailedLocationGuess { .. }
+            | RoundOutcome::ImpostersFailedLocationGuess { .. } => self.crew_location_miss,
+            RoundOutcome::ImposterLeft { .. } => self.crew_forfeit,
+            RoundOutcome::ImposterIdentifiedLocation { .. }
+            | RoundOutcome::ImpostersIdentifiedLocation { .. } => self.imposter_location_guess,
+            RoundOutcome::CrewMisdirected { .. }
+            | RoundOutcome::CrewMisdirectedImposters { .. } => self.imposter_misdirection,
+            RoundOutcome::VoteDeadlocked { .. }
+            | RoundOutcome::TimeExpired { .. }
+            | RoundOutcome::QuestionsExhausted { .. } => self.imposter_survival,
+        }
+    }
+}
+
+/// How each round's turn order is chosen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TurnOrderMode {
+    #[default]
+    Shuffled,
+    /// Follow the order players joined, matching how a group sits in person.
+    JoinOrder,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 struct GameRules {
+    min_players: u8,
     max_players: u8,
     round_time_seconds: u16,
     allow_repeated_questions: bool,
     location_pool_size: u8,
     question_categories: Vec<String>,
+    /// Relative draw weight per question category; unlisted categories weigh 1.
+    category_weights: HashMap<String, u32>,
+    location_tags: Vec<String>,
+    hide_history_from_imposter: bool,
+    require_majority_vote: bool,
+    imposter_count: u8,
+    require_all_ready: bool,
+    diversify_questions: bool,
+    imposter_peek: bool,
+    turn_time_seconds: Option<u16>,
+    public: bool,
+    allow_duplicate_roles: bool,
+    bot_fill: u8,
+    imposter_last_chance: bool,
+    /// Skip the current turn once its player's socket has been silent this long.
+    idle_turn_grace_seconds: Option<u16>,
+    /// End the match after this many rounds.
+    max_rounds: Option<u32>,
+    /// Never hand the imposter role to last round's imposter when someone else can take it.
+    avoid_repeat_imposter: bool,
+    /// Locations this lobby never draws, by id.
+    excluded_location_ids: Vec<u32>,
+    /// Hold each draw until the player asked the previous question has answered it.
+    require_answer_before_next: bool,
+    /// Questions a round may use before the crew is forced to accuse.
+    max_questions_per_round: Option<u32>,
+    turn_order_mode: TurnOrderMode,
+    scoring: ScoringRules,
+    /// Give players who leave and later rejoin with their reconnect token
+    /// their wins back. Off by default so churny public lobbies stay lean.
+    restore_returning_players: bool,
+    /// Strike a decoy from the imposter's shortlist every interval, down to one.
+    imposter_narrowing: bool,
+    narrowing_interval_seconds: u16,
+    /// Language of the built-in locations and questions, such as `en` or `es`.
+    locale: String,
+    /// Questions that must be asked in a round before anyone may accuse.
+    min_questions_before_guess: u32,
+    /// Hold the imposter's location guess to `min_questions_before_guess` too.
+    gate_location_guess: bool,
 }
 
 impl Default for GameRules {
     fn default() -> Self {
         Self {
+            min_players: 3,
             max_players: 8,
             round_time_seconds: 120,
             allow_repeated_questions: false,
             location_pool_size: 10,
             question_categories: Vec::new(),
+            category_weights: HashMap::new(),
+            location_tags: Vec::new(),
+            hide_history_from_imposter: false,
+            require_majority_vote: false,
+            imposter_count: 1,
+            require_all_ready: false,
+            diversify_questions: true,
+            imposter_peek: false,
+            turn_time_seconds: None,
+            public: false,
+            allow_duplicate_roles: false,
+            bot_fill: 0,
+            imposter_last_chance: false,
+            idle_turn_grace_seconds: None,
+            max_rounds: None,
+            avoid_repeat_imposter: false,
+            excluded_location_ids: Vec::new(),
+            require_answer_before_next: false,
+            max_questions_per_round: None,
+            turn_order_mode: TurnOrderMode::Shuffled,
+            scoring: ScoringRules::default(),
+            restore_returning_players: false,
+            imposter_narrowing: false,
+            narrowing_interval_seconds: 60,
+            locale: DEFAULT_LOCALE.to_owned(),
+            min_questions_before_guess: 0,
+            gate_location_guess: false,
         }
     }
 }
 
 impl GameRules {
     fn normalize(mut self, content: &GameContent) -> Result<Self, AppError> {
-        let min_players: u8 = 3;
-        let max_players = content.max_player_capacity().max(min_players);
+        let min_players: u8 = MIN_PLAYER_CAP;
+        let max_players = content
+            .max_player_capacity()
+            .min(content.player_cap)
+            .max(min_players);
         self.max_players = self.max_players.clamp(min_players, max_players);
+        self.min_players = self.min_players.clamp(min_players, self.max_players);
+
+        let max_imposters = ((self.max_players - 1) / 2).max(1);
+        self.imposter_count = self.imposter_count.clamp(1, max_imposters);
+        self.bot_fill = self.bot_fill.min(self.max_players - 1);
 
         let min_round: u16 = 30;
         let max_round: u16 = 600;
         self.round_time_seconds = self.round_time_seconds.clamp(min_round, max_round);
+        self.turn_time_seconds = self
+            .turn_time_seconds
+            .map(|seconds| seconds.clamp(10, self.round_time_seconds));
+        // Sockets are pinged every 30 seconds, so anything shorter would skip live players.
+        self.idle_turn_grace_seconds = self
+            .idle_turn_grace_seconds
+            .map(|seconds| seconds.clamp(45, 600));
+        self.max_rounds = self
+            .max_rounds
+            .map(|rounds| rounds.clamp(1, MAX_ROUNDS_LIMIT));
+        self.max_questions_per_round = self
+            .max_questions_per_round
+            .map(|questions| questions.clamp(1, MAX_QUESTIONS_PER_ROUND_LIMIT));
+        // Never demand more questions than the round is allowed to draw.
+        self.min_questions_before_guess = self.min_questions_before_guess.min(
+            self.max_questions_per_round
+                .unwrap_or(MAX_QUESTIONS_PER_ROUND_LIMIT),
+        );
+        self.scoring = self.scoring.normalize();
+        self.narrowing_interval_seconds = self.narrowing_interval_seconds.clamp(15, 300);
+        self.locale = normalize_locale(&self.locale)?;
 
         let min_pool: u8 = 1;
         if self.location_pool_size == 0 {
@@ -1177,8 +5020,46 @@ impl GameRules {
         self.location_pool_size = self.location_pool_size.clamp(min_pool, max_pool_u8);
 
         self.question_categories = content.normalize_categories(&self.question_categories)?;
+        // Caught here so the host hears about it when setting the rule, not
+        // when the next round fails to draw.
+        if content.questions_in(&self.question_categories).is_empty() {
+            return Err(AppError::BadRequest(
+                ErrorCode::NoQuestionsAvailable,
+                "no questions match the selected categories".into(),
+            ));
+        }
+        self.category_weights = content.normalize_category_weights(&self.category_weights)?;
+        self.location_tags = content.normalize_location_tags(&self.location_tags)?;
+
+        self.excluded_location_ids.sort_unstable();
+        self.excluded_location_ids.dedup();
+        if let Some(unknown) = self
+            .excluded_location_ids
+            .iter()
+            .find(|id| !content.locations().any(|location| location.id == **id))
+        {
+            return Err(AppError::BadRequest(
+                ErrorCode::UnknownLocation,
+                format!("unknown location id: {}", unknown),
+            ));
+        }
+        let min_players = usize::from(self.min_players);
+        if !content
+            .locations()
+            .any(|location| self.allows_location(location, min_players))
+        {
+            return Err(AppError::BadRequest(
+                ErrorCode::AllLocationsExcluded,
+                "excluding these locations leaves none for this lobby".into(),
+            ));
+        }
         Ok(self)
     }
+
+    fn allows_location(&self, location: &LocationDefinition, player_count: usize) -> bool {
+        !self.excluded_location_ids.contains(&location.id)
+            && location.supports(player_count, self.allow_duplicate_roles)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1187,38 +5068,186 @@ struct PlayerSummary {
     name: String,
     crew_wins: u32,
     imposter_wins: u32,
+    #[serde(default)]
+    points: u32,
+    #[serde(default)]
+    ready: bool,
+    #[serde(default)]
+    last_seen_ms: Option<u64>,
+    #[serde(default)]
+    connected: bool,
+    #[serde(default)]
+    is_bot: bool,
 }
 
 impl From<Player> for PlayerSummary {
     fn from(value: Player) -> Self {
+        let connected = value.is_connected(Instant::now());
         Self {
             id: value.id,
             name: value.name,
             crew_wins: value.wins.crew,
             imposter_wins: value.wins.imposter,
+            points: value.wins.points,
+            ready: value.ready,
+            last_seen_ms: value.last_seen.map(instant_ms),
+            connected,
+            is_bot: value.is_bot,
         }
     }
 }
 
-#[derive(Clone)]
+const MAX_PLAYER_NAME_LENGTH: usize = 32;
+/// How often lobbies are checked for a current-turn player who has gone quiet.
+const IDLE_TURN_SWEEP_INTERVAL: Duration = Duration::from_secs(5);
+/// A player whose socket has been silent this long is reported as disconnected.
+/// Sockets are pinged every 30 seconds, so a live client always answers well within it.
+const PLAYER_IDLE_THRESHOLD: Duration = Duration::from_secs(75);
+/// A chatty socket refreshes its player's `last_seen` at most this often,
+/// sparing the games lock on every frame.
+const PRESENCE_REFRESH: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Serialize, Deserialize)]
 struct Player {
     id: Uuid,
+    reconnect_token: Uuid,
+    /// Secret proof of identity for player actions. `id` is public (it shows
+    /// up in turn order and votes), so it must never authorize anything alone.
+    #[serde(default = "Uuid::new_v4")]
+    action_token: Uuid,
+    /// Position in the lobby's join order; the host is 0.
+    #[serde(default)]
+    join_seq: u64,
     name: String,
     wins: PlayerWins,
+    #[serde(default)]
+    ready: bool,
+    /// Socket activity; not persisted, since every socket drops on restart.
+    #[serde(skip)]
+    last_seen: Option<Instant>,
+    #[serde(default)]
+    is_bot: bool,
+    /// Client-generated id of the join request that seated this player, so
+    /// a retried join finds the seat instead of adding a second one.
+    #[serde(default)]
+    client_join_id: Option<Uuid>,
 }
 
-impl Player {
-    fn new(name: String) -> Result<Self, AppError> {
-        let trimmed = name.trim();
-        if trimmed.is_empty() {
-            return Err(AppError::BadRequest("player name required".into()));
+/// Collapses whitespace and control characters, then enforces the length
+/// limit and the operator's deny-list.
+fn validate_player_name(name: &str, denylist: &NameDenylist) -> Result<String, AppError> {
+    let cleaned = name
+        .split(|c: char| c.is_whitespace() || c.is_control())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if cleaned.is_empty() {
+        return Err(AppError::BadRequest(
+            ErrorCode::PlayerNameRequired,
+            "player name required".into(),
+        ));
+    }
+    if cleaned.chars().count() > MAX_PLAYER_NAME_LENGTH {
+        return Err(AppError::BadRequest(
+            ErrorCode::PlayerNameTooLong,
+            format!(
+                "player names are limited to {} characters",
+                MAX_PLAYER_NAME_LENGTH
+            ),
+        ));
+    }
+    if denylist.denies(&cleaned) {
+        return Err(AppError::BadRequest(
+            ErrorCode::NameNotAllowed,
+            "name not allowed".into(),
+        ));
+    }
+    Ok(cleaned)
+}
+
+/// Player names an operator refuses, compared case-insensitively and with
+/// spacing and punctuation ignored, so `A.d m-i-n` matches `admin`.
+#[derive(Debug, Default)]
+struct NameDenylist {
+    names: HashSet<String>,
+}
+
+impl NameDenylist {
+    fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            names: names
+                .into_iter()
+                .map(|name| Self::key(name.as_ref()))
+                .filter(|key| !key.is_empty())
+                .collect(),
         }
+    }
+
+    fn key(name: &str) -> String {
+        name.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    fn denies(&self, name: &str) -> bool {
+        !self.names.is_empty() && self.names.contains(&Self::key(name))
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+}
+
+impl Player {
+    fn new(name: String, denylist: &NameDenylist) -> Result<Self, AppError> {
+        let cleaned = validate_player_name(&name, denylist)?;
         Ok(Self {
             id: Uuid::new_v4(),
-            name: trimmed.to_owned(),
+            reconnect_token: Uuid::new_v4(),
+            action_token: Uuid::new_v4(),
+            join_seq: 0,
+            name: cleaned,
+            wins: PlayerWins::default(),
+            ready: false,
+            last_seen: None,
+            is_bot: false,
+            client_join_id: None,
+        })
+    }
+
+    /// A fresh seat in a rematch lobby: same name, seat, and reconnect token,
+    /// but a new id, action token, and record.
+    fn rematch_seat(&self) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            action_token: Uuid::new_v4(),
             wins: PlayerWins::default(),
+            ready: false,
+            last_seen: None,
+            ..self.clone()
+        }
+    }
+
+    /// Synthetic seat-filler for practice games; always ready and never idle.
+    fn bot(name: String) -> Result<Self, AppError> {
+        Ok(Self {
+            ready: true,
+            is_bot: true,
+            ..Self::new(name, &NameDenylist::default())?
         })
     }
+
+    fn is_connected(&self, now: Instant) -> bool {
+        self.is_bot
+            || self
+                .last_seen
+                .is_some_and(|seen| now.saturating_duration_since(seen) < PLAYER_IDLE_THRESHOLD)
+    }
 }
 
 #[derive(Deserialize)]
@@ -1226,6 +5255,14 @@ struct CreateGameRequest {
     host_name: String,
     #[serde(default)]
     rules: Option<GameRules>,
+    #[serde(default)]
+    seed: Option<u64>,
+    /// Ties this game to earlier ones played by the same group.
+    #[serde(default)]
+    session_id: Option<Uuid>,
+    /// Reconnect token from a previous game in the session, kept as the host's session key.
+    #[serde(default)]
+    reconnect_token: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1234,85 +5271,267 @@ struct CreateGameResponse {
     host_token: Uuid,
     leader_id: Uuid,
     player_id: Uuid,
+    reconnect_token: Uuid,
+    action_token: Uuid,
     rules: GameRules,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    session_id: Option<Uuid>,
 }
 
 async fn create_game(
     State(state): State<SharedState>,
+    client: Option<ConnectInfo<SocketAddr>>,
     Json(payload): Json<CreateGameRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let host_player = Player::new(payload.host_name)?;
+    state
+        .create_limiter
+        .check(client_ip(client), CREATE_LIMIT_REFUSAL)?;
+    let mut host_player = Player::new(payload.host_name, &state.name_denylist)?;
+    if payload.session_id.is_some()
+        && let Some(token) = payload.reconnect_token
+    {
+        host_player.reconnect_token = token;
+    }
     let content = state.content();
     let rules = payload.rules.unwrap_or_default().normalize(&content)?;
     let host_token = Uuid::new_v4();
 
     let mut games_lock = state.games.write().await;
+    if games_lock.len() >= state.max_games {
+        return Err(AppError::Unavailable(
+            ErrorCode::ServerAtCapacity,
+            "the server is hosting too many games; try again later".into(),
+        ));
+    }
     let existing_codes: HashSet<RoomCode> = games_lock.keys().cloned().collect();
-    let code = RoomCode::generate(&existing_codes);
-    let (events_tx, _) = broadcast::channel(64);
-
-    let mut players = HashMap::new();
-    players.insert(host_player.id, host_player.clone());
+    let code = RoomCode::generate(&existing_codes, &state.room_codes);
+    let (events_tx, _) = broadcast::channel(state.event_capacity);
 
-    let game = Game {
-        code: code.clone(),
+    let game = Game::new(
+        code.clone(),
         host_token,
-        rules: rules.clone(),
-        leader_id: host_player.id,
-        players,
-        created_at: SystemTime::now(),
-        last_active: SystemTime::now(),
-        round_counter: 0,
-        phase: GamePhase::Lobby,
-        current_round: None,
-        last_round: None,
-        round_history: Vec::new(),
-        location_pool: Vec::new(),
-        used_location_ids: HashSet::new(),
-        events: events_tx.clone(),
-    };
+        host_player.clone(),
+        rules.clone(),
+        events_tx,
+        payload.seed,
+        payload.session_id,
+    )
+    .with_ledger(state.ledger());
 
     games_lock.insert(code.clone(), game);
     drop(games_lock);
+    state.metrics.games_created.fetch_add(1, Ordering::Relaxed);
+    if let Some(session_id) = payload.session_id {
+        state.attach_session(session_id);
+    }
 
     let response = CreateGameResponse {
         code,
         host_token,
         leader_id: host_player.id,
         player_id: host_player.id,
+        reconnect_token: host_player.reconnect_token,
+        action_token: host_player.action_token,
         rules,
+        session_id: payload.session_id,
     };
 
     Ok((StatusCode::CREATED, Json(response)))
 }
 
 #[derive(Deserialize)]
-struct JoinGameRequest {
-    player_name: String,
+struct RematchRequest {
+    host_token: Uuid,
+    /// Seat everyone from the source lobby in the new one as well.
+    #[serde(default)]
+    invite_players: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct JoinGameResponse {
-    player_id: Uuid,
-    code: RoomCode,
+struct RematchResponse {
+    #[serde(flatten)]
+    game: CreateGameResponse,
+    /// New player ids keyed by each player's id in the source lobby.
+    player_ids: HashMap<Uuid, Uuid>,
 }
 
-#[derive(Deserialize)]
-struct StartGameRequest {
-    host_token: Uuid,
-}
+/// Opens a new lobby with the source lobby's rules and custom content. The
+/// host keeps hosting, and invited players keep their reconnect tokens so
+/// each device can claim its new seat through `/reconnect`.
+async fn rematch_game(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    client: Option<ConnectInfo<SocketAddr>>,
+    Json(payload): Json<RematchRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let shared = state.content();
+    let mut games = state.games.write().await;
+    let source = games
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "snake_case")]
-enum AbortScope {
-    Round,
-    Game,
-}
+    source.ensure_host(&payload.host_token)?;
+    if source.phase == GamePhase::InRound {
+        return Err(AppError::BadRequest(
+            ErrorCode::GameInProgress,
+            "finish the current round before starting a rematch".into(),
+        ));
+    }
+    let (host_seat, host_player) = source
+        .players
+        .get(&source.host_player_id)
+        .or_else(|| {
+            source
+                .players
+                .values()
+                .filter(|player| !player.is_bot)
+                .min_by_key(|player| player.join_seq)
+        })
+        .map(|player| (player.id, player.rematch_seat()))
+        .ok_or_else(|| {
+            AppError::BadRequest(
+                ErrorCode::NotAPlayer,
+                "no player is left to host the rematch".into(),
+            )
+        })?;
+    state
+        .create_limiter
+        .check(client_ip(client), CREATE_LIMIT_REFUSAL)?;
+    if games.len() >= state.max_games {
+        return Err(AppError::Unavailable(
+            ErrorCode::ServerAtCapacity,
+            "the server is hosting too many games; try again later".into(),
+        ));
+    }
 
-impl Default for AbortScope {
-    fn default() -> Self {
-        Self::Round
+    let source = &games[&code];
+    let mut player_ids = HashMap::from([(host_seat, host_player.id)]);
+    let guests: Vec<(Uuid, Player)> = if payload.invite_players {
+        source
+            .players
+            .values()
+            .filter(|player| player.id != host_seat && !player.is_bot)
+            .map(|player| (player.id, player.rematch_seat()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let rules = source.rules.clone();
+    let custom_questions = source.custom_questions.clone();
+    let custom_locations = source.custom_locations.clone();
+    let session_id = source.session_id;
+    let seed = source
+        .seed
+        .map(|seed| seed.wrapping_add(u64::from(source.round_counter)));
+
+    let existing_codes: HashSet<RoomCode> = games.keys().cloned().collect();
+    let new_code = RoomCode::generate(&existing_codes, &state.room_codes);
+    let (events_tx, _) = broadcast::channel(state.event_capacity);
+    let host_token = Uuid::new_v4();
+    let mut game = Game::new(
+        new_code.clone(),
+        host_token,
+        host_player.clone(),
+        rules.clone(),
+        events_tx,
+        seed,
+        session_id,
+    )
+    .with_ledger(state.ledger());
+    for (old_id, player) in guests {
+        player_ids.insert(old_id, player.id);
+        game.players.insert(player.id, player);
+    }
+    game.custom_questions = custom_questions;
+    game.custom_locations = custom_locations;
+    if !game.custom_questions.is_empty() || !game.custom_locations.is_empty() {
+        game.rebuild_custom_content(&shared);
+    }
+    games.insert(new_code.clone(), game);
+
+    if let Some(source) = games.get(&code) {
+        let _ = source.events.send(GameEvent::Rematch {
+            code: new_code.clone(),
+        });
+    }
+    drop(games);
+    state.metrics.games_created.fetch_add(1, Ordering::Relaxed);
+    if let Some(session_id) = session_id {
+        state.attach_session(session_id);
     }
+    info!(room = %code, rematch = %new_code, "rematch lobby opened");
+
+    let response = RematchResponse {
+        game: CreateGameResponse {
+            code: new_code,
+            host_token,
+            leader_id: host_player.id,
+            player_id: host_player.id,
+            reconnect_token: host_player.reconnect_token,
+            action_token: host_player.action_token,
+            rules,
+            session_id,
+        },
+        player_ids,
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+#[derive(Deserialize)]
+struct JoinGameRequest {
+    player_name: String,
+    /// Reconnect token from a previous game in the lobby's session, or from
+    /// an earlier seat in this lobby under `restore_returning_players`.
+    #[serde(default)]
+    reconnect_token: Option<Uuid>,
+    /// Generated once per join attempt by the client and resent on retries;
+    /// a repeat returns the seat the first attempt created.
+    #[serde(default)]
+    client_join_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JoinGameResponse {
+    player_id: Uuid,
+    reconnect_token: Uuid,
+    action_token: Uuid,
+    code: RoomCode,
+}
+
+#[derive(Deserialize)]
+struct ReconnectRequest {
+    reconnect_token: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReconnectResponse {
+    player_id: Uuid,
+    action_token: Uuid,
+    assignment: Option<PlayerAssignmentView>,
+    snapshot: GameSnapshot,
+}
+
+#[derive(Deserialize)]
+struct StartGameRequest {
+    host_token: Uuid,
+    /// Hand the imposter role to this player instead of drawing it, for
+    /// teaching games and demos. The round is flagged so nobody mistakes it
+    /// for a random pick.
+    #[serde(default)]
+    forced_imposter_id: Option<Uuid>,
+    /// Play this location from the pool instead of drawing one, for
+    /// reproducing bug reports. Flagged on the round like a forced imposter.
+    #[serde(default)]
+    forced_location_id: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum AbortScope {
+    #[default]
+    Round,
+    Game,
 }
 
 #[derive(Deserialize)]
@@ -1320,32 +5539,158 @@ struct AbortRequest {
     host_token: Uuid,
     #[serde(default)]
     scope: AbortScope,
+    /// Shown to players, e.g. "someone had to leave".
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+const MAX_ABORT_REASON_LENGTH: usize = 140;
+
+#[derive(Deserialize)]
+struct SyncBotsRequest {
+    host_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct ReadyRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+    ready: bool,
+}
+
+#[derive(Deserialize)]
+struct LeaveGameRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct ClaimHostRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct KeepaliveRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeepaliveResponse {
+    /// Absent when the server never expires idle lobbies.
+    expires_at_ms: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct RenamePlayerRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct TransferLeaderRequest {
+    host_token: Uuid,
+    new_leader_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct TransferHostRequest {
+    host_token: Uuid,
+    /// Player who takes over hosting; omit to keep the current leader and
+    /// just move the token to a new device.
+    #[serde(default)]
+    new_host_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TransferHostResponse {
+    /// Left out when hosting moved to another player, who picks the token
+    /// up through `claim_host` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    host_token: Option<Uuid>,
+    leader_id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct KickPlayerRequest {
+    host_token: Uuid,
+    player_id: Uuid,
+}
+
+const MAX_ANSWER_LENGTH: usize = 280;
+
+#[derive(Deserialize)]
+struct AnswerRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+    answer: String,
+}
+
+#[derive(Deserialize)]
+struct PeekRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PeekResponse {
+    locations: Vec<LocationOption>,
+}
+
+#[derive(Deserialize)]
+struct SkipQuestionVoteRequest {
+    player_id: Uuid,
+    action_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct RoundControlRequest {
+    host_token: Uuid,
+}
+
+#[derive(Deserialize)]
+struct SkipTurnRequest {
+    host_token: Uuid,
+    #[serde(default)]
+    redraw: bool,
 }
 
 #[derive(Deserialize)]
 struct NextQuestionRequest {
     player_id: Uuid,
+    action_token: Uuid,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct NextQuestionResponse {
-    question: QuestionView,
-    next_turn_player_id: Uuid,
+    /// Absent when the draw spent the last of `max_questions_per_round`.
+    question: Option<QuestionView>,
+    next_turn_player_id: Option<Uuid>,
     asked_total: usize,
+    /// Set when the question cap ran out and the crew must now accuse.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    forced_vote_deadline_ms: Option<u64>,
 }
 
 #[derive(Deserialize)]
 struct GuessRequest {
     player_id: Uuid,
+    action_token: Uuid,
     #[serde(default)]
     accused_player_id: Option<Uuid>,
     #[serde(default)]
     location_id: Option<u32>,
+    #[serde(default)]
+    idempotency_key: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct GuessResponse {
-    resolution: RoundResolution,
+    resolution: Option<RoundResolution>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    votes: Vec<VoteTally>,
 }
 
 #[derive(Deserialize)]
@@ -1353,6 +5698,17 @@ struct NextRoundRequest {
     host_token: Uuid,
 }
 
+#[derive(Deserialize)]
+struct AssignmentsRequest {
+    host_token: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AssignmentsResponse {
+    round_number: u32,
+    assignments: HashMap<Uuid, PlayerAssignmentView>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct LocationListResponse {
     locations: Vec<LocationOption>,
@@ -1363,22 +5719,64 @@ async fn join_game(
     Path(code): Path<String>,
     Json(payload): Json<JoinGameRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let mut player = Player::new(payload.player_name, &state.name_denylist)?;
+    if let Some(existing) = payload
+        .client_join_id
+        .and_then(|join_id| game.player_for_join_id(&join_id, &player.name))
+    {
+        return Ok((
+            StatusCode::OK,
+            Json(JoinGameResponse {
+                player_id: existing.id,
+                reconnect_token: existing.reconnect_token,
+                action_token: existing.action_token,
+                code,
+            }),
+        ));
+    }
 
     if game.phase != GamePhase::Lobby {
-        return Err(AppError::BadRequest("game already in progress".into()));
+        return Err(AppError::BadRequest(
+            ErrorCode::GameInProgress,
+            "game already in progress".into(),
+        ));
     }
 
     if game.players.len() >= game.rules.max_players as usize {
-        return Err(AppError::BadRequest("game is full".into()));
+        return Err(AppError::BadRequest(
+            ErrorCode::GameFull,
+            "game is full".into(),
+        ));
     }
 
-    let player = Player::new(payload.player_name)?;
+    game.ensure_name_available(&player.name, None)?;
+    if let Some(token) = payload.reconnect_token
+        && let Some(wins) = game.reclaim_departed(&token)
+    {
+        player.reconnect_token = token;
+        player.wins = wins;
+    } else if game.session_id.is_some()
+        && let Some(token) = payload.reconnect_token
+    {
+        if game.player_for_token(&token).is_ok() {
+            return Err(AppError::BadRequest(
+                ErrorCode::SessionPlayerInUse,
+                "that session player has already joined this lobby".into(),
+            ));
+        }
+        player.reconnect_token = token;
+    }
+    player.join_seq = game.next_join_seq();
+    player.client_join_id = payload.client_join_id;
     let player_id = player.id;
+    let reconnect_token = player.reconnect_token;
+    let action_token = player.action_token;
     game.players.insert(player_id, player);
     game.touch();
     let lobby_update = game.lobby_view();
@@ -1386,7 +5784,47 @@ async fn join_game(
         lobby: lobby_update.clone(),
     });
 
-    Ok((StatusCode::OK, Json(JoinGameResponse { player_id, code })))
+    Ok((
+        StatusCode::OK,
+        Json(JoinGameResponse {
+            player_id,
+            reconnect_token,
+            action_token,
+            code,
+        }),
+    ))
+}
+
+async fn reconnect_player(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<ReconnectRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let player_id = game.player_for_token(&payload.reconnect_token)?;
+    let action_token = game.players[&player_id].action_token;
+    let assignment = game
+        .current_round
+        .as_ref()
+        .filter(|round| round.is_active())
+        .and_then(|round| round.assignment_for(&player_id));
+    game.touch();
+    let snapshot = game.snapshot_for(Some(player_id));
+    drop(games);
+    Ok((
+        StatusCode::OK,
+        Json(ReconnectResponse {
+            player_id,
+            action_token,
+            assignment,
+            snapshot,
+        }),
+    ))
 }
 
 async fn start_game(
@@ -1394,15 +5832,21 @@ async fn start_game(
     Path(code): Path<String>,
     Json(payload): Json<StartGameRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let content = state.content();
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
     game.ensure_host(&payload.host_token)?;
-    let public_state = game.begin_round(content.as_ref())?;
+    let content = game.content(&content);
+    let public_state = game.begin_round(
+        content.as_ref(),
+        payload.forced_imposter_id,
+        payload.forced_location_id,
+    )?;
+    schedule_round_timer(&state, game);
     let lobby = game.lobby_view();
     let round_update = public_state.clone();
     let _ = game.events.send(GameEvent::Lobby {
@@ -1417,7 +5861,146 @@ async fn start_game(
 #[derive(Deserialize)]
 struct UpdateRulesRequest {
     host_token: Uuid,
-    rules: GameRules,
+    /// The `rules_version` of the lobby these edits were made against.
+    rules_version: u32,
+    rules: RulesPatch,
+}
+
+/// A rules update where absent fields keep the lobby's current value, so a
+/// host changing one setting can't clobber another. Optional rules take
+/// `null` to switch them off.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RulesPatch {
+    min_players: Option<u8>,
+    max_players: Option<u8>,
+    round_time_seconds: Option<u16>,
+    allow_repeated_questions: Option<bool>,
+    location_pool_size: Option<u8>,
+    question_categories: Option<Vec<String>>,
+    category_weights: Option<HashMap<String, u32>>,
+    location_tags: Option<Vec<String>>,
+    hide_history_from_imposter: Option<bool>,
+    require_majority_vote: Option<bool>,
+    imposter_count: Option<u8>,
+    require_all_ready: Option<bool>,
+    diversify_questions: Option<bool>,
+    imposter_peek: Option<bool>,
+    #[serde(deserialize_with = "nullable")]
+    turn_time_seconds: Option<Option<u16>>,
+    public: Option<bool>,
+    allow_duplicate_roles: Option<bool>,
+    bot_fill: Option<u8>,
+    imposter_last_chance: Option<bool>,
+    #[serde(deserialize_with = "nullable")]
+    idle_turn_grace_seconds: Option<Option<u16>>,
+    #[serde(deserialize_with = "nullable")]
+    max_rounds: Option<Option<u32>>,
+    avoid_repeat_imposter: Option<bool>,
+    excluded_location_ids: Option<Vec<u32>>,
+    require_answer_before_next: Option<bool>,
+    #[serde(deserialize_with = "nullable")]
+    max_questions_per_round: Option<Option<u32>>,
+    turn_order_mode: Option<TurnOrderMode>,
+    scoring: Option<ScoringRules>,
+    restore_returning_players: Option<bool>,
+    imposter_narrowing: Option<bool>,
+    narrowing_interval_seconds: Option<u16>,
+    locale: Option<String>,
+    min_questions_before_guess: Option<u32>,
+    gate_location_guess: Option<bool>,
+}
+
+/// Keeps an explicit `null` distinct from an absent field.
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl GameRules {
+    fn patched(mut self, patch: RulesPatch) -> Self {
+        let RulesPatch {
+            min_players,
+            max_players,
+            round_time_seconds,
+            allow_repeated_questions,
+            location_pool_size,
+            question_categories,
+            category_weights,
+            location_tags,
+            hide_history_from_imposter,
+            require_majority_vote,
+            imposter_count,
+            require_all_ready,
+            diversify_questions,
+            imposter_peek,
+            turn_time_seconds,
+            public,
+            allow_duplicate_roles,
+            bot_fill,
+            imposter_last_chance,
+            idle_turn_grace_seconds,
+            max_rounds,
+            avoid_repeat_imposter,
+            excluded_location_ids,
+            require_answer_before_next,
+            max_questions_per_round,
+            turn_order_mode,
+            scoring,
+            restore_returning_players,
+            imposter_narrowing,
+            narrowing_interval_seconds,
+            locale,
+            min_questions_before_guess,
+            gate_location_guess,
+        } = patch;
+        macro_rules! apply {
+            ($($field:ident),* $(,)?) => {
+                $(if let Some(value) = $field {
+                    self.$field = value;
+                })*
+            };
+        }
+        apply!(
+            min_players,
+            max_players,
+            round_time_seconds,
+            allow_repeated_questions,
+            location_pool_size,
+            question_categories,
+            category_weights,
+            location_tags,
+            hide_history_from_imposter,
+            require_majority_vote,
+            imposter_count,
+            require_all_ready,
+            diversify_questions,
+            imposter_peek,
+            turn_time_seconds,
+            public,
+            allow_duplicate_roles,
+            bot_fill,
+            imposter_last_chance,
+            idle_turn_grace_seconds,
+            max_rounds,
+            avoid_repeat_imposter,
+            excluded_location_ids,
+            require_answer_before_next,
+            max_questions_per_round,
+            turn_order_mode,
+            scoring,
+            restore_returning_players,
+            imposter_narrowing,
+            narrowing_interval_seconds,
+            locale,
+            min_questions_before_guess,
+            gate_location_guess,
+        );
+        self
+    }
 }
 
 async fn update_rules(
@@ -1425,19 +6008,41 @@ async fn update_rules(
     Path(code): Path<String>,
     Json(payload): Json<UpdateRulesRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
     if payload.host_token != game.host_token {
-        return Err(AppError::Forbidden("host token invalid".into()));
+        return Err(AppError::Forbidden(
+            ErrorCode::InvalidHostToken,
+            "host token invalid".into(),
+        ));
     }
 
-    let content = state.content();
-    game.rules = payload.rules.normalize(&content)?;
-    game.touch();
+    if payload.rules_version != game.rules_version {
+        return Err(AppError::Conflict(
+            ErrorCode::RulesVersionMismatch,
+            format!(
+                "rules changed since version {}; reload and retry",
+                payload.rules_version
+            ),
+        ));
+    }
+
+    let shared = state.content();
+    let content = game.content(&shared);
+    let rules = game
+        .rules
+        .clone()
+        .patched(payload.rules)
+        .normalize(&content)?;
+    let locale_changed = rules.locale != game.rules.locale;
+    game.replace_rules(rules);
+    if locale_changed {
+        game.relocalize(&shared);
+    }
     let lobby = game.lobby_view();
     let _ = game.events.send(GameEvent::Lobby {
         lobby: lobby.clone(),
@@ -1449,924 +6054,8981 @@ async fn fetch_game_details(
     State(state): State<SharedState>,
     Path(code): Path<String>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
     game.touch();
     let lobby = game.lobby_view();
     drop(games);
     Ok((StatusCode::OK, Json(lobby)))
 }
 
-async fn get_round_state(
+async fn get_scoreboard(
     State(state): State<SharedState>,
     Path(code): Path<String>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
-    let mut games = state.games.write().await;
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let games = state.games.read().await;
     let game = games
-        .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    let public_state = game.public_round_state()?;
-    game.touch();
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+    let players = game.scoreboard();
     drop(games);
-    Ok((StatusCode::OK, Json(public_state)))
+    Ok((StatusCode::OK, Json(ScoreboardResponse { players })))
 }
 
-async fn stream_game(
-    ws: WebSocketUpgrade,
-    State(state): State<SharedState>,
-    Path(code): Path<String>,
-) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
-    let (events, snapshot) = {
-        let games = state.games.read().await;
-        let game = games
-            .get(&code)
-            .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-        (game.events.clone(), game.snapshot())
-    };
-    let state_clone = Arc::clone(&state);
-    let code_clone = code.clone();
-    Ok(ws.on_upgrade(move |socket| async move {
-        handle_socket(socket, state_clone, code_clone, events, snapshot).await;
-    }))
+#[derive(Debug, Serialize, Deserialize)]
+struct CanStartResponse {
+    can_start: bool,
+    reasons: Vec<String>,
+    min_players: u8,
+    current_players: usize,
 }
 
-async fn draw_next_question(
+async fn can_start_game(
     State(state): State<SharedState>,
     Path(code): Path<String>,
-    Json(payload): Json<NextQuestionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let content = state.content();
-    let mut games = state.games.write().await;
+    let games = state.games.read().await;
     let game = games
-        .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    let response = game.draw_next_question(payload.player_id, content.as_ref())?;
-    let round = game.public_round_state()?;
-    let _ = game.events.send(GameEvent::Round {
-        round: Some(round.clone()),
-    });
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let content = game.content(&content);
+    let reasons: Vec<String> = game
+        .start_blockers(content.as_ref())
+        .into_iter()
+        .map(|(_, message)| message)
+        .collect();
+    let response = CanStartResponse {
+        can_start: reasons.is_empty(),
+        reasons,
+        min_players: game.rules.min_players,
+        current_players: game.players.len(),
+    };
+    drop(games);
     Ok((StatusCode::OK, Json(response)))
 }
 
-async fn submit_guess(
+async fn get_session_leaderboard(
+    State(state): State<SharedState>,
+    Path(session_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let session_id = Uuid::parse_str(&session_id)
+        .map_err(|_| AppError::NotFound(ErrorCode::SessionNotFound, "session not found".into()))?;
+    let leaderboard = state.session_leaderboard(session_id)?;
+    Ok((StatusCode::OK, Json(leaderboard)))
+}
+
+/// Header carrying the operator secret configured through `ADMIN_TOKEN`.
+const ADMIN_TOKEN_HEADER: &str = "x-admin-token";
+const CREATE_LIMIT_REFUSAL: &str = "too many games created recently; try again shortly";
+
+async fn force_close_game(
     State(state): State<SharedState>,
     Path(code): Path<String>,
-    Json(payload): Json<GuessRequest>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    state.ensure_admin(&headers)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
-    let game = games
-        .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    let action = match (payload.accused_player_id, payload.location_id) {
-        (Some(accused_id), None) => GuessAction::AccusePlayer { accused_id },
-        (None, Some(location_id)) => GuessAction::GuessLocation { location_id },
-        _ => {
-            return Err(AppError::BadRequest(
-                "provide an accused_player_id or location_id, but not both".into(),
-            ));
-        }
-    };
+    let mut game = games
+        .remove(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+    drop(games);
 
-    let resolution = game.submit_guess(payload.player_id, action)?;
-    let round = game.public_round_state()?;
-    let lobby = game.lobby_view();
-    let _ = game.events.send(GameEvent::Round {
-        round: Some(round.clone()),
+    game.cancel_round_timer();
+    game.cancel_narrowing_timer();
+    let _ = game.events.send(GameEvent::Closed {
+        reason: "this lobby was closed by an operator".into(),
     });
-    let _ = game.events.send(GameEvent::Lobby {
-        lobby: lobby.clone(),
-    });
-    Ok((StatusCode::OK, Json(GuessResponse { resolution })))
+    info!(room = %code, "lobby force-closed by admin");
+    Ok(StatusCode::NO_CONTENT)
 }
 
-async fn start_next_round(
+async fn export_game(
     State(state): State<SharedState>,
     Path(code): Path<String>,
-    Json(payload): Json<NextRoundRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
-    let content = state.content();
-    let mut games = state.games.write().await;
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let games = state.games.read().await;
     let game = games
-        .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    game.ensure_host(&payload.host_token)?;
-    let public_state = game.begin_round(content.as_ref())?;
-    let lobby = game.lobby_view();
-    let round_update = public_state.clone();
-    let _ = game.events.send(GameEvent::Lobby {
-        lobby: lobby.clone(),
-    });
-    let _ = game.events.send(GameEvent::Round {
-        round: Some(round_update.clone()),
-    });
-    Ok((StatusCode::OK, Json(public_state)))
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let export = GameExport {
+        code: game.code.clone(),
+        rules: game.rules.clone(),
+        players: game.lobby_view().players,
+        created_at_ms: timestamp_ms(game.created_at),
+        rounds: game.round_archives.clone(),
+    };
+    drop(games);
+    Ok((StatusCode::OK, Json(export)))
 }
 
-async fn abort_game(
+async fn get_round_history(
     State(state): State<SharedState>,
     Path(code): Path<String>,
-    Json(payload): Json<AbortRequest>,
+    Query(params): Query<HistoryParams>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
-    let mut games = state.games.write().await;
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let games = state.games.read().await;
     let game = games
-        .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    game.ensure_host(&payload.host_token)?;
-    let lobby = game.abort(payload.scope)?;
-    let round = game.current_round_view();
-    let _ = game.events.send(GameEvent::Lobby {
-        lobby: lobby.clone(),
-    });
-    let _ = game.events.send(GameEvent::Round { round });
-    Ok((StatusCode::OK, Json(lobby)))
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_HISTORY_PAGE)
+        .clamp(1, MAX_HISTORY_PAGE);
+    let matching: Vec<&RoundSummary> = game
+        .round_history
+        .iter()
+        .filter(|summary| {
+            params
+                .winner
+                .is_none_or(|winner| summary.resolution.winner == winner)
+        })
+        .collect();
+    let page = RoundHistoryPage {
+        total: matching.len() as u32,
+        offset: params.offset as u32,
+        rounds: matching
+            .into_iter()
+            .skip(params.offset)
+            .take(limit)
+            .cloned()
+            .collect(),
+    };
+    drop(games);
+    Ok((StatusCode::OK, Json(page)))
 }
 
-async fn get_assignment(
+async fn get_round_state(
     State(state): State<SharedState>,
-    Path((code, player_id)): Path<(String, String)>,
+    Path(code): Path<String>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
-    let player_id = Uuid::parse_str(&player_id)
-        .map_err(|_| AppError::BadRequest("invalid player id".into()))?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
-    let assignment = game.assignment_for(player_id)?;
+    let public_state = game.public_round_state()?;
     game.touch();
     drop(games);
-    Ok((StatusCode::OK, Json(assignment)))
+    Ok((StatusCode::OK, Json(public_state)))
 }
 
-async fn get_game_locations(
+/// Just the prompt on the table, for clients that don't need the full round.
+#[derive(Debug, Serialize, Deserialize)]
+struct CurrentQuestionResponse {
+    round_number: u32,
+    question: Option<QuestionView>,
+    current_turn_player_id: Option<Uuid>,
+}
+
+async fn get_current_question(
     State(state): State<SharedState>,
     Path(code): Path<String>,
 ) -> Result<impl IntoResponse, AppError> {
-    let code = RoomCode::new(code)?;
+    let code = RoomCode::new(code, &state.room_codes)?;
     let mut games = state.games.write().await;
     let game = games
         .get_mut(&code)
-        .ok_or_else(|| AppError::NotFound("game not found".into()))?;
-
-    if game.location_pool.is_empty() {
-        return Err(AppError::BadRequest(
-            "location pool has not been generated yet".into(),
-        ));
-    }
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
+    let round = game.round_state()?;
+    let response = CurrentQuestionResponse {
+        round_number: round.round_number,
+        question: round.current_question.as_ref().map(QuestionView::from),
+        current_turn_player_id: round.current_turn(),
+    };
     game.touch();
-    let locations = game.location_options();
     drop(games);
-    Ok((StatusCode::OK, Json(LocationListResponse { locations })))
+    Ok((StatusCode::OK, Json(response)))
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct CategoriesResponse {
-    categories: Vec<String>,
+#[derive(Deserialize)]
+struct PlayerViewParams {
+    player_id: Uuid,
+    action_token: Uuid,
 }
 
-async fn get_question_categories(
+async fn get_player_view(
     State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Query(params): Query<PlayerViewParams>,
 ) -> Result<impl IntoResponse, AppError> {
-    let content = state.content();
-    Ok((
-        StatusCode::OK,
-        Json(CategoriesResponse {
-            categories: content.default_categories(),
-        }),
-    ))
-}
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let games = state.games.read().await;
+    let game = games
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
 
-async fn handle_socket(
-    socket: WebSocket,
-    state: SharedState,
-    code: RoomCode,
-    events: broadcast::Sender<GameEvent>,
-    initial: GameSnapshot,
-) {
-    info!(room = %code, "realtime subscriber connected");
-    let (mut sender, mut receiver) = socket.split();
-    if let Some(message) = event_message(&GameEvent::Snapshot(initial.clone())) {
-        if sender.send(message).await.is_err() {
-            let _ = sender.close().await;
-            warn!(room = %code, "failed to deliver initial snapshot");
-            return;
-        }
-    }
+    game.check_action_token(&params.player_id, &params.action_token)?;
+    let view = game.player_view(params.player_id)?;
+    drop(games);
+    Ok((StatusCode::OK, Json(view)))
+}
 
-    let mut rx = events.subscribe();
-    let mut ping_interval = tokio::time::interval(Duration::from_secs(30));
+/// Who is on the other end of a realtime socket. Spectators never resolve to a
+/// player, so nothing player-specific is ever routed to them.
+#[derive(Clone, Copy)]
+enum Subscriber {
+    Anonymous,
+    Player(Uuid),
+    Spectator,
+}
 
-    loop {
-        tokio::select! {
-            _ = ping_interval.tick() => {
-                if sender.send(Message::Ping(Vec::new())).await.is_err() {
-                    break;
-                }
-            }
-            inbound = receiver.next() => {
-                match inbound {
-                    Some(Ok(Message::Close(frame))) => {
-                        let _ = sender.send(Message::Close(frame)).await;
-                        break;
-                    }
-                    Some(Ok(Message::Ping(payload))) => {
-                        if sender.send(Message::Pong(payload)).await.is_err() {
-                            break;
-                        }
-                    }
-                    Some(Ok(Message::Text(text))) => {
-                        if text.trim().eq_ignore_ascii_case("ping") {
-                            if let Some(msg) = event_message(&GameEvent::Pong) {
-                                if sender.send(msg).await.is_err() {
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                    Some(Ok(Message::Binary(_))) | Some(Ok(Message::Pong(_))) => {
-                        // ignore
-                    }
-                    Some(Err(err)) => {
-                        warn!(room = %code, error = %err, "websocket receive error");
-                        break;
-                    }
-                    None => break,
-                }
-            }
-            broadcast = rx.recv() => {
-                match broadcast {
-                    Ok(event) => {
-                        if let Some(message) = event_message(&event) {
-                            if sender.send(message).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(_)) => {
-                        if let Some(snapshot) = latest_snapshot(&state, &code).await {
-                            if let Some(message) = event_message(&GameEvent::Snapshot(snapshot)) {
-                                if sender.send(message).await.is_err() {
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Closed) => break,
-                }
-            }
+impl Subscriber {
+    fn player_id(&self) -> Option<Uuid> {
+        match self {
+            Subscriber::Player(player_id) => Some(*player_id),
+            Subscriber::Anonymous | Subscriber::Spectator => None,
         }
     }
+}
 
-    let _ = sender.close().await;
-    info!(room = %code, "realtime subscriber disconnected");
+/// Number of most recent rounds inlined into every lobby view; the full
+/// history is served by `GET /api/games/:code/history`.
+const LOBBY_HISTORY_LIMIT: usize = 10;
+const DEFAULT_HISTORY_PAGE: usize = 20;
+const MAX_HISTORY_PAGE: usize = 100;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ScoreboardEntry {
+    player_id: Uuid,
+    name: String,
+    crew_wins: u32,
+    imposter_wins: u32,
+    total_wins: u32,
+    rounds_played: u32,
+    correct_accusations: u32,
+    fastest_resolution_ms: Option<u64>,
 }
 
-fn event_message(event: &GameEvent) -> Option<Message> {
-    match serde_json::to_string(event) {
-        Ok(payload) => Some(Message::Text(payload)),
-        Err(err) => {
-            warn!(error = %err, "failed to serialize game event");
-            None
-        }
-    }
+#[derive(Debug, Serialize, Deserialize)]
+struct ScoreboardResponse {
+    players: Vec<ScoreboardEntry>,
 }
 
-async fn latest_snapshot(state: &SharedState, code: &RoomCode) -> Option<GameSnapshot> {
-    let games = state.games.read().await;
-    games.get(code).map(Game::snapshot)
+#[derive(Deserialize)]
+struct HistoryParams {
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    offset: usize,
+    #[serde(default)]
+    winner: Option<RoundWinner>,
 }
 
-async fn health_check() -> &'static str {
-    "ok"
+#[derive(Debug, Serialize, Deserialize)]
+struct RoundHistoryPage {
+    total: u32,
+    offset: u32,
+    rounds: Vec<RoundSummary>,
 }
 
-fn timestamp_ms(time: SystemTime) -> u64 {
-    time.duration_since(SystemTime::UNIX_EPOCH)
-        .map(|dur| dur.as_millis().min(u128::from(u64::MAX)) as u64)
-        .unwrap_or_default()
+#[derive(Deserialize)]
+struct StreamParams {
+    #[serde(default)]
+    player_id: Option<Uuid>,
+    /// Required with `player_id`; without it anyone could chat or answer as that player.
+    #[serde(default)]
+    action_token: Option<Uuid>,
 }
 
-#[derive(Debug, Error)]
-enum AppError {
-    #[error("bad request: {0}")]
-    BadRequest(String),
-    #[error("not found: {0}")]
-    NotFound(String),
-    #[error("forbidden: {0}")]
-    Forbidden(String),
-    #[error(transparent)]
-    Unexpected(#[from] Box<dyn std::error::Error + Send + Sync>),
+/// Subprotocol names look like `imposter.v1`; the suffix is the version.
+const STREAM_PROTOCOL_PREFIX: &str = "imposter.v";
+/// Realtime protocol versions this server can speak, oldest first.
+const STREAM_PROTOCOL_VERSIONS: &[u16] = &[1, 2];
+/// From this version on, large snapshots may arrive as gzip binary frames.
+const COMPRESSED_SNAPSHOTS_SINCE: u16 = 2;
+/// Serialized snapshots at least this long are compressed when allowed.
+const SNAPSHOT_COMPRESSION_THRESHOLD: usize = 16 * 1024;
+/// Close code sent when the client offered no version we support.
+const UNSUPPORTED_PROTOCOL_CLOSE_CODE: u16 = 4406;
+/// Close code sent once the lobby behind a stream is gone; reconnecting
+/// cannot succeed.
+const GAME_GONE_CLOSE_CODE: u16 = 4404;
+/// Close code sent when the server fails the stream itself; a fresh
+/// connection may well work (RFC 6455 "internal error").
+const INTERNAL_ERROR_CLOSE_CODE: u16 = 1011;
+
+/// Why the server is ending a realtime stream. Clients get an `error` event
+/// and then a close frame carrying the same reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamFailure {
+    GameGone,
+    Internal,
 }
 
-impl AppError {
-    fn status_code(&self) -> StatusCode {
+impl StreamFailure {
+    fn event(self) -> GameEvent {
+        let (code, fatal) = match self {
+            Self::GameGone => (ErrorCode::GameNotFound, true),
+            Self::Internal => (ErrorCode::Internal, false),
+        };
+        GameEvent::Error {
+            code,
+            message: self.reason().into(),
+            fatal,
+        }
+    }
+
+    fn close_code(self) -> u16 {
         match self {
-            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
-            AppError::NotFound(_) => StatusCode::NOT_FOUND,
-            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
-            AppError::Unexpected(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::GameGone => GAME_GONE_CLOSE_CODE,
+            Self::Internal => INTERNAL_ERROR_CLOSE_CODE,
         }
     }
-}
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let status = self.status_code();
-        let message = self.to_string();
-        let body = Json(ErrorResponse { message });
-        (status, body).into_response()
+    fn reason(self) -> &'static str {
+        match self {
+            Self::GameGone => "this lobby no longer exists",
+            Self::Internal => "the server could not encode an update",
+        }
     }
 }
 
-#[derive(Serialize, Deserialize)]
-struct ErrorResponse {
-    message: String,
+/// Outcome of reading `Sec-WebSocket-Protocol` on a stream upgrade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StreamProtocol {
+    /// The client sent no subprotocol; it speaks the original version.
+    Legacy,
+    Negotiated(u16),
+    /// Only unknown protocols were offered. The first one is echoed back so
+    /// browsers finish the handshake and can read the close code.
+    Unsupported(String),
 }
 
-impl From<std::io::Error> for AppError {
-    fn from(value: std::io::Error) -> Self {
-        AppError::Unexpected(Box::new(value))
+impl StreamProtocol {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let offered: Vec<&str> = headers
+            .get_all(header::SEC_WEBSOCKET_PROTOCOL)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+        let Some(first) = offered.first() else {
+            return Self::Legacy;
+        };
+        offered
+            .iter()
+            .filter_map(|name| name.strip_prefix(STREAM_PROTOCOL_PREFIX)?.parse().ok())
+            .filter(|version| STREAM_PROTOCOL_VERSIONS.contains(version))
+            .max()
+            .map_or_else(|| Self::Unsupported((*first).to_string()), Self::Negotiated)
+    }
+
+    fn version(&self) -> Option<u16> {
+        match self {
+            Self::Legacy => STREAM_PROTOCOL_VERSIONS.first().copied(),
+            Self::Negotiated(version) => Some(*version),
+            Self::Unsupported(_) => None,
+        }
+    }
+
+    fn accept(&self, ws: WebSocketUpgrade) -> WebSocketUpgrade {
+        match self {
+            Self::Legacy => ws,
+            Self::Negotiated(version) => {
+                ws.protocols([format!("{STREAM_PROTOCOL_PREFIX}{version}")])
+            }
+            Self::Unsupported(name) => ws.protocols([name.clone()]),
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use axum::{
-        body::Body,
-        http::{Request, StatusCode},
+async fn stream_game(
+    ws: WebSocketUpgrade,
+    headers: HeaderMap,
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Query(params): Query<StreamParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let (events, snapshot) = {
+        let games = state.games.read().await;
+        let game = games
+            .get(&code)
+            .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+        if let Some(player_id) = params.player_id.as_ref() {
+            game.check_action_token(player_id, &params.action_token.unwrap_or_default())?;
+        }
+        (game.events.clone(), game.snapshot_for(params.player_id))
     };
-    use serde_json::json;
-    use std::collections::HashMap;
-    use tower::ServiceExt;
+    let state_clone = Arc::clone(&state);
+    let code_clone = code.clone();
+    let subscriber = params
+        .player_id
+        .map_or(Subscriber::Anonymous, Subscriber::Player);
+    let protocol = StreamProtocol::from_headers(&headers);
+    let span = Span::current();
+    Ok(protocol.accept(ws).on_upgrade(move |socket| async move {
+        handle_socket(
+            socket,
+            state_clone,
+            code_clone,
+            subscriber,
+            events,
+            snapshot,
+            protocol,
+        )
+        .instrument(span)
+        .await;
+    }))
+}
 
-    #[tokio::test]
-    async fn create_game_initializes_lobby() {
-        let content = GameContent::load().expect("content should load");
-        let state = Arc::new(AppState::new(content));
-        let app = super::app_router(state.clone());
+async fn spectate_game(
+    ws: WebSocketUpgrade,
+    headers: HeaderMap,
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let (events, snapshot) = {
+        let games = state.games.read().await;
+        let game = games
+            .get(&code)
+            .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+        (game.events.clone(), game.snapshot_for(None))
+    };
+    let state_clone = Arc::clone(&state);
+    let code_clone = code.clone();
+    let protocol = StreamProtocol::from_headers(&headers);
+    let span = Span::current();
+    Ok(protocol.accept(ws).on_upgrade(move |socket| async move {
+        handle_socket(
+            socket,
+            state_clone,
+            code_clone,
+            Subscriber::Spectator,
+            events,
+            snapshot,
+            protocol,
+        )
+        .instrument(span)
+        .await;
+    }))
+}
 
-        let response = app
-            .clone()
-            .oneshot(
+/// Read-only server-sent-events feed of the same broadcasts the WebSocket
+/// stream carries, for networks whose proxies block WebSocket upgrades. Each
+/// event is named after its `type` and starts with the snapshot; there is no
+/// round coalescing and no inbound channel.
+async fn game_event_stream(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Query(params): Query<StreamParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let (events, rx, snapshot) = {
+        let games = state.games.read().await;
+        let game = games
+            .get(&code)
+            .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+        if let Some(player_id) = params.player_id.as_ref() {
+            game.check_action_token(player_id, &params.action_token.unwrap_or_default())?;
+        }
+        (
+            game.events.clone(),
+            game.events.subscribe(),
+            game.snapshot_for(params.player_id),
+        )
+    };
+    if let Some(player_id) = params.player_id {
+        record_presence(&state, &code, Some(player_id), false).await;
+    }
+    state
+        .metrics
+        .connected_subscribers
+        .fetch_add(1, Ordering::Relaxed);
+    info!(room = %code, "event-stream subscriber connected");
+
+    let roster = params
+        .player_id
+        .map(|player_id| PresenceGuard::join(&state, &code, player_id, events));
+    let feed = EventFeed {
+        state,
+        code,
+        viewer: params.player_id,
+        _roster: roster,
+        rx,
+        initial: Some(snapshot),
+        finished: false,
+        presence: tokio::time::interval(Duration::from_secs(30)),
+    };
+    let stream = futures::stream::unfold(feed, |mut feed| async move {
+        let event = feed.next_event().await?;
+        Some((Ok::<_, std::convert::Infallible>(event), feed))
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Open realtime connections per player, per lobby. Unlike `last_seen`, this
+/// moves only when a connection opens or closes.
+#[derive(Default)]
+struct PresenceRoster {
+    rooms: Mutex<HashMap<RoomCode, HashMap<Uuid, u32>>>,
+}
+
+impl PresenceRoster {
+    /// Counts one connection in or out and returns who is still connected.
+    fn update(&self, code: &RoomCode, player_id: Uuid, joined: bool) -> Vec<Uuid> {
+        let mut rooms = self.rooms.lock().unwrap_or_else(|err| err.into_inner());
+        let room = rooms.entry(code.clone()).or_default();
+        if joined {
+            *room.entry(player_id).or_default() += 1;
+        } else if let Some(count) = room.get_mut(&player_id) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                room.remove(&player_id);
+            }
+        }
+        let mut connected: Vec<Uuid> = room.keys().copied().collect();
+        connected.sort_unstable();
+        if room.is_empty() {
+            rooms.remove(code);
+        }
+        connected
+    }
+}
+
+/// A player's place in the presence roster for as long as one of their
+/// connections is open. Leaving happens on drop, so every exit path of a
+/// connection, errors included, releases it.
+struct PresenceGuard {
+    state: SharedState,
+    code: RoomCode,
+    player_id: Uuid,
+    events: broadcast::Sender<GameEvent>,
+}
+
+impl PresenceGuard {
+    fn join(
+        state: &SharedState,
+        code: &RoomCode,
+        player_id: Uuid,
+        events: broadcast::Sender<GameEvent>,
+    ) -> Self {
+        let connected = state.presence.update(code, player_id, true);
+        let _ = events.send(GameEvent::Presence { connected });
+        Self {
+            state: Arc::clone(state),
+            code: code.clone(),
+            player_id,
+            events,
+        }
+    }
+}
+
+impl Drop for PresenceGuard {
+    fn drop(&mut self) {
+        let connected = self
+            .state
+            .presence
+            .update(&self.code, self.player_id, false);
+        let _ = self.events.send(GameEvent::Presence { connected });
+    }
+}
+
+/// Per-connection state behind `game_event_stream`.
+struct EventFeed {
+    state: SharedState,
+    code: RoomCode,
+    viewer: Option<Uuid>,
+    _roster: Option<PresenceGuard>,
+    rx: broadcast::Receiver<GameEvent>,
+    initial: Option<GameSnapshot>,
+    finished: bool,
+    /// SSE has no inbound traffic, so presence is refreshed on a timer.
+    presence: tokio::time::Interval,
+}
+
+impl EventFeed {
+    async fn next_event(&mut self) -> Option<Event> {
+        if let Some(snapshot) = self.initial.take() {
+            return sse_event(&GameEvent::Snapshot(Box::new(snapshot)));
+        }
+        while !self.finished {
+            tokio::select! {
+                _ = self.presence.tick() => {
+                    if let Some(player_id) = self.viewer {
+                        record_presence(&self.state, &self.code, Some(player_id), false).await;
+                    }
+                }
+                broadcast = self.rx.recv() => match broadcast {
+                    Ok(event) => {
+                        self.finished = matches!(event, GameEvent::Closed { .. });
+                        let event =
+                            personalize_event(&self.state, &self.code, self.viewer, event).await;
+                        if let Some(event) = sse_event(&event) {
+                            return Some(event);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        let snapshot = latest_snapshot(&self.state, &self.code, self.viewer).await;
+                        if let Some(snapshot) = snapshot {
+                            return sse_event(&GameEvent::Snapshot(Box::new(snapshot)));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => self.finished = true,
+                },
+            }
+        }
+        None
+    }
+}
+
+impl Drop for EventFeed {
+    fn drop(&mut self) {
+        self.state
+            .metrics
+            .connected_subscribers
+            .fetch_sub(1, Ordering::Relaxed);
+        info!(room = %self.code, "event-stream subscriber disconnected");
+    }
+}
+
+/// A game event as a named SSE event carrying the same JSON a socket gets.
+fn sse_event(event: &GameEvent) -> Option<Event> {
+    let payload = match serde_json::to_value(event) {
+        Ok(payload) => payload,
+        Err(err) => {
+            warn!(error = %err, "failed to serialize game event");
+            return None;
+        }
+    };
+    let name = payload["type"].as_str().unwrap_or("message").to_owned();
+    Some(Event::default().event(name).data(payload.to_string()))
+}
+
+async fn draw_next_question(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<NextQuestionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let content = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let content = game.content(&content);
+    let response = game.draw_next_question(payload.player_id, content.as_ref())?;
+    announce_draw(&state, game, payload.player_id, &response);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Broadcasts a draw, or the forced vote when the draw exhausted the
+/// question cap, and re-arms the matching clock.
+fn announce_draw(
+    state: &SharedState,
+    game: &mut Game,
+    asked_by: Uuid,
+    response: &NextQuestionResponse,
+) {
+    if let Some(deadline_ms) = response.forced_vote_deadline_ms {
+        schedule_forced_vote_timer(state, game);
+        let round = game.current_round_view();
+        if let Some(round_number) = round.as_ref().map(|round| round.round_number) {
+            let _ = game.events.send(GameEvent::VoteForced {
+                round_number,
+                deadline_ms,
+            });
+        }
+        let _ = game.events.send(GameEvent::Round { round });
+        return;
+    }
+    schedule_turn_timer(state, game);
+    if let (Some(question), Some(next_turn_player_id)) =
+        (&response.question, response.next_turn_player_id)
+    {
+        let _ = game.events.send(GameEvent::QuestionDrawn {
+            question: question.clone(),
+            asked_by,
+            next_turn_player_id,
+        });
+    }
+    let round = game.current_round_view();
+    if let Some(round) = &round {
+        announce_turn(game, round);
+    }
+    let _ = game.events.send(GameEvent::Round { round });
+}
+
+/// Tells subscribers whose turn it is now so they can cue the player without
+/// diffing the round state.
+fn announce_turn(game: &Game, round: &RoundPublicState) {
+    if let Some(current_turn_player_id) = round.current_turn_player_id {
+        let _ = game.events.send(GameEvent::TurnChanged {
+            current_turn_player_id,
+            round_number: round.round_number,
+        });
+    }
+}
+
+async fn vote_skip_question(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<SkipQuestionVoteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let content = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let content = game.content(&content);
+    let (replaced, round) = game.vote_skip_question(payload.player_id, content.as_ref())?;
+    if replaced {
+        info!(room = %code, round = round.round_number, "question skipped by vote");
+    }
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round.clone()),
+    });
+    Ok((StatusCode::OK, Json(round)))
+}
+
+async fn submit_answer(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<AnswerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let round = game.submit_answer(payload.player_id, &payload.answer)?;
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round.clone()),
+    });
+    Ok((StatusCode::OK, Json(round)))
+}
+
+async fn peek_location(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<PeekRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let content = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let content = game.content(&content);
+    let locations = game.peek_location(payload.player_id, content.as_ref())?;
+    drop(games);
+    Ok((StatusCode::OK, Json(PeekResponse { locations })))
+}
+
+async fn pause_round(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<RoundControlRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    set_round_paused(&state, code, payload.host_token, true).await
+}
+
+async fn resume_round(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<RoundControlRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    set_round_paused(&state, code, payload.host_token, false).await
+}
+
+async fn set_round_paused(
+    state: &SharedState,
+    code: String,
+    host_token: Uuid,
+    paused: bool,
+) -> Result<(StatusCode, Json<RoundPublicState>), AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&host_token)?;
+    let round = game.set_paused(paused)?;
+    if !paused {
+        schedule_round_timer(state, game);
+    }
+    info!(room = %code, paused, "round pause toggled");
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round.clone()),
+    });
+    Ok((StatusCode::OK, Json(round)))
+}
+
+async fn skip_turn(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<SkipTurnRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let content = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    let content = game.content(&content);
+    let round = game.skip_turn(payload.redraw, content.as_ref())?;
+    schedule_turn_timer(&state, game);
+    announce_turn(game, &round);
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round.clone()),
+    });
+    Ok((StatusCode::OK, Json(round)))
+}
+
+async fn submit_guess(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<GuessRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let action = match (payload.accused_player_id, payload.location_id) {
+        (Some(accused_id), None) => GuessAction::AccusePlayer { accused_id },
+        (None, Some(location_id)) => GuessAction::GuessLocation { location_id },
+        _ => {
+            return Err(AppError::BadRequest(
+                ErrorCode::InvalidGuess,
+                "provide an accused_player_id or location_id, but not both".into(),
+            ));
+        }
+    };
+
+    if let Some(resolution) = payload
+        .idempotency_key
+        .and_then(|key| game.replayed_guess(payload.player_id, key))
+    {
+        return Ok((
+            StatusCode::OK,
+            Json(GuessResponse {
+                resolution: Some(resolution),
+                votes: Vec::new(),
+            }),
+        ));
+    }
+
+    let had_last_chance = game.in_last_chance();
+    let resolution = game.submit_guess(payload.player_id, action, payload.idempotency_key)?;
+    if !had_last_chance && game.in_last_chance() {
+        schedule_last_chance_timer(&state, game);
+    }
+    let round = game.public_round_state()?;
+    if resolution.is_none() {
+        let _ = game.events.send(GameEvent::Votes {
+            round_number: round.round_number,
+            tallies: round.vote_tallies.clone(),
+        });
+    }
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round.clone()),
+    });
+    if resolution.is_some() {
+        let lobby = game.lobby_view();
+        let _ = game.events.send(GameEvent::Lobby {
+            lobby: lobby.clone(),
+        });
+    }
+    let votes = if resolution.is_none() {
+        round.vote_tallies
+    } else {
+        Vec::new()
+    };
+    Ok((StatusCode::OK, Json(GuessResponse { resolution, votes })))
+}
+
+async fn start_next_round(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<NextRoundRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let content = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    let content = game.content(&content);
+    let public_state = game.begin_round(content.as_ref(), None, None)?;
+    schedule_round_timer(&state, game);
+    let lobby = game.lobby_view();
+    let round_update = public_state.clone();
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    let _ = game.events.send(GameEvent::Round {
+        round: Some(round_update.clone()),
+    });
+    Ok((StatusCode::OK, Json(public_state)))
+}
+
+async fn abort_game(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<AbortRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    let (lobby, aborted) = game.abort(payload.scope, payload.reason.as_deref())?;
+    let round = game.current_round_view();
+    let _ = game.events.send(aborted);
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    let _ = game.events.send(GameEvent::Round { round });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+async fn set_ready(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<ReadyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    if let Some(player) = game.players.get_mut(&payload.player_id) {
+        player.ready = payload.ready;
+    }
+    game.touch();
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+async fn sync_bots(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<SyncBotsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    game.sync_bots()?;
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+async fn leave_game(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<LeaveGameRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    let (turn_moved, aborted) = game.remove_player(payload.player_id)?;
+    let lobby = game.lobby_view();
+    if game.is_abandoned() {
+        close_abandoned_game(&mut games, &code);
+        return Ok((StatusCode::OK, Json(lobby)));
+    }
+    let round = game.current_round_view();
+    if let Some(aborted) = aborted {
+        let _ = game.events.send(aborted);
+    }
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    pass_turn_after_departure(&state, game, turn_moved, round.as_ref());
+    let _ = game.events.send(GameEvent::Round { round });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+/// Restarts the turn clock for whoever inherited a departed player's turn.
+fn pass_turn_after_departure(
+    state: &SharedState,
+    game: &mut Game,
+    turn_moved: bool,
+    round: Option<&RoundPublicState>,
+) {
+    if !turn_moved {
+        return;
+    }
+    schedule_turn_timer(state, game);
+    if let Some(round) = round {
+        announce_turn(game, round);
+    }
+}
+
+/// Drops a lobby whose last player just left rather than leaving it for the
+/// TTL sweep, which never reclaims a lobby stuck mid-round. Spectators get a
+/// final `closed` event.
+fn close_abandoned_game(games: &mut HashMap<RoomCode, Game>, code: &RoomCode) {
+    let Some(mut game) = games.remove(code) else {
+        return;
+    };
+    game.cancel_round_timer();
+    game.cancel_narrowing_timer();
+    let _ = game.events.send(GameEvent::Closed {
+        reason: "every player left this lobby".into(),
+    });
+    info!(room = %code, "lobby closed after its last player left");
+}
+
+/// Holds off the idle-lobby sweep for a group that is still together but
+/// not taking actions, e.g. talking through the rules.
+async fn keep_lobby_alive(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<KeepaliveRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    game.touch();
+    let expires_at_ms = state.expires_at_ms(game);
+    Ok((StatusCode::OK, Json(KeepaliveResponse { expires_at_ms })))
+}
+
+async fn rename_player(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<RenamePlayerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.authorize(&payload.player_id, &payload.action_token)?;
+    game.rename_player(payload.player_id, payload.name, &state.name_denylist)?;
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+async fn transfer_leader(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<TransferLeaderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    game.transfer_leader(payload.new_leader_id)?;
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+async fn transfer_host(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<TransferHostRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    let host_token = game.rotate_host_token(payload.new_host_id)?;
+    info!(room = %code, "host token rotated");
+    let host_token = payload.new_host_id.is_none().then_some(host_token);
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: game.lobby_view(),
+    });
+    Ok((
+        StatusCode::OK,
+        Json(TransferHostResponse {
+            host_token,
+            leader_id: game.leader_id,
+        }),
+    ))
+}
+
+async fn claim_host(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<ClaimHostRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let games = state.games.read().await;
+    let game = games
+        .get(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let host_token = game.claim_host_token(&payload.player_id, &payload.action_token)?;
+    Ok((
+        StatusCode::OK,
+        Json(TransferHostResponse {
+            host_token: Some(host_token),
+            leader_id: game.leader_id,
+        }),
+    ))
+}
+
+async fn kick_player(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<KickPlayerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    if payload.player_id == game.host_player_id {
+        return Err(AppError::BadRequest(
+            ErrorCode::CannotKickHost,
+            "the host cannot be kicked".into(),
+        ));
+    }
+    let (turn_moved, aborted) = game.remove_player(payload.player_id)?;
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Kicked {
+        player_id: payload.player_id,
+    });
+    if game.is_abandoned() {
+        close_abandoned_game(&mut games, &code);
+        return Ok((StatusCode::OK, Json(lobby)));
+    }
+    let round = game.current_round_view();
+    if let Some(aborted) = aborted {
+        let _ = game.events.send(aborted);
+    }
+    let _ = game.events.send(GameEvent::Lobby {
+        lobby: lobby.clone(),
+    });
+    pass_turn_after_departure(&state, game, turn_moved, round.as_ref());
+    let _ = game.events.send(GameEvent::Round { round });
+    Ok((StatusCode::OK, Json(lobby)))
+}
+
+#[derive(Deserialize)]
+struct MyAssignmentParams {
+    /// The player's private reconnect token; player ids are public, so they
+    /// can't unlock a secret role on their own.
+    token: Uuid,
+}
+
+async fn get_my_assignment(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Query(params): Query<MyAssignmentParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let player_id = game.player_for_token(&params.token)?;
+    let assignment = game.assignment_for(player_id)?;
+    game.touch();
+    drop(games);
+    Ok((StatusCode::OK, Json(assignment)))
+}
+
+async fn get_all_assignments(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<AssignmentsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    let assignments = game.host_assignments(&payload.host_token)?;
+    let round_number = game.round_state()?.round_number;
+    game.touch();
+    drop(games);
+    Ok((
+        StatusCode::OK,
+        Json(AssignmentsResponse {
+            round_number,
+            assignments,
+        }),
+    ))
+}
+
+async fn get_game_locations(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    if game.location_pool.is_empty() {
+        return Err(AppError::BadRequest(
+            ErrorCode::LocationPoolPending,
+            "location pool has not been generated yet".into(),
+        ));
+    }
+
+    game.touch();
+    let locations = game.location_options();
+    drop(games);
+    Ok((StatusCode::OK, Json(LocationListResponse { locations })))
+}
+
+const MAX_CUSTOM_QUESTIONS: usize = 200;
+const MAX_CUSTOM_QUESTION_LENGTH: usize = 280;
+const CUSTOM_QUESTION_CATEGORY: &str = "custom";
+
+#[derive(Deserialize)]
+struct CustomQuestionInput {
+    text: String,
+    #[serde(default)]
+    categories: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CustomQuestionsRequest {
+    host_token: Uuid,
+    questions: Vec<CustomQuestionInput>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CustomQuestionsResponse {
+    questions: Vec<QuestionView>,
+    categories: Vec<String>,
+}
+
+const MAX_CUSTOM_LOCATIONS: usize = 50;
+
+#[derive(Deserialize)]
+struct CustomLocationInput {
+    name: String,
+    roles: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CustomLocationsRequest {
+    host_token: Uuid,
+    locations: Vec<CustomLocationInput>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CustomLocationsResponse {
+    locations: Vec<LocationDefinition>,
+    rules: GameRules,
+}
+
+async fn add_custom_locations(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<CustomLocationsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let shared = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    game.add_custom_locations(&shared, payload.locations)?;
+    let response = CustomLocationsResponse {
+        locations: game.custom_locations.clone(),
+        rules: game.rules.clone(),
+    };
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby { lobby });
+    Ok((StatusCode::OK, Json(response)))
+}
+
+async fn add_custom_questions(
+    State(state): State<SharedState>,
+    Path(code): Path<String>,
+    Json(payload): Json<CustomQuestionsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let code = RoomCode::new(code, &state.room_codes)?;
+    let shared = state.content();
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(&code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+
+    game.ensure_host(&payload.host_token)?;
+    game.add_custom_questions(&shared, payload.questions)?;
+    let response = CustomQuestionsResponse {
+        questions: game
+            .custom_questions
+            .iter()
+            .map(QuestionView::from)
+            .collect(),
+        categories: game.content(&shared).display_categories(),
+    };
+    let lobby = game.lobby_view();
+    let _ = game.events.send(GameEvent::Lobby { lobby });
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CategoriesResponse {
+    categories: Vec<String>,
+}
+
+async fn get_question_categories(
+    State(state): State<SharedState>,
+) -> Result<impl IntoResponse, AppError> {
+    let content = state.content();
+    Ok((
+        StatusCode::OK,
+        Json(CategoriesResponse {
+            categories: content.display_categories(),
+        }),
+    ))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct QuestionCatalogResponse {
+    questions: Vec<QuestionView>,
+}
+
+/// Lists the built-in questions; repeat `category` to match any of several.
+async fn get_content_questions(
+    State(state): State<SharedState>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<impl IntoResponse, AppError> {
+    let locale = params
+        .iter()
+        .find(|(key, _)| key == "locale")
+        .map(|(_, value)| value.as_str());
+    let content = state.localized_content(locale)?;
+    let requested: Vec<String> = params
+        .iter()
+        .filter(|(key, _)| key == "category")
+        .map(|(_, value)| value.clone())
+        .collect();
+    let categories = if requested.is_empty() {
+        Vec::new()
+    } else {
+        content.normalize_categories(&requested)?
+    };
+    let questions = content
+        .questions_in(&categories)
+        .into_iter()
+        .map(QuestionView::from)
+        .collect();
+    Ok((StatusCode::OK, Json(QuestionCatalogResponse { questions })))
+}
+
+const MAX_REPORT_REASON_LENGTH: usize = 200;
+
+#[derive(Deserialize)]
+struct ReportQuestionRequest {
+    reason: String,
+}
+
+/// Flags a built-in question as confusing or inappropriate. Reports only
+/// feed the operator view; gameplay is unaffected. Ids are the same in every
+/// locale, so one report covers each translation.
+async fn report_question(
+    State(state): State<SharedState>,
+    Path(question_id): Path<String>,
+    client: Option<ConnectInfo<SocketAddr>>,
+    Json(payload): Json<ReportQuestionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    state.report_limiter.check(
+        client_ip(client),
+        "too many question reports recently; try again shortly",
+    )?;
+    if !state
+        .content
+        .questions
+        .iter()
+        .any(|question| question.id == question_id)
+    {
+        return Err(AppError::NotFound(
+            ErrorCode::QuestionNotFound,
+            "question not found".into(),
+        ));
+    }
+    let reason: String = payload
+        .reason
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_REPORT_REASON_LENGTH)
+        .collect();
+    if reason.is_empty() {
+        return Err(AppError::BadRequest(
+            ErrorCode::ReportReasonRequired,
+            "say what is wrong with the question".into(),
+        ));
+    }
+
+    state
+        .question_reports
+        .write()
+        .await
+        .entry(question_id.clone())
+        .or_default()
+        .record(reason);
+    info!(question = %question_id, "question reported");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct QuestionReportSummary {
+    question_id: String,
+    text: String,
+    #[serde(flatten)]
+    reports: QuestionReports,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct QuestionReportsResponse {
+    questions: Vec<QuestionReportSummary>,
+}
+
+#[derive(Deserialize)]
+struct LocaleParams {
+    #[serde(default)]
+    locale: Option<String>,
+}
+
+/// Operator view of reported questions, most reported first.
+async fn get_question_reports(
+    State(state): State<SharedState>,
+    Query(params): Query<LocaleParams>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    state.ensure_admin(&headers)?;
+    let content = state.localized_content(params.locale.as_deref())?;
+    let reports = state.question_reports.read().await;
+    let mut questions: Vec<QuestionReportSummary> = reports
+        .iter()
+        .map(|(question_id, reports)| QuestionReportSummary {
+            question_id: question_id.clone(),
+            text: content
+                .questions
+                .iter()
+                .find(|question| &question.id == question_id)
+                .map(|question| question.text.clone())
+                .unwrap_or_default(),
+            reports: reports.clone(),
+        })
+        .collect();
+    questions.sort_by(|a, b| {
+        b.reports
+            .count
+            .cmp(&a.reports.count)
+            .then_with(|| a.question_id.cmp(&b.question_id))
+    });
+    Ok((StatusCode::OK, Json(QuestionReportsResponse { questions })))
+}
+
+async fn get_content_stats(State(state): State<SharedState>) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(state.content_stats.clone()),
+    )
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LocationCatalogResponse {
+    locations: Vec<LocationDefinition>,
+}
+
+#[derive(Deserialize)]
+struct LocationCatalogParams {
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    locale: Option<String>,
+}
+
+async fn get_content_locations(
+    State(state): State<SharedState>,
+    Query(params): Query<LocationCatalogParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let content = state.localized_content(params.locale.as_deref())?;
+    let tags: Vec<String> = params
+        .tag
+        .map(|tag| tag.trim().to_owned())
+        .filter(|tag| !tag.is_empty())
+        .into_iter()
+        .collect();
+    let locations = content
+        .locations
+        .iter()
+        .filter(|location| tags.is_empty() || location.has_any_tag(&tags))
+        .cloned()
+        .collect();
+    Ok((StatusCode::OK, Json(LocationCatalogResponse { locations })))
+}
+
+/// Arms whichever clock the round is on: an open last chance or forced vote
+/// takes the place of the round clock.
+fn schedule_round_timer(state: &SharedState, game: &mut Game) {
+    game.cancel_round_timer();
+    schedule_narrowing_timer(state, game);
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    if round.last_chance.is_some() {
+        schedule_last_chance_timer(state, game);
+        return;
+    }
+    if round.forced_vote_deadline.is_some() {
+        schedule_forced_vote_timer(state, game);
+        return;
+    }
+
+    let round_number = round.round_number;
+    let remaining = round.time_remaining();
+    let code = game.code.clone();
+    schedule_turn_timer(state, game);
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(remaining).await;
+        let mut games = state.games.write().await;
+        let Some(game) = games.get_mut(&code) else {
+            return;
+        };
+        game.round_timer = None;
+        if game.expire_round(round_number).is_none() {
+            return;
+        }
+        info!(room = %code, round = round_number, "round timer expired");
+        let round = game.current_round_view();
+        let lobby = game.lobby_view();
+        let _ = game.events.send(GameEvent::Round { round });
+        let _ = game.events.send(GameEvent::Lobby { lobby });
+    });
+    game.round_timer = Some(task.abort_handle());
+}
+
+/// Announces each decoy struck under `imposter_narrowing`.
+fn schedule_narrowing_timer(state: &SharedState, game: &mut Game) {
+    game.cancel_narrowing_timer();
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    let Some(mut wait) = round.next_narrowing_in() else {
+        return;
+    };
+
+    let round_number = round.round_number;
+    let code = game.code.clone();
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(wait).await;
+            let games = state.games.read().await;
+            let Some(game) = games.get(&code) else {
+                return;
+            };
+            let Some(round) = game
+                .current_round
+                .as_ref()
+                .filter(|round| round.round_number == round_number && round.is_active())
+            else {
+                return;
+            };
+            let _ = game
+                .events
+                .send(GameEvent::LocationsNarrowed { round_number });
+            match round.next_narrowing_in() {
+                Some(next) => wait = next,
+                None => return,
+            }
+        }
+    });
+    game.narrowing_timer = Some(task.abort_handle());
+}
+
+/// Replaces the round clock with the shorter last-chance window.
+fn schedule_last_chance_timer(state: &SharedState, game: &mut Game) {
+    game.cancel_round_timer();
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    let Some(last_chance) = round.last_chance.as_ref() else {
+        return;
+    };
+
+    let round_number = round.round_number;
+    let remaining = last_chance
+        .deadline
+        .saturating_duration_since(Instant::now());
+    let code = game.code.clone();
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(remaining).await;
+        let mut games = state.games.write().await;
+        let Some(game) = games.get_mut(&code) else {
+            return;
+        };
+        game.round_timer = None;
+        if game.expire_last_chance(round_number).is_none() {
+            return;
+        }
+        info!(room = %code, round = round_number, "last chance expired");
+        let round = game.current_round_view();
+        let lobby = game.lobby_view();
+        let _ = game.events.send(GameEvent::Round { round });
+        let _ = game.events.send(GameEvent::Lobby { lobby });
+    });
+    game.round_timer = Some(task.abort_handle());
+}
+
+/// Replaces the round clock with the forced-vote window.
+fn schedule_forced_vote_timer(state: &SharedState, game: &mut Game) {
+    game.cancel_round_timer();
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    let Some(deadline) = round.forced_vote_deadline else {
+        return;
+    };
+
+    let round_number = round.round_number;
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let code = game.code.clone();
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(remaining).await;
+        let mut games = state.games.write().await;
+        let Some(game) = games.get_mut(&code) else {
+            return;
+        };
+        game.round_timer = None;
+        if game.expire_forced_vote(round_number).is_none() {
+            return;
+        }
+        info!(room = %code, round = round_number, "forced vote lapsed");
+        let round = game.current_round_view();
+        let lobby = game.lobby_view();
+        let _ = game.events.send(GameEvent::Round { round });
+        let _ = game.events.send(GameEvent::Lobby { lobby });
+    });
+    game.round_timer = Some(task.abort_handle());
+}
+
+/// Pause before a bot takes its turn, so humans can follow along.
+const BOT_TURN_DELAY: Duration = Duration::from_secs(2);
+
+fn schedule_bot_turn(state: &SharedState, game: &mut Game) {
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    let Some(bot_id) = round.current_turn().filter(|id| game.is_bot(id)) else {
+        return;
+    };
+    if !round.is_active() || round.paused_at.is_some() {
+        return;
+    }
+
+    let round_number = round.round_number;
+    let turn_serial = round.turn_serial;
+    let code = game.code.clone();
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(BOT_TURN_DELAY).await;
+        let shared = state.content();
+        let mut games = state.games.write().await;
+        let Some(game) = games.get_mut(&code) else {
+            return;
+        };
+        game.bot_timer = None;
+        let still_bots_turn = game.phase == GamePhase::InRound
+            && game.current_round.as_ref().is_some_and(|round| {
+                round.round_number == round_number && round.turn_serial == turn_serial
+            });
+        if !still_bots_turn {
+            return;
+        }
+        let content = game.content(&shared);
+        let response = match game.draw_next_question(bot_id, content.as_ref()) {
+            Ok(response) => response,
+            Err(err) => {
+                warn!(room = %code, bot = %bot_id, error = %err, "bot failed to take its turn");
+                return;
+            }
+        };
+        announce_draw(&state, game, bot_id, &response);
+    });
+    game.bot_timer = Some(task.abort_handle());
+}
+
+fn schedule_turn_timer(state: &SharedState, game: &mut Game) {
+    game.cancel_turn_timer();
+    schedule_bot_turn(state, game);
+    let Some(round) = game.current_round.as_ref() else {
+        return;
+    };
+    let Some(deadline) = round.turn_deadline() else {
+        return;
+    };
+
+    let round_number = round.round_number;
+    let turn_serial = round.turn_serial;
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let code = game.code.clone();
+    let state = Arc::clone(state);
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(remaining).await;
+        let shared = state.content();
+        let mut games = state.games.write().await;
+        let Some(game) = games.get_mut(&code) else {
+            return;
+        };
+        game.turn_timer = None;
+        let content = game.content(&shared);
+        let Some(round) = game.expire_turn(round_number, turn_serial, content.as_ref()) else {
+            return;
+        };
+        info!(room = %code, round = round_number, "turn timer expired");
+        schedule_turn_timer(&state, game);
+        announce_turn(game, &round);
+        let _ = game.events.send(GameEvent::Round { round: Some(round) });
+    });
+    game.turn_timer = Some(task.abort_handle());
+}
+
+async fn handle_socket(
+    socket: WebSocket,
+    state: SharedState,
+    code: RoomCode,
+    subscriber: Subscriber,
+    events: broadcast::Sender<GameEvent>,
+    mut initial: GameSnapshot,
+    protocol: StreamProtocol,
+) {
+    let (mut sender, mut receiver) = socket.split();
+    let Some(version) = protocol.version() else {
+        warn!(room = %code, ?protocol, "rejected unsupported realtime protocol");
+        let reason = format!("unsupported protocol; use {STREAM_PROTOCOL_PREFIX}1");
+        let _ = sender
+            .send(close_message(UNSUPPORTED_PROTOCOL_CLOSE_CODE, reason))
+            .await;
+        return;
+    };
+    info!(room = %code, version, "realtime subscriber connected");
+    let viewer = subscriber.player_id();
+    let compress = version >= COMPRESSED_SNAPSHOTS_SINCE;
+    initial.protocol_version = Some(version);
+    initial.binary_encoding = compress.then(|| "gzip".to_string());
+    let Some(message) = snapshot_message(initial, compress) else {
+        fail_stream(&mut sender, StreamFailure::Internal).await;
+        return;
+    };
+    if sender.send(message).await.is_err() {
+        let _ = sender.close().await;
+        warn!(room = %code, "failed to deliver initial snapshot");
+        return;
+    }
+
+    let spectating = matches!(subscriber, Subscriber::Spectator);
+    if spectating {
+        adjust_spectators(&state, &code, true).await;
+    }
+    state
+        .metrics
+        .connected_subscribers
+        .fetch_add(1, Ordering::Relaxed);
+
+    if viewer.is_some() {
+        record_presence(&state, &code, viewer, false).await;
+    }
+    let mut presence_recorded_at = Instant::now();
+
+    let mut rx = events.subscribe();
+    let _roster = viewer.map(|player_id| PresenceGuard::join(&state, &code, player_id, events));
+    let mut ping_interval = tokio::time::interval(Duration::from_secs(30));
+    let mut coalescer = RoundCoalescer::default();
+    let flush_timer = tokio::time::sleep(ROUND_COALESCE_WINDOW);
+    tokio::pin!(flush_timer);
+    let mut ending = None;
+
+    loop {
+        tokio::select! {
+            _ = ping_interval.tick() => {
+                // Player sockets keep the event channel open themselves, so
+                // a lobby removed without a `closed` event is caught here.
+                if !state.games.read().await.contains_key(&code) {
+                    ending = Some(StreamEnd::Failed(StreamFailure::GameGone));
+                    break;
+                }
+                if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                    break;
+                }
+            }
+            inbound = receiver.next() => {
+                let keepalive = matches!(
+                    &inbound,
+                    Some(Ok(Message::Text(text))) if text.trim().eq_ignore_ascii_case("ping")
+                );
+                let presence_due =
+                    viewer.is_some() && presence_recorded_at.elapsed() >= PRESENCE_REFRESH;
+                if matches!(inbound, Some(Ok(_))) && (keepalive || presence_due) {
+                    record_presence(&state, &code, viewer, keepalive).await;
+                    presence_recorded_at = Instant::now();
+                }
+                match inbound {
+                    Some(Ok(Message::Close(frame))) => {
+                        let _ = sender.send(Message::Close(frame)).await;
+                        break;
+                    }
+                    Some(Ok(Message::Ping(payload))) => {
+                        if sender.send(Message::Pong(payload)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Text(text))) => {
+                        if keepalive {
+                            if let Some(msg) = event_message(&GameEvent::Pong)
+                                && sender.send(msg).await.is_err() {
+                                    break;
+                                }
+                        } else if let Ok(message) = serde_json::from_str::<InboundMessage>(&text) {
+                            match message {
+                                InboundMessage::Chat { player_id, text } => {
+                                    relay_chat(&state, &code, &subscriber, player_id, &text).await;
+                                }
+                                InboundMessage::Answer { player_id, text } => {
+                                    let relayed =
+                                        relay_answer(&state, &code, &subscriber, player_id, &text).await;
+                                    if let Err(err) = relayed {
+                                        warn!(room = %code, player = %player_id, error = %err, "dropped answer");
+                                        if let Some(msg) = event_message(&err.socket_event())
+                                            && sender.send(msg).await.is_err() {
+                                                break;
+                                            }
+                                    }
+                                }
+                                InboundMessage::Whoami { player_id } => {
+                                    let reply =
+                                        private_assignment(&state, &code, &subscriber, player_id).await;
+                                    if let Some(event) = reply
+                                        && let Some(msg) = event_message(&event)
+                                        && sender.send(msg).await.is_err() {
+                                            break;
+                                        }
+                                }
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Binary(_))) | Some(Ok(Message::Pong(_))) => {
+                        // ignore
+                    }
+                    Some(Err(err)) => {
+                        warn!(room = %code, error = %err, "websocket receive error");
+                        break;
+                    }
+                    None => break,
+                }
+            }
+            () = &mut flush_timer, if coalescer.is_pending() => {
+                if let Some(event) = coalescer.flush()
+                    && !deliver_event(&mut sender, &state, &code, viewer, event).await
+                {
+                    break;
+                }
+            }
+            broadcast = rx.recv() => {
+                let end = stream_end(&broadcast);
+                match broadcast {
+                    Ok(event) => {
+                        let (ready, start_window) = coalescer.push(event);
+                        if start_window {
+                            flush_timer
+                                .as_mut()
+                                .reset(tokio::time::Instant::now() + ROUND_COALESCE_WINDOW);
+                        }
+                        let mut delivered = true;
+                        for event in ready {
+                            delivered =
+                                deliver_event(&mut sender, &state, &code, viewer, event).await;
+                            if !delivered {
+                                break;
+                            }
+                        }
+                        if !delivered {
+                            break;
+                        }
+                        if end.is_some() {
+                            ending = end;
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        coalescer.discard();
+                        let Some(snapshot) = latest_snapshot(&state, &code, viewer).await else {
+                            ending = Some(StreamEnd::Failed(StreamFailure::GameGone));
+                            break;
+                        };
+                        let Some(message) = snapshot_message(snapshot, compress) else {
+                            ending = Some(StreamEnd::Failed(StreamFailure::Internal));
+                            break;
+                        };
+                        let notice = GameEvent::Error {
+                            code: ErrorCode::StreamLagged,
+                            message: format!("missed {missed} updates; resyncing from a snapshot"),
+                            fatal: false,
+                        };
+                        if let Some(notice) = event_message(&notice)
+                            && sender.send(notice).await.is_err()
+                        {
+                            break;
+                        }
+                        if sender.send(message).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        if let Some(event) = coalescer.flush() {
+                            deliver_event(&mut sender, &state, &code, viewer, event).await;
+                        }
+                        ending = end;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(end) = ending {
+        if let StreamEnd::Failed(failure) = &end {
+            warn!(room = %code, ?failure, "ending realtime stream");
+        }
+        finish_stream(&mut sender, end).await;
+    }
+    let _ = sender.close().await;
+    state
+        .metrics
+        .connected_subscribers
+        .fetch_sub(1, Ordering::Relaxed);
+    if spectating {
+        adjust_spectators(&state, &code, false).await;
+    }
+    info!(room = %code, "realtime subscriber disconnected");
+}
+
+/// How long a burst of round updates is held so only the newest is sent.
+const ROUND_COALESCE_WINDOW: Duration = Duration::from_millis(50);
+
+/// Collapses back-to-back `Round` broadcasts into the latest one. Any other
+/// event flushes the held round first so subscribers never see them reordered.
+#[derive(Default)]
+struct RoundCoalescer {
+    pending: Option<GameEvent>,
+}
+
+impl RoundCoalescer {
+    /// Returns the events to send immediately and whether a new coalescing
+    /// window just opened.
+    fn push(&mut self, event: GameEvent) -> (Vec<GameEvent>, bool) {
+        if matches!(event, GameEvent::Round { .. }) {
+            let opened = self.pending.is_none();
+            self.pending = Some(event);
+            return (Vec::new(), opened);
+        }
+        let mut ready: Vec<GameEvent> = self.pending.take().into_iter().collect();
+        ready.push(event);
+        (ready, false)
+    }
+
+    fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    fn flush(&mut self) -> Option<GameEvent> {
+        self.pending.take()
+    }
+
+    fn discard(&mut self) {
+        self.pending = None;
+    }
+}
+
+fn close_message(code: u16, reason: impl Into<String>) -> Message {
+    Message::Close(Some(CloseFrame {
+        code,
+        reason: reason.into().into(),
+    }))
+}
+
+/// Why the server is closing a realtime stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StreamEnd {
+    /// The lobby announced it closed; its reason goes in the close frame.
+    Closed(String),
+    Failed(StreamFailure),
+}
+
+/// Whether a stream stops after this receive from its lobby's channel.
+fn stream_end(received: &Result<GameEvent, broadcast::error::RecvError>) -> Option<StreamEnd> {
+    match received {
+        Ok(GameEvent::Closed { reason }) => Some(StreamEnd::Closed(reason.clone())),
+        Err(broadcast::error::RecvError::Closed) => {
+            Some(StreamEnd::Failed(StreamFailure::GameGone))
+        }
+        _ => None,
+    }
+}
+
+async fn finish_stream<S: Sink<Message> + Unpin>(sender: &mut S, end: StreamEnd) {
+    match end {
+        StreamEnd::Closed(reason) => {
+            let _ = sender
+                .send(close_message(GAME_GONE_CLOSE_CODE, reason))
+                .await;
+        }
+        StreamEnd::Failed(failure) => fail_stream(sender, failure).await,
+    }
+}
+
+/// Sends the failure as an `error` event, then closes with its code.
+async fn fail_stream<S: Sink<Message> + Unpin>(sender: &mut S, failure: StreamFailure) {
+    if let Some(message) = event_message(&failure.event()) {
+        let _ = sender.send(message).await;
+    }
+    let _ = sender
+        .send(close_message(failure.close_code(), failure.reason()))
+        .await;
+}
+
+async fn deliver_event(
+    sender: &mut futures::stream::SplitSink<WebSocket, Message>,
+    state: &SharedState,
+    code: &RoomCode,
+    viewer: Option<Uuid>,
+    event: GameEvent,
+) -> bool {
+    let event = personalize_event(state, code, viewer, event).await;
+    match event_message(&event) {
+        Some(message) => sender.send(message).await.is_ok(),
+        None => true,
+    }
+}
+
+async fn adjust_spectators(state: &SharedState, code: &RoomCode, joined: bool) {
+    let mut games = state.games.write().await;
+    let Some(game) = games.get_mut(code) else {
+        return;
+    };
+    game.spectator_count = if joined {
+        game.spectator_count.saturating_add(1)
+    } else {
+        game.spectator_count.saturating_sub(1)
+    };
+    let _ = game.events.send(GameEvent::Spectators {
+        count: game.spectator_count,
+    });
+}
+
+/// Marks the player as seen now, re-broadcasting the lobby only when they
+/// come back from being idle so chatty sockets don't flood subscribers.
+/// With `keepalive`, a socket `ping`, the lobby's idle timer is reset under
+/// the same lock.
+async fn record_presence(
+    state: &SharedState,
+    code: &RoomCode,
+    viewer: Option<Uuid>,
+    keepalive: bool,
+) {
+    let mut games = state.games.write().await;
+    let Some(game) = games.get_mut(code) else {
+        return;
+    };
+    if keepalive {
+        game.touch();
+    }
+    let Some(player) = viewer.and_then(|player_id| game.players.get_mut(&player_id)) else {
+        return;
+    };
+    let now = Instant::now();
+    let was_connected = player.is_connected(now);
+    player.last_seen = Some(now);
+    if !was_connected {
+        let lobby = game.lobby_view();
+        let _ = game.events.send(GameEvent::Lobby { lobby });
+    }
+}
+
+fn event_message(event: &GameEvent) -> Option<Message> {
+    match serde_json::to_string(event) {
+        Ok(payload) => Some(Message::Text(payload)),
+        Err(err) => {
+            warn!(error = %err, "failed to serialize game event");
+            None
+        }
+    }
+}
+
+/// Serializes a snapshot, gzipping it into a binary frame when `compress`
+/// is set and the text would be large. Small snapshots stay plain text.
+fn snapshot_message(snapshot: GameSnapshot, compress: bool) -> Option<Message> {
+    let message = event_message(&GameEvent::Snapshot(Box::new(snapshot)))?;
+    match message {
+        Message::Text(payload) if compress && payload.len() >= SNAPSHOT_COMPRESSION_THRESHOLD => {
+            Some(gzip_text(payload))
+        }
+        message => Some(message),
+    }
+}
+
+fn gzip_text(payload: String) -> Message {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    match encoder
+        .write_all(payload.as_bytes())
+        .and_then(|()| encoder.finish())
+    {
+        Ok(bytes) => Message::Binary(bytes),
+        Err(err) => {
+            warn!(error = %err, "failed to compress snapshot; sending as text");
+            Message::Text(payload)
+        }
+    }
+}
+
+async fn latest_snapshot(
+    state: &SharedState,
+    code: &RoomCode,
+    viewer: Option<Uuid>,
+) -> Option<GameSnapshot> {
+    let games = state.games.read().await;
+    games.get(code).map(|game| game.snapshot_for(viewer))
+}
+
+async fn relay_chat(
+    state: &SharedState,
+    code: &RoomCode,
+    subscriber: &Subscriber,
+    player_id: Uuid,
+    text: &str,
+) {
+    if matches!(subscriber, Subscriber::Spectator)
+        || subscriber
+            .player_id()
+            .is_some_and(|viewer| viewer != player_id)
+    {
+        return;
+    }
+    let games = state.games.read().await;
+    let Some(game) = games.get(code) else {
+        return;
+    };
+    match game.chat_message(player_id, text) {
+        Ok(event) => {
+            let _ = game.events.send(event);
+        }
+        Err(err) => {
+            warn!(room = %code, player = %player_id, error = %err, "dropped chat message");
+        }
+    }
+}
+
+/// Socket counterpart of `POST /round/answer`; only sockets opened with the
+/// answering `player_id` may use it.
+async fn relay_answer(
+    state: &SharedState,
+    code: &RoomCode,
+    subscriber: &Subscriber,
+    player_id: Uuid,
+    text: &str,
+) -> Result<(), AppError> {
+    if subscriber.player_id() != Some(player_id) {
+        return Err(AppError::Forbidden(
+            ErrorCode::InvalidActionToken,
+            "this socket can only answer as its own player".into(),
+        ));
+    }
+    let mut games = state.games.write().await;
+    let game = games
+        .get_mut(code)
+        .ok_or_else(|| AppError::NotFound(ErrorCode::GameNotFound, "game not found".into()))?;
+    let round = game.submit_answer(player_id, text)?;
+    let _ = game.events.send(GameEvent::Round { round: Some(round) });
+    Ok(())
+}
+
+/// Answers a socket's `whoami` with its own assignment. Only sockets opened
+/// with that `player_id` may ask, and nothing is sent outside a round.
+async fn private_assignment(
+    state: &SharedState,
+    code: &RoomCode,
+    subscriber: &Subscriber,
+    player_id: Uuid,
+) -> Option<GameEvent> {
+    if subscriber.player_id() != Some(player_id) {
+        return None;
+    }
+    let games = state.games.read().await;
+    let assignment = games.get(code)?.assignment_for(player_id).ok()?;
+    Some(GameEvent::Assignment { assignment })
+}
+
+async fn personalize_event(
+    state: &SharedState,
+    code: &RoomCode,
+    viewer: Option<Uuid>,
+    event: GameEvent,
+) -> GameEvent {
+    let Some(player_id) = viewer else {
+        return event;
+    };
+    if matches!(event, GameEvent::LocationsNarrowed { .. }) {
+        let games = state.games.read().await;
+        let assignment = games
+            .get(code)
+            .and_then(|game| game.assignment_for(player_id).ok());
+        return match assignment {
+            Some(assignment) if assignment.is_imposter => GameEvent::Assignment { assignment },
+            _ => event,
+        };
+    }
+    match event {
+        GameEvent::Snapshot(mut snapshot) => {
+            snapshot.round = snapshot.round.map(|round| round.for_viewer(viewer));
+            GameEvent::Snapshot(snapshot)
+        }
+        GameEvent::Round { round } => GameEvent::Round {
+            round: round.map(|round| round.for_viewer(viewer)),
+        },
+        other => other,
+    }
+}
+
+async fn health_check() -> &'static str {
+    "ok"
+}
+
+async fn route_not_found() -> AppError {
+    AppError::NotFound(ErrorCode::RouteNotFound, "no such route".into())
+}
+
+async fn method_not_allowed() -> AppError {
+    AppError::MethodNotAllowed(
+        ErrorCode::MethodNotAllowed,
+        "method not allowed for this route".into(),
+    )
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PhaseCounts {
+    lobby: u32,
+    in_round: u32,
+    #[serde(default)]
+    reveal: u32,
+    awaiting_next_round: u32,
+    #[serde(default)]
+    finished: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PublicGameSummary {
+    code: RoomCode,
+    player_count: u32,
+    max_players: u8,
+    question_categories: Vec<String>,
+    created_at_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PublicGamesResponse {
+    games: Vec<PublicGameSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServerStatus {
+    active_games: u32,
+    games_by_phase: PhaseCounts,
+    /// Every seated player, including those with no open connection.
+    total_players: u32,
+    uptime_seconds: u64,
+}
+
+async fn prometheus_metrics(State(state): State<SharedState>) -> impl IntoResponse {
+    let active_games = state.games.read().await.len();
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(active_games),
+    )
+}
+
+async fn list_public_games(State(state): State<SharedState>) -> impl IntoResponse {
+    let games = state.games.read().await;
+    let mut summaries: Vec<PublicGameSummary> =
+        games.values().filter_map(Game::public_summary).collect();
+    drop(games);
+    summaries.sort_by_key(|summary| std::cmp::Reverse(summary.created_at_ms));
+    (
+        StatusCode::OK,
+        Json(PublicGamesResponse { games: summaries }),
+    )
+}
+
+async fn server_status(State(state): State<SharedState>) -> impl IntoResponse {
+    let games = state.games.read().await;
+    let mut games_by_phase = PhaseCounts::default();
+    let mut total_players = 0;
+    for game in games.values() {
+        match game.phase {
+            GamePhase::Lobby => games_by_phase.lobby += 1,
+            GamePhase::InRound => games_by_phase.in_round += 1,
+            GamePhase::Reveal => games_by_phase.reveal += 1,
+            GamePhase::AwaitingNextRound => games_by_phase.awaiting_next_round += 1,
+            GamePhase::Finished => games_by_phase.finished += 1,
+        }
+        total_players += game.players.len() as u32;
+    }
+    let status = ServerStatus {
+        active_games: games.len() as u32,
+        games_by_phase,
+        total_players,
+        uptime_seconds: state.started_at.elapsed().as_secs(),
+    };
+    drop(games);
+    (StatusCode::OK, Json(status))
+}
+
+fn timestamp_ms(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|dur| dur.as_millis().min(u128::from(u64::MAX)) as u64)
+        .unwrap_or_default()
+}
+
+/// The wall clock as it read when the monotonic clock was first consulted.
+/// Deadlines, TTLs and idle checks run on `Instant`, which never jumps back
+/// when NTP steps the system time; wall-clock values are derived from this
+/// pair only for display and persistence.
+fn clock_anchor() -> (Instant, SystemTime) {
+    static ANCHOR: OnceLock<(Instant, SystemTime)> = OnceLock::new();
+    *ANCHOR.get_or_init(|| (Instant::now(), SystemTime::now()))
+}
+
+fn wall_time(instant: Instant) -> SystemTime {
+    let (anchor, wall) = clock_anchor();
+    match instant.checked_duration_since(anchor) {
+        Some(ahead) => wall + ahead,
+        None => wall - anchor.duration_since(instant),
+    }
+}
+
+/// Maps a persisted wall-clock time back onto the monotonic clock, clamping
+/// anything older than the process can represent to the anchor.
+fn instant_at(time: SystemTime) -> Instant {
+    let (anchor, wall) = clock_anchor();
+    match time.duration_since(wall) {
+        Ok(ahead) => anchor + ahead,
+        Err(err) => anchor.checked_sub(err.duration()).unwrap_or(anchor),
+    }
+}
+
+fn instant_ms(instant: Instant) -> u64 {
+    timestamp_ms(wall_time(instant))
+}
+
+#[derive(Debug, Error)]
+enum AppError {
+    #[error("bad request: {1}")]
+    BadRequest(ErrorCode, String),
+    #[error("not found: {1}")]
+    NotFound(ErrorCode, String),
+    #[error("forbidden: {1}")]
+    Forbidden(ErrorCode, String),
+    #[error("conflict: {1}")]
+    Conflict(ErrorCode, String),
+    #[error("method not allowed: {1}")]
+    MethodNotAllowed(ErrorCode, String),
+    #[error("too many requests: {1}")]
+    TooManyRequests(ErrorCode, String),
+    #[error("service unavailable: {1}")]
+    Unavailable(ErrorCode, String),
+    #[error(transparent)]
+    Unexpected(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest(..) => StatusCode::BAD_REQUEST,
+            AppError::NotFound(..) => StatusCode::NOT_FOUND,
+            AppError::Forbidden(..) => StatusCode::FORBIDDEN,
+            AppError::Conflict(..) => StatusCode::CONFLICT,
+            AppError::MethodNotAllowed(..) => StatusCode::METHOD_NOT_ALLOWED,
+            AppError::TooManyRequests(..) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Unavailable(..) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Unexpected(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn code(&self) -> ErrorCode {
+        match self {
+            AppError::BadRequest(code, _)
+            | AppError::NotFound(code, _)
+            | AppError::Forbidden(code, _)
+            | AppError::Conflict(code, _)
+            | AppError::MethodNotAllowed(code, _)
+            | AppError::TooManyRequests(code, _)
+            | AppError::Unavailable(code, _) => *code,
+            AppError::Unexpected(_) => ErrorCode::Internal,
+        }
+    }
+
+    /// The error as told to the socket whose message caused it; the stream
+    /// stays open.
+    fn socket_event(&self) -> GameEvent {
+        GameEvent::Error {
+            code: self.code(),
+            message: self.to_string(),
+            fatal: false,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let code = self.code();
+        let message = self.to_string();
+        let body = Json(ErrorResponse { code, message });
+        (status, body).into_response()
+    }
+}
+
+/// Stable, machine-readable identifier sent alongside every error message so
+/// clients never need to match on human-facing text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum ErrorCode {
+    UnknownCategory,
+    UnknownLocationTag,
+    NotEnoughPlayers,
+    LocationTooSmall,
+    NoQuestionsAvailable,
+    RoundResolved,
+    NoTurnAvailable,
+    NotYourTurn,
+    NotInRound,
+    AnswerRequired,
+    AnswerTooLong,
+    NoQuestionAsked,
+    AlreadyIdentified,
+    InvalidGuess,
+    UnknownLocation,
+    AccusedNotFound,
+    SelfAccusation,
+    InvalidRoomCode,
+    NotAPlayer,
+    ChatRequired,
+    InvalidHostToken,
+    InvalidReconnectToken,
+    NoQuestionsProvided,
+    TooManyCustomQuestions,
+    QuestionRequired,
+    QuestionTooLong,
+    NoLocationsProvided,
+    TooManyCustomLocations,
+    LocationNameRequired,
+    NotEnoughRoles,
+    NoActiveRound,
+    AssignmentNotFound,
+    RoundInProgress,
+    PlayersNotReady,
+    NoLocationsAvailable,
+    PlayerNameRequired,
+    PlayerNameTooLong,
+    DuplicatePlayerName,
+    GameNotFound,
+    GameInProgress,
+    GameFull,
+    CannotKickHost,
+    InvalidPlayerId,
+    LocationPoolPending,
+    NotImposter,
+    PeekDisabled,
+    PeekUsed,
+    RateLimited,
+    ServerAtCapacity,
+    RoundPaused,
+    RoundNotPaused,
+    RouteNotFound,
+    MethodNotAllowed,
+    NoCurrentQuestion,
+    AlreadyVoted,
+    SessionNotFound,
+    SessionPlayerInUse,
+    LastChancePending,
+    InvalidAdminToken,
+    GameFinished,
+    AllLocationsExcluded,
+    AnswerPending,
+    QuestionCapReached,
+    InvalidActionToken,
+    RulesVersionMismatch,
+    NameNotAllowed,
+    UnknownLocale,
+    QuestionNotFound,
+    ReportReasonRequired,
+    TooFewQuestions,
+    NotHost,
+    StreamLagged,
+    Internal,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ErrorResponse {
+    code: ErrorCode,
+    message: String,
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(value: std::io::Error) -> Self {
+        AppError::Unexpected(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn create_game_initializes_lobby() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreateGameResponse = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(created.rules.max_players, GameRules::default().max_players);
+        assert_eq!(state.games.read().await.len(), 1);
+        assert_eq!(
+            state
+                .games
+                .read()
+                .await
+                .get(&created.code)
+                .unwrap()
+                .players
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn join_game_adds_player() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        // Create a lobby to join.
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body_bytes = axum::body::to_bytes(create_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreateGameResponse = serde_json::from_slice(&body_bytes).unwrap();
+
+        let join_uri = format!("/api/games/{}/join", created.code);
+        let join_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(join_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "player_name": "Bob" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(join_response.status(), StatusCode::OK);
+
+        let updated = state
+            .games
+            .read()
+            .await
+            .get(&created.code)
+            .unwrap()
+            .players
+            .len();
+        assert_eq!(updated, 2);
+    }
+
+    #[tokio::test]
+    async fn host_can_start_game_without_readying() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
+        let code = format!("{}", created.code);
+        let host_token = created.host_token;
+
+        let mut player_ids = vec![created.player_id];
+        for name in ["Bob", "Cara"] {
+            let join_uri = format!("/api/games/{}/join", code);
+            let join_response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(&join_uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(json!({ "player_name": name }).to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(join_response.status(), StatusCode::OK);
+            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
+            player_ids.push(joined.player_id);
+        }
+
+        let start_uri = format!("/api/games/{}/start", code);
+        let start_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&start_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(start_response.status(), StatusCode::OK);
+        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let round: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
+        assert_eq!(round.round_number, 1);
+        assert_eq!(round.turn_order.len(), player_ids.len());
+    }
+
+    #[tokio::test]
+    async fn host_can_abort_round() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
+        let code = format!("{}", created.code);
+        let host_token = created.host_token;
+
+        let mut player_ids = vec![created.player_id];
+        for name in ["Bob", "Cara"] {
+            let join_uri = format!("/api/games/{}/join", code);
+            let join_response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(&join_uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(json!({ "player_name": name }).to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(join_response.status(), StatusCode::OK);
+            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
+            player_ids.push(joined.player_id);
+        }
+
+        let start_uri = format!("/api/games/{}/start", code);
+        let start_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&start_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(start_response.status(), StatusCode::OK);
+        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let started_round: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
+        assert_eq!(started_round.round_number, 1);
+        assert_eq!(started_round.turn_order.len(), player_ids.len());
+
+        let abort_uri = format!("/api/games/{}/abort", code);
+        let abort_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&abort_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "host_token": host_token,
+                            "scope": "round"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(abort_response.status(), StatusCode::OK);
+        let abort_body = axum::body::to_bytes(abort_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lobby: GameLobby = serde_json::from_slice(&abort_body).unwrap();
+        assert_eq!(lobby.phase, GamePhase::AwaitingNextRound);
+
+        let round_uri = format!("/api/games/{}/round", code);
+        let round_fetch = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(&round_uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(round_fetch.status(), StatusCode::BAD_REQUEST);
+
+        let next_round_uri = format!("/api/games/{}/round/next", code);
+        let next_round_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&next_round_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(next_round_response.status(), StatusCode::OK);
+        let next_round_body = axum::body::to_bytes(next_round_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let resumed_round: RoundPublicState = serde_json::from_slice(&next_round_body).unwrap();
+        assert_eq!(resumed_round.round_number, 2);
+        assert_eq!(resumed_round.turn_order.len(), player_ids.len());
+    }
+
+    #[tokio::test]
+    async fn imposter_wrong_location_guess_rewards_crew() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
+        let code = format!("{}", created.code);
+
+        let mut player_ids = vec![created.player_id];
+        let mut tokens = HashMap::from([(created.player_id, created.reconnect_token)]);
+        for name in ["Bob", "Cara"] {
+            let join_uri = format!("/api/games/{}/join", code);
+            let join_response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(&join_uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(json!({ "player_name": name }).to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(join_response.status(), StatusCode::OK);
+            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
+            player_ids.push(joined.player_id);
+            tokens.insert(joined.player_id, joined.reconnect_token);
+        }
+
+        let start_uri = format!("/api/games/{}/start", code);
+        let start_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&start_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({ "host_token": created.host_token }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(start_response.status(), StatusCode::OK);
+        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let round_state: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
+        assert_eq!(round_state.round_number, 1);
+        assert_eq!(round_state.turn_order.len(), player_ids.len());
+        let current_turn = round_state
+            .current_turn_player_id
+            .expect("round should provide first turn");
+
+        let question_uri = format!("/api/games/{}/round/question", code);
+        let question_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&question_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "player_id": current_turn,
+                            "action_token": action_token(&state, &created.code, current_turn).await,
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(question_response.status(), StatusCode::OK);
+        let question_body = axum::body::to_bytes(question_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let next_question: NextQuestionResponse = serde_json::from_slice(&question_body).unwrap();
+        assert!(next_question.asked_total >= 1);
+
+        let round_fetch_uri = format!("/api/games/{}/round", code);
+        let round_fetch_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(&round_fetch_uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(round_fetch_response.status(), StatusCode::OK);
+        let round_fetch_body = axum::body::to_bytes(round_fetch_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let refreshed_round: RoundPublicState = serde_json::from_slice(&round_fetch_body).unwrap();
+        assert_eq!(
+            refreshed_round.current_turn_player_id,
+            next_question.next_turn_player_id
+        );
+        assert_eq!(
+            refreshed_round.asked_questions.len(),
+            next_question.asked_total
+        );
+
+        let mut assignments: HashMap<Uuid, PlayerAssignmentView> = HashMap::new();
+        for player_id in &player_ids {
+            let assign_uri = format!(
+                "/api/games/{}/round/my-assignment?token={}",
+                code, tokens[player_id]
+            );
+            let assignment_response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("GET")
+                        .uri(&assign_uri)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(assignment_response.status(), StatusCode::OK);
+            let assignment_body = axum::body::to_bytes(assignment_response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let assignment: PlayerAssignmentView =
+                serde_json::from_slice(&assignment_body).unwrap();
+            assignments.insert(*player_id, assignment);
+        }
+
+        let (imposter_id, location_id) = assignments.iter().fold(
+            (None, None),
+            |(mut imposter, mut location), (player_id, assignment)| {
+                if assignment.is_imposter {
+                    imposter = Some(*player_id);
+                } else if location.is_none() {
+                    location = assignment.location_id;
+                }
+                (imposter, location)
+            },
+        );
+
+        let imposter_id = imposter_id.expect("expected one imposter");
+        let location_id = location_id.expect("crew assignment should include location");
+
+        let locations_uri = format!("/api/games/{}/locations", code);
+        let locations_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(&locations_uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(locations_response.status(), StatusCode::OK);
+        let locations_body = axum::body::to_bytes(locations_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let location_list: LocationListResponse = serde_json::from_slice(&locations_body).unwrap();
+        assert!(!location_list.locations.is_empty());
+        let wrong_location_id = location_list
+            .locations
+            .iter()
+            .find(|option| option.id != location_id)
+            .map(|option| option.id)
+            .unwrap_or(location_id);
+
+        assert_ne!(
+            location_id, wrong_location_id,
+            "need alternative location id"
+        );
+
+        let guess_uri = format!("/api/games/{}/round/guess", code);
+        let guess_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&guess_uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "player_id": imposter_id,
+                            "action_token": action_token(&state, &created.code, imposter_id).await,
+                            "location_id": wrong_location_id
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(guess_response.status(), StatusCode::OK);
+        let guess_body = axum::body::to_bytes(guess_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let guess_result: GuessResponse = serde_json::from_slice(&guess_body).unwrap();
+
+        let resolution = guess_result
+            .resolution
+            .expect("guess should resolve the round");
+        assert!(matches!(resolution.winner, RoundWinner::Crew));
+        match resolution.outcome {
+            RoundOutcome::ImposterFailedLocationGuess {
+                guessed_location_id,
+                actual_location_id,
+                ..
+            } => {
+                assert_eq!(guessed_location_id, wrong_location_id);
+                assert_eq!(actual_location_id, location_id);
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+
+        let lobby_uri = format!("/api/games/{}", code);
+        let lobby_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(&lobby_uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(lobby_response.status(), StatusCode::OK);
+        let lobby_body = axum::body::to_bytes(lobby_response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lobby: GameLobby = serde_json::from_slice(&lobby_body).unwrap();
+        assert_eq!(lobby.phase, GamePhase::Reveal);
+
+        for player in lobby.players {
+            if player.id == imposter_id {
+                assert_eq!(player.imposter_wins, 0);
+            } else {
+                assert_eq!(player.crew_wins, 1);
+            }
+        }
+    }
+
+    fn players_named(names: &[&str]) -> HashMap<Uuid, Player> {
+        names
+            .iter()
+            .map(|name| {
+                let player = Player::new((*name).to_owned(), &NameDenylist::default()).unwrap();
+                (player.id, player)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn public_state_reports_upcoming_turns() {
+        let content = GameContent::load().expect("content should load");
+        let players = players_named(&["Alice", "Bob", "Cara"]);
+        let rules = GameRules::default().normalize(&content).unwrap();
+        let mut rng = thread_rng();
+        let location = content.locations[0].clone();
+        let mut round =
+            RoundState::new(1, location, &players, &rules, &content, &[], &mut rng).unwrap();
+
+        let state = round.public_state();
+        assert_eq!(state.next_turn_player_id, Some(round.turn_order[1]));
+        assert_eq!(state.upcoming_turns, round.turn_order[1..].to_vec());
+
+        let first = round.current_turn().unwrap();
+        round
+            .next_question(first, &rules, &content, &mut rng)
+            .unwrap();
+        let state = round.public_state();
+        assert_eq!(state.current_turn_player_id, Some(round.turn_order[1]));
+        assert_eq!(
+            state.upcoming_turns,
+            vec![round.turn_order[2], round.turn_order[0]]
+        );
+        assert_eq!(state.upcoming, round.turn_order[1..].to_vec());
+        assert_eq!(state.turns_taken[&first], 1);
+        assert_eq!(state.turns_taken[&round.turn_order[2]], 0);
+
+        for _ in 0..2 {
+            let player = round.current_turn().unwrap();
+            round
+                .next_question(player, &rules, &content, &mut rng)
+                .unwrap();
+        }
+        let state = round.public_state();
+        assert_eq!(state.upcoming, round.turn_order);
+        assert!(state.turns_taken.values().all(|turns| *turns == 0));
+        assert!(state.draw_counts.iter().all(|count| count.draws == 1));
+
+        let player = round.current_turn().unwrap();
+        round
+            .next_question(player, &rules, &content, &mut rng)
+            .unwrap();
+        let state = round.public_state();
+        assert_eq!(state.turns_taken[&player], 1);
+        assert_eq!(state.turns_taken.values().sum::<u32>(), 1);
+        assert_eq!(state.upcoming, round.turn_order[1..].to_vec());
+    }
+
+    /// A player's action token, read straight from the lobby.
+    async fn action_token(
+        state: &SharedState,
+        code: &RoomCode,
+        player_id: impl Into<Option<Uuid>>,
+    ) -> Uuid {
+        let games = state.games.read().await;
+        player_id
+            .into()
+            .and_then(|player_id| games[code].players.get(&player_id))
+            .map(|player| player.action_token)
+            .unwrap_or_default()
+    }
+
+    async fn send_json(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut builder = Request::builder().method(method).uri(uri);
+        let body = match body {
+            Some(value) => {
+                builder = builder.header("content-type", "application/json");
+                Body::from(value.to_string())
+            }
+            None => Body::empty(),
+        };
+        let response = app
+            .clone()
+            .oneshot(builder.body(body).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let value = serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null);
+        (status, value)
+    }
+
+    async fn lobby_with_players(
+        app: &Router,
+        rules: Option<serde_json::Value>,
+        guests: &[&str],
+    ) -> (CreateGameResponse, Vec<Uuid>) {
+        let mut payload = json!({ "host_name": "Alice" });
+        if let Some(rules) = rules {
+            payload["rules"] = rules;
+        }
+        let (status, body) = send_json(app, "POST", "/api/games", Some(payload)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: CreateGameResponse = serde_json::from_value(body).unwrap();
+
+        let mut player_ids = vec![created.player_id];
+        for name in guests {
+            let (status, body) = send_json(
+                app,
+                "POST",
+                &format!("/api/games/{}/join", created.code),
+                Some(json!({ "player_name": name })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            let joined: JoinGameResponse = serde_json::from_value(body).unwrap();
+            player_ids.push(joined.player_id);
+        }
+        (created, player_ids)
+    }
+
+    #[tokio::test]
+    async fn imposter_snapshot_hides_history_when_enabled() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "hide_history_from_imposter": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": round.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, round.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let games = state.games.read().await;
+        let game = games.get(&created.code).unwrap();
+        let current = game.current_round.as_ref().unwrap();
+        let crew_id = current
+            .turn_order
+            .iter()
+            .copied()
+            .find(|id| !current.is_imposter(id))
+            .unwrap();
+
+        let imposter_view = game
+            .snapshot_for(Some(current.imposter_ids[0]))
+            .round
+            .unwrap();
+        assert!(imposter_view.history_hidden);
+        assert!(imposter_view.asked_questions.is_empty());
+
+        let crew_view = game.snapshot_for(Some(crew_id)).round.unwrap();
+        assert!(!crew_view.history_hidden);
+        assert_eq!(crew_view.asked_questions.len(), 1);
+
+        let anonymous = game.snapshot_for(None).round.unwrap();
+        assert!(anonymous.history_hidden);
+        assert!(anonymous.asked_questions.is_empty());
+        drop(games);
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/round", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["history_hidden"], true);
+        assert_eq!(body["asked_questions"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn imposter_leaving_mid_round_awards_crew() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, player_ids) = lobby_with_players(&app, None, &["Bob", "Cara", "Dan"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let imposter_id = state.games.read().await[&created.code]
+            .current_round
+            .as_ref()
+            .unwrap()
+            .imposter_ids[0];
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leave", created.code),
+            Some(json!({
+                "player_id": imposter_id,
+                "action_token": action_token(&state, &created.code, imposter_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.phase, GamePhase::Reveal);
+        assert_eq!(lobby.player_count as usize, player_ids.len() - 1);
+        assert_ne!(lobby.leader_id, imposter_id);
+        let resolution = lobby.last_round.unwrap().resolution;
+        assert!(matches!(
+            resolution.outcome,
+            RoundOutcome::ImposterLeft { .. }
+        ));
+        assert!(lobby.players.iter().all(|player| player.crew_wins == 1));
+    }
+
+    #[tokio::test]
+    async fn departures_hand_the_lead_to_a_human_and_announce_aborts() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) =
+            lobby_with_players(&app, Some(json!({ "bot_fill": 3 })), &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/bots", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let leave_uri = format!("/api/games/{}/leave", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &leave_uri,
+            Some(json!({ "player_id": ids[0], "action_token": created.action_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.leader_id, ids[1]);
+
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            game.rules.bot_fill = 0;
+            game.sync_bots().unwrap();
+            assert!(game.players.contains_key(&game.leader_id));
+            game.rules.bot_fill = 1;
+            game.sync_bots().unwrap();
+        }
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token, "forced_imposter_id": ids[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut events = state.games.read().await[&created.code].events.subscribe();
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &leave_uri,
+            Some(json!({
+                "player_id": ids[2],
+                "action_token": action_token(&state, &created.code, ids[2]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        let aborted = std::iter::from_fn(|| events.try_recv().ok()).any(|event| {
+            matches!(
+                event,
+                GameEvent::Aborted {
+                    scope: AbortScope::Round,
+                    ..
+                }
+            )
+        });
+        assert!(aborted);
+        assert_eq!(lobby.phase, GamePhase::AwaitingNextRound);
+    }
+
+    #[tokio::test]
+    async fn reconnect_token_restores_player_identity() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, player_ids) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let reconnect_uri = format!("/api/games/{}/reconnect", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &reconnect_uri,
+            Some(json!({ "reconnect_token": created.reconnect_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let resumed: ReconnectResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(resumed.player_id, created.player_id);
+        assert!(resumed.assignment.is_some());
+        assert!(resumed.snapshot.round.is_some());
+        assert_eq!(resumed.snapshot.lobby.players.len(), player_ids.len());
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &reconnect_uri,
+            Some(json!({ "reconnect_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn majority_vote_resolves_only_after_quorum() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "require_majority_vote": true })),
+            &["Bob", "Cara", "Dan"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (imposter_id, civilians) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.clone().unwrap();
+            let civilians: Vec<Uuid> = round
+                .turn_order
+                .iter()
+                .copied()
+                .filter(|id| !round.is_imposter(id))
+                .collect();
+            (round.imposter_ids[0], civilians)
+        };
+        let guess_uri = format!("/api/games/{}/round/guess", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": civilians[0],
+                "action_token": action_token(&state, &created.code, civilians[0]).await,
+                "accused_player_id": imposter_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let first: GuessResponse = serde_json::from_value(body).unwrap();
+        assert!(first.resolution.is_none());
+        assert_eq!(first.votes[0].votes, 1);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": civilians[1],
+                "action_token": action_token(&state, &created.code, civilians[1]).await,
+                "accused_player_id": imposter_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let second: GuessResponse = serde_json::from_value(body).unwrap();
+        let resolution = second
+            .resolution
+            .expect("majority should resolve the round");
+        assert!(matches!(resolution.winner, RoundWinner::Crew));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn round_timer_expiry_awards_imposter() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "round_time_seconds": 30 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        tokio::time::sleep(Duration::from_secs(31)).await;
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        assert_eq!(game.phase, GamePhase::Reveal);
+        assert!(game.round_timer.is_none());
+        let resolution = game.last_round.as_ref().unwrap().resolution.clone();
+        assert!(matches!(
+            resolution.outcome,
+            RoundOutcome::TimeExpired { .. }
+        ));
+    }
+
+    #[test]
+    fn crew_must_catch_every_imposter() {
+        let content = GameContent::load().expect("content should load");
+        let players = players_named(&["Alice", "Bob", "Cara", "Dan", "Eve"]);
+        let rules = GameRules {
+            imposter_count: 2,
+            ..GameRules::default()
+        }
+        .normalize(&content)
+        .unwrap();
+        let mut rng = thread_rng();
+        let location = content.locations[0].clone();
+        let mut round =
+            RoundState::new(1, location, &players, &rules, &content, &[], &mut rng).unwrap();
+        assert_eq!(round.imposter_ids.len(), 2);
+
+        let accuser = *players.keys().find(|id| !round.is_imposter(id)).unwrap();
+        let first = round.imposter_ids[0];
+        let second = round.imposter_ids[1];
+        let pending = round
+            .resolve_guess(
+                accuser,
+                GuessAction::AccusePlayer { accused_id: first },
+                &rules,
+            )
+            .unwrap();
+        assert!(pending.is_none());
+        assert_eq!(round.remaining_imposters(), 1);
+
+        let resolution = round
+            .resolve_guess(
+                accuser,
+                GuessAction::AccusePlayer { accused_id: second },
+                &rules,
+            )
+            .unwrap()
+            .expect("catching the last imposter should resolve the round");
+        assert!(matches!(resolution.winner, RoundWinner::Crew));
+        match resolution.outcome {
+            RoundOutcome::CrewIdentifiedImposters { impostors, .. } => {
+                assert_eq!(impostors, round.imposter_ids);
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_questions_extend_lobby_categories() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let uri = format!("/api/games/{}/content/questions", created.code);
+
+        let (status, _) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "questions": [{ "text": "   ", "categories": [] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "questions": [
+                    { "text": "Which desk is yours?", "categories": ["Office Life"] },
+                    { "text": "Which desk is yours?", "categories": ["office life"] }
+                ]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let response: CustomQuestionsResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.questions.len(), 1);
+        assert!(response.categories.contains(&"Office Life".to_owned()));
+        assert!(
+            response
+                .questions
+                .iter()
+                .all(|question| question.categories == ["Office Life"])
+        );
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        assert!(
+            game.rules
+                .question_categories
+                .contains(&"office life".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_locations_join_the_pool() {
+        let content = GameContent::load().expect("content should load");
+        let builtin_max = content.next_location_id();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let uri = format!("/api/games/{}/content/locations", created.code);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "locations": [{ "name": "Break Room", "roles": ["Intern"] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "locations": [{ "name": "Break Room", "roles": ["Intern", "Manager"] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let response: CustomLocationsResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.locations.len(), 1);
+        let custom_id = response.locations[0].id;
+        assert!(custom_id >= builtin_max);
+        state
+            .games
+            .write()
+            .await
+            .get_mut(&created.code)
+            .unwrap()
+            .rules
+            .location_pool_size = u8::MAX;
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let games = state.games.read().await;
+        assert!(
+            games[&created.code]
+                .location_pool
+                .iter()
+                .any(|location| location.id == custom_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn require_all_ready_gates_round_start() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, guests) = lobby_with_players(
+            &app,
+            Some(json!({ "require_all_ready": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let start_uri = format!("/api/games/{}/start", created.code);
+        let ready_uri = format!("/api/games/{}/ready", created.code);
+        let start_body = json!({ "host_token": created.host_token });
+
+        let (status, _) = send_json(&app, "POST", &start_uri, Some(start_body.clone())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let mut everyone = vec![created.player_id];
+        everyone.extend(guests);
+        for player_id in &everyone {
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                &ready_uri,
+                Some(json!({
+                    "player_id": player_id,
+                    "action_token": action_token(&state, &created.code, *player_id).await,
+                    "ready": true,
+                })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            let lobby: GameLobby = serde_json::from_value(body).unwrap();
+            assert!(lobby.players.iter().any(|p| p.id == *player_id && p.ready));
+        }
+
+        let (status, _) = send_json(&app, "POST", &start_uri, Some(start_body)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut games = state.games.write().await;
+        let game = games.get_mut(&created.code).unwrap();
+        let impostor = game.current_round.as_ref().unwrap().imposter_ids[0];
+        game.remove_player(impostor).unwrap();
+        assert!(game.players.values().all(|player| !player.ready));
+    }
+
+    #[tokio::test]
+    async fn host_can_transfer_leader() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}/leader", created.code);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token, "new_leader_id": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token, "new_leader_id": players[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.leader_id, players[1]);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": Uuid::new_v4(), "new_leader_id": created.player_id })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn host_can_skip_a_stuck_turn() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let host_body = json!({ "host_token": created.host_token });
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(host_body.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let stuck = started.current_turn_player_id.unwrap();
+
+        let skip_uri = format!("/api/games/{}/round/skip", created.code);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &skip_uri,
+            Some(json!({ "host_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+        let (status, body) = send_json(&app, "POST", &skip_uri, Some(host_body)).await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert_eq!(round.current_turn_player_id, started.next_turn_player_id);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(GameEvent::TurnChanged { current_turn_player_id, .. })
+                if Some(current_turn_player_id) == started.next_turn_player_id
+        ));
+        assert_eq!(round.asked_questions.len(), 1);
+        assert!(round.asked_questions[0].skipped);
+        assert_eq!(round.asked_questions[0].asked_by, stuck);
+        assert_eq!(
+            round.current_question.map(|q| q.id),
+            started.current_question.map(|q| q.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn answers_attach_to_latest_question() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, guests) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+
+        let answer_uri = format!("/api/games/{}/round/answer", created.code);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &answer_uri,
+            Some(json!({
+                "player_id": guests[0],
+                "action_token": action_token(&state, &created.code, guests[0]).await,
+                "answer": "Too early",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": started.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, started.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &answer_uri,
+            Some(json!({
+                "player_id": guests[0],
+                "action_token": action_token(&state, &created.code, guests[0]).await,
+                "answer": "x".repeat(MAX_ANSWER_LENGTH + 1),
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &answer_uri,
+            Some(json!({
+                "player_id": Uuid::new_v4(),
+                "action_token": Uuid::new_v4(),
+                "answer": "Hello",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &answer_uri,
+            Some(json!({
+                "player_id": guests[0],
+                "action_token": action_token(&state, &created.code, guests[0]).await,
+                "answer": "  Plenty of sand  ",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let answers = &round.asked_questions.last().unwrap().answers;
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].player_id, guests[0]);
+        assert_eq!(answers[0].text, "Plenty of sand");
+    }
+
+    #[tokio::test]
+    async fn chat_is_relayed_for_known_players_only() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+
+        relay_chat(
+            &state,
+            &created.code,
+            &Subscriber::Anonymous,
+            Uuid::new_v4(),
+            "hello",
+        )
+        .await;
+        relay_chat(
+            &state,
+            &created.code,
+            &Subscriber::Player(created.player_id),
+            players[1],
+            "spoofed",
+        )
+        .await;
+        relay_chat(
+            &state,
+            &created.code,
+            &Subscriber::Player(players[1]),
+            players[1],
+            &format!("  {}  ", "a".repeat(MAX_CHAT_LENGTH + 20)),
+        )
+        .await;
+
+        match rx.try_recv().expect("chat should be broadcast") {
+            GameEvent::Chat {
+                player_id,
+                name,
+                text,
+                ..
+            } => {
+                assert_eq!(player_id, players[1]);
+                assert_eq!(name, "Bob");
+                assert_eq!(text.chars().count(), MAX_CHAT_LENGTH);
+            }
+            _ => panic!("expected a chat event"),
+        }
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn persisted_lobbies_survive_a_reload() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content.clone()));
+        let app = super::app_router(state.clone());
+
+        let (lobby, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let (status, _) = send_json(
+            &app,
+            "PATCH",
+            &format!("/api/games/{}/content/questions", lobby.code),
+            Some(json!({
+                "host_token": lobby.host_token,
+                "questions": [{ "text": "What smell do you notice first?" }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (in_round, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", in_round.code),
+            Some(json!({ "host_token": in_round.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let path = std::env::temp_dir().join(format!("imposter-{}.json", Uuid::new_v4()));
+        assert_eq!(state.save_games(&path).await.unwrap(), 1);
+
+        let restored = Arc::new(AppState::new(content));
+        assert_eq!(restored.load_games(&path).await.unwrap(), 1);
+        let _ = std::fs::remove_file(&path);
+
+        let games = restored.games.read().await;
+        assert!(!games.contains_key(&in_round.code));
+        let game = &games[&lobby.code];
+        assert_eq!(game.host_token, lobby.host_token);
+        assert!(game.players.contains_key(&players[1]));
+        assert_eq!(game.custom_questions.len(), 1);
+        assert!(game.custom_content.is_some());
+    }
+
+    #[tokio::test]
+    async fn round_history_is_capped_inline_and_paginated() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            for round_number in 1..=15 {
+                let winner = if round_number % 3 == 0 {
+                    RoundWinner::Imposter
+                } else {
+                    RoundWinner::Crew
+                };
+                game.round_history.push(RoundSummary {
+                    round_number,
+                    resolution: RoundResolution {
+                        winner,
+                        outcome: RoundOutcome::TimeExpired {
+                            impostors: Vec::new(),
+                        },
+                        ended_at_ms: 0,
+                        revealed_roles: Vec::new(),
+                    },
+                    started_at_ms: 0,
+                    participants: Vec::new(),
+                });
+            }
+        }
+
+        let (status, body) =
+            send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.total_rounds, 15);
+        assert_eq!(lobby.round_history.len(), LOBBY_HISTORY_LIMIT);
+        assert_eq!(lobby.round_history[0].round_number, 6);
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/history?limit=4&offset=2", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let page: RoundHistoryPage = serde_json::from_value(body).unwrap();
+        assert_eq!(page.total, 15);
+        let numbers: Vec<u32> = page.rounds.iter().map(|r| r.round_number).collect();
+        assert_eq!(numbers, vec![3, 4, 5, 6]);
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/history?winner=Imposter", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let page: RoundHistoryPage = serde_json::from_value(body).unwrap();
+        assert_eq!(page.total, 5);
+        assert!(
+            page.rounds
+                .iter()
+                .all(|r| r.resolution.winner == RoundWinner::Imposter)
+        );
+    }
+
+    #[tokio::test]
+    async fn scoreboard_attributes_round_stats() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (impostor, accuser) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.as_ref().unwrap();
+            let impostor = round.imposter_ids[0];
+            let accuser = *players.iter().find(|id| **id != impostor).unwrap();
+            (impostor, accuser)
+        };
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": accuser,
+                "action_token": action_token(&state, &created.code, accuser).await,
+                "accused_player_id": impostor,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/scoreboard", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let scoreboard: ScoreboardResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(scoreboard.players.len(), 3);
+        assert_eq!(scoreboard.players[2].player_id, impostor);
+        assert!(
+            scoreboard
+                .players
+                .iter()
+                .all(|entry| entry.rounds_played == 1)
+        );
+        assert!(
+            scoreboard
+                .players
+                .iter()
+                .all(|entry| entry.fastest_resolution_ms.is_some())
+        );
+        let accuser_entry = scoreboard
+            .players
+            .iter()
+            .find(|entry| entry.player_id == accuser)
+            .unwrap();
+        assert_eq!(accuser_entry.total_wins, 1);
+        assert_eq!(accuser_entry.correct_accusations, 1);
+    }
+
+    #[test]
+    fn diversified_draws_favor_fresh_categories() {
+        let prompt = |id: &str, category: &str| QuestionPrompt {
+            id: id.to_owned(),
+            text: format!("Question {}", id),
+            categories: vec![category.to_owned()],
+        };
+        let questions = vec![
+            prompt("a1", "alpha"),
+            prompt("a2", "alpha"),
+            prompt("b1", "beta"),
+            prompt("b2", "beta"),
+        ];
+        let content = GameContent {
+            locations: Vec::new(),
+            categories: GameContent::collect_categories(&questions),
+            category_labels: GameContent::collect_category_labels(&questions),
+            questions,
+            player_cap: DEFAULT_PLAYER_CAP,
+            translations: HashMap::new(),
+            base: None,
+        };
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let avoid = vec!["alpha".to_owned()];
+        let repeats = (0..1000)
+            .filter(|_| {
+                content
+                    .random_question(
+                        &[],
+                        true,
+                        &HashSet::new(),
+                        &avoid,
+                        &HashMap::new(),
+                        &mut rng,
+                    )
+                    .is_some_and(|question| question.categories[0] == "alpha")
+            })
+            .count();
+        assert!(
+            repeats < 350,
+            "expected alpha to be down-weighted, got {repeats}"
+        );
+        assert!(repeats > 0);
+    }
+
+    #[tokio::test]
+    async fn errors_carry_machine_readable_codes() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (status, body) = send_json(&app, "GET", "/api/games/ZZZZ", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "GAME_NOT_FOUND");
+        assert_eq!(body["message"], "not found: game not found");
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "INVALID_HOST_TOKEN");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NOT_ENOUGH_PLAYERS");
+    }
+
+    #[test]
+    fn player_names_are_sanitized() {
+        let open = NameDenylist::default();
+        let player = Player::new("  Ada \t\u{7}  Lovelace \n".into(), &open).unwrap();
+        assert_eq!(player.name, "Ada Lovelace");
+        assert!(Player::new("\u{1b}\u{7}".into(), &open).is_err());
+        assert!(Player::new("x".repeat(MAX_PLAYER_NAME_LENGTH + 1), &open).is_err());
+        assert!(Player::new("x".repeat(MAX_PLAYER_NAME_LENGTH), &open).is_ok());
+    }
+
+    #[tokio::test]
+    async fn duplicate_player_names_are_rejected() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/join", created.code),
+            Some(json!({ "player_name": "  bob " })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "DUPLICATE_PLAYER_NAME");
+    }
+
+    #[tokio::test]
+    async fn configured_minimum_players_gates_start() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "min_players": 4, "max_players": 8 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        assert_eq!(created.rules.min_players, 4);
+        let start_uri = format!("/api/games/{}/start", created.code);
+        let start_body = json!({ "host_token": created.host_token });
+
+        let (status, body) = send_json(&app, "POST", &start_uri, Some(start_body.clone())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NOT_ENOUGH_PLAYERS");
+        assert!(
+            body["message"]
+                .as_str()
+                .unwrap()
+                .contains("at least 4 players")
+        );
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/join", created.code),
+            Some(json!({ "player_name": "Dan" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_json(&app, "POST", &start_uri, Some(start_body)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn location_guesses_must_come_from_the_pool() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (impostor, outside_id) = {
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            let outside_id = game
+                .location_pool
+                .iter()
+                .map(|location| location.id)
+                .max()
+                .unwrap()
+                + 1_000;
+            (
+                game.current_round.as_ref().unwrap().imposter_ids[0],
+                outside_id,
+            )
+        };
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": impostor,
+                "action_token": action_token(&state, &created.code, impostor).await,
+                "location_id": outside_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCATION");
+    }
+
+    #[tokio::test]
+    async fn drawing_a_question_emits_question_drawn() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let drawer = started.current_turn_player_id.unwrap();
+
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": drawer,
+                "action_token": action_token(&state, &created.code, drawer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let response: NextQuestionResponse = serde_json::from_value(body).unwrap();
+
+        match rx.try_recv().expect("question event should be broadcast") {
+            GameEvent::QuestionDrawn {
+                question,
+                asked_by,
+                next_turn_player_id,
+            } => {
+                assert_eq!(question.id, response.question.unwrap().id);
+                assert_eq!(asked_by, drawer);
+                assert_eq!(Some(next_turn_player_id), response.next_turn_player_id);
+            }
+            _ => panic!("expected a question_drawn event"),
+        }
+        match rx.try_recv().expect("turn change should be broadcast") {
+            GameEvent::TurnChanged {
+                current_turn_player_id,
+                round_number,
+            } => {
+                assert_eq!(Some(current_turn_player_id), response.next_turn_player_id);
+                assert_eq!(round_number, started.round_number);
+            }
+            _ => panic!("expected a turn_changed event"),
+        }
+        assert!(matches!(rx.try_recv(), Ok(GameEvent::Round { .. })));
+    }
+
+    #[tokio::test]
+    async fn game_creation_is_rate_limited_per_client() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content).with_create_limit(RateLimiter::per_minute(2)));
+        let app = super::app_router(state);
+
+        let create_from = |ip: [u8; 4]| {
+            let app = app.clone();
+            async move {
+                let request = Request::builder()
+                    .method("POST")
+                    .uri("/api/games")
+                    .header("content-type", "application/json")
+                    .extension(ConnectInfo(SocketAddr::from((ip, 4000))))
+                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .unwrap();
+                app.oneshot(request).await.unwrap().status()
+            }
+        };
+
+        assert_eq!(create_from([10, 0, 0, 1]).await, StatusCode::CREATED);
+        assert_eq!(create_from([10, 0, 0, 1]).await, StatusCode::CREATED);
+        assert_eq!(
+            create_from([10, 0, 0, 1]).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(create_from([10, 0, 0, 2]).await, StatusCode::CREATED);
+
+        let payload = json!({ "host_name": "Alice" });
+        for expected in [
+            StatusCode::CREATED,
+            StatusCode::CREATED,
+            StatusCode::TOO_MANY_REQUESTS,
+        ] {
+            let (status, _) = send_json(&app, "POST", "/api/games", Some(payload.clone())).await;
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[test]
+    fn rate_limiter_forgets_the_oldest_drained_clients() {
+        let limiter = RateLimiter::per_minute(1);
+        for index in 0..=RateLimiter::MAX_TRACKED_CLIENTS as u32 {
+            limiter
+                .check(IpAddr::from(index.to_be_bytes()), "slow down")
+                .unwrap();
+        }
+        let buckets = limiter.buckets.lock().unwrap();
+        assert!(buckets.len() < RateLimiter::MAX_TRACKED_CLIENTS);
+        let newest = RateLimiter::MAX_TRACKED_CLIENTS as u32;
+        assert!(buckets.contains_key(&IpAddr::from(newest.to_be_bytes())));
+        assert!(!buckets.contains_key(&IpAddr::from(0u32.to_be_bytes())));
+    }
+
+    #[tokio::test]
+    async fn game_creation_respects_server_capacity() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content).with_max_games(1));
+        let app = super::app_router(state);
+
+        let payload = json!({ "host_name": "Alice" });
+        let (status, _) = send_json(&app, "POST", "/api/games", Some(payload.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, body) = send_json(&app, "POST", "/api/games", Some(payload)).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["code"], "SERVER_AT_CAPACITY");
+    }
+
+    #[tokio::test]
+    async fn imposter_can_peek_once_when_enabled() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(
+            &app,
+            Some(json!({ "imposter_peek": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (impostor, location_id) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.as_ref().unwrap();
+            (round.imposter_ids[0], round.location.id)
+        };
+        let civilian = *players.iter().find(|id| **id != impostor).unwrap();
+        let peek_uri = format!("/api/games/{}/round/peek", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &peek_uri,
+            Some(json!({
+                "player_id": civilian,
+                "action_token": action_token(&state, &created.code, civilian).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "NOT_IMPOSTER");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &peek_uri,
+            Some(json!({
+                "player_id": impostor,
+                "action_token": action_token(&state, &created.code, impostor).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let peek: PeekResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(peek.locations.len(), 2);
+        assert_ne!(peek.locations[0].id, peek.locations[1].id);
+        assert!(peek.locations.iter().any(|option| option.id == location_id));
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &peek_uri,
+            Some(json!({
+                "player_id": impostor,
+                "action_token": action_token(&state, &created.code, impostor).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "PEEK_USED");
+    }
+
+    #[tokio::test]
+    async fn content_locations_include_roles() {
+        let content = GameContent::load().expect("content should load");
+        let expected = content.locations.len();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (status, body) = send_json(&app, "GET", "/api/content/locations", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let catalog: LocationCatalogResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(catalog.locations.len(), expected);
+        assert!(
+            catalog
+                .locations
+                .iter()
+                .all(|location| !location.roles.is_empty())
+        );
+    }
+
+    #[tokio::test]
+    async fn location_tags_filter_catalog_and_pool() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (status, body) = send_json(&app, "GET", "/api/content/locations?tag=Space", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let catalog: LocationCatalogResponse = serde_json::from_value(body).unwrap();
+        assert!(!catalog.locations.is_empty());
+        assert!(
+            catalog
+                .locations
+                .iter()
+                .all(|location| location.tags.contains(&"space".to_owned()))
+        );
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "rules": { "location_tags": ["nowhere"] } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCATION_TAG");
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "location_tags": [" SPACE "] })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        assert_eq!(created.rules.location_tags, vec!["space".to_owned()]);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let games = state.games.read().await;
+        assert!(
+            games[&created.code]
+                .location_pool
+                .iter()
+                .all(|location| location.has_any_tag(&["space".to_owned()]))
+        );
+    }
+
+    #[tokio::test]
+    async fn retried_guesses_with_same_key_replay_resolution() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let impostor = {
+            let games = state.games.read().await;
+            games[&created.code]
+                .current_round
+                .as_ref()
+                .unwrap()
+                .imposter_ids[0]
+        };
+        let accuser = *players.iter().find(|id| **id != impostor).unwrap();
+        let guess_uri = format!("/api/games/{}/round/guess", created.code);
+        let idempotency_key = Uuid::new_v4();
+        let guess = json!({
+            "player_id": accuser,
+            "action_token": action_token(&state, &created.code, accuser).await,
+            "accused_player_id": impostor,
+            "idempotency_key": idempotency_key,
+        });
+
+        let (status, first) = send_json(&app, "POST", &guess_uri, Some(guess.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, retry) = send_json(&app, "POST", &guess_uri, Some(guess)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["resolution"], retry["resolution"]);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": impostor,
+                "action_token": action_token(&state, &created.code, impostor).await,
+                "accused_player_id": accuser,
+                "idempotency_key": idempotency_key,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_RESOLVED");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": accuser,
+                "action_token": action_token(&state, &created.code, accuser).await,
+                "accused_player_id": impostor,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_RESOLVED");
+    }
+
+    #[tokio::test]
+    async fn status_reports_game_and_player_counts() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        lobby_with_players(&app, None, &["Bob"]).await;
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send_json(&app, "GET", "/api/status", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let report: ServerStatus = serde_json::from_value(body).unwrap();
+        assert_eq!(report.active_games, 2);
+        assert_eq!(report.games_by_phase.lobby, 1);
+        assert_eq!(report.games_by_phase.in_round, 1);
+        assert_eq!(report.total_players, 5);
+    }
+
+    #[tokio::test]
+    async fn metrics_track_created_games_and_resolutions() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let impostor = {
+            let games = state.games.read().await;
+            games[&created.code]
+                .current_round
+                .as_ref()
+                .unwrap()
+                .imposter_ids[0]
+        };
+        let accuser = *players.iter().find(|id| **id != impostor).unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": accuser,
+                "action_token": action_token(&state, &created.code, accuser).await,
+                "accused_player_id": impostor,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(text.contains("imposter_games_created_total 1\n"));
+        assert!(text.contains("imposter_rounds_resolved_total{winner=\"crew\"} 1\n"));
+        assert!(text.contains("imposter_rounds_resolved_total{winner=\"imposter\"} 0\n"));
+        assert!(text.contains("imposter_active_games 1\n"));
+    }
+
+    #[tokio::test]
+    async fn players_can_rename_outside_rounds() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let rename_uri = format!("/api/games/{}/rename", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &rename_uri,
+            Some(json!({
+                "player_id": players[1],
+                "action_token": action_token(&state, &created.code, players[1]).await,
+                "name": "CARA",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "DUPLICATE_PLAYER_NAME");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &rename_uri,
+            Some(json!({
+                "player_id": players[1],
+                "action_token": action_token(&state, &created.code, players[1]).await,
+                "name": " Robert  Jr ",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert!(
+            lobby
+                .players
+                .iter()
+                .any(|p| p.id == players[1] && p.name == "Robert Jr")
+        );
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &rename_uri,
+            Some(json!({
+                "player_id": players[1],
+                "action_token": action_token(&state, &created.code, players[1]).await,
+                "name": "Bobby",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_IN_PROGRESS");
+
+        for (phase, expected) in [
+            (GamePhase::Reveal, Some("ROUND_IN_PROGRESS")),
+            (GamePhase::Finished, Some("GAME_FINISHED")),
+            (GamePhase::AwaitingNextRound, None),
+        ] {
+            state
+                .games
+                .write()
+                .await
+                .get_mut(&created.code)
+                .unwrap()
+                .phase = phase;
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                &rename_uri,
+                Some(json!({
+                    "player_id": players[1],
+                    "action_token": action_token(&state, &created.code, players[1]).await,
+                    "name": "Bobby",
+                })),
+            )
+            .await;
+            match expected {
+                Some(code) => {
+                    assert_eq!(status, StatusCode::BAD_REQUEST);
+                    assert_eq!(body["code"], code);
+                }
+                None => assert_eq!(status, StatusCode::OK),
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn turn_timer_advances_stalled_turns() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "round_time_seconds": 300, "turn_time_seconds": 15 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(started.turn_deadline_ms.is_some());
+
+        tokio::time::sleep(Duration::from_secs(16)).await;
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        let round = game.current_round.as_ref().unwrap().public_state();
+        assert_eq!(game.phase, GamePhase::InRound);
+        assert_eq!(round.current_turn_player_id, started.next_turn_player_id);
+        assert_eq!(round.asked_questions.len(), 1);
+        assert!(round.asked_questions[0].skipped);
+        assert!(game.turn_timer.is_some());
+    }
+
+    #[tokio::test]
+    async fn host_can_fetch_every_assignment() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let assignments_uri = format!("/api/games/{}/round/assignments", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &assignments_uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NO_ACTIVE_ROUND");
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &assignments_uri,
+            Some(json!({ "host_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &assignments_uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let response: AssignmentsResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.round_number, 1);
+        assert_eq!(response.assignments.len(), players.len());
+        assert!(
+            players
+                .iter()
+                .all(|id| response.assignments.contains_key(id))
+        );
+        assert_eq!(
+            response
+                .assignments
+                .values()
+                .filter(|assignment| assignment.is_imposter)
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn room_code_format_is_configurable() {
+        let content = GameContent::load().expect("content should load");
+        let format = RoomCodeFormat::new(6, RoomCodeAlphabet::Unambiguous);
+        let state = Arc::new(AppState::new(content).with_room_code_format(format));
+        let app = super::app_router(state.clone());
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: CreateGameResponse = serde_json::from_value(body).unwrap();
+        let code = created.code.to_string();
+        assert_eq!(code.len(), 6);
+        assert!(format.accepts(&code));
+        assert!(!code.contains(['0', '1', 'I', 'L', 'O', 'U']));
+
+        let (status, _) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}", code.to_ascii_lowercase()),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert!(RoomCode::new("ab1o".into(), &format).is_ok());
+        let err = RoomCode::new("ABCDE0".into(), &format).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidRoomCode);
+    }
+
+    #[tokio::test]
+    async fn public_lobbies_are_listed_until_they_start() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (public, _) =
+            lobby_with_players(&app, Some(json!({ "public": true })), &["Bob", "Cara"]).await;
+        let (private, _) = lobby_with_players(&app, None, &["Dan"]).await;
+
+        let (status, body) = send_json(&app, "GET", "/api/games", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let listing: PublicGamesResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(listing.games.len(), 1);
+        assert_eq!(listing.games[0].code, public.code);
+        assert_eq!(listing.games[0].player_count, 3);
+        assert_eq!(listing.games[0].max_players, public.rules.max_players);
+        assert!(listing.games.iter().all(|game| game.code != private.code));
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", public.code),
+            Some(json!({ "host_token": public.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send_json(&app, "GET", "/api/games", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let listing: PublicGamesResponse = serde_json::from_value(body).unwrap();
+        assert!(listing.games.is_empty());
+    }
+
+    #[tokio::test]
+    async fn socket_activity_marks_players_connected() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+
+        record_presence(&state, &created.code, Some(players[1]), false).await;
+        match rx.try_recv().expect("lobby should be re-broadcast") {
+            GameEvent::Lobby { lobby } => {
+                let bob = lobby.players.iter().find(|p| p.id == players[1]).unwrap();
+                assert!(bob.connected);
+                assert!(bob.last_seen_ms.is_some());
+                let alice = lobby.players.iter().find(|p| p.id == players[0]).unwrap();
+                assert!(!alice.connected);
+                assert!(alice.last_seen_ms.is_none());
+            }
+            _ => panic!("expected a lobby event"),
+        }
+
+        record_presence(&state, &created.code, Some(players[1]), false).await;
+        assert!(rx.try_recv().is_err());
+
+        {
+            let mut games = state.games.write().await;
+            let bob = games
+                .get_mut(&created.code)
+                .unwrap()
+                .players
+                .get_mut(&players[1])
+                .unwrap();
+            bob.last_seen = Some(Instant::now() - PLAYER_IDLE_THRESHOLD);
+        }
+        let (_, body) = send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        let bob = lobby.players.iter().find(|p| p.id == players[1]).unwrap();
+        assert!(!bob.connected);
+        assert!(bob.last_seen_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn resolved_rounds_enter_reveal_phase() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(started.reveal.is_none());
+
+        let (impostor, location_id) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.as_ref().unwrap();
+            (round.imposter_ids[0], round.location.id)
+        };
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": impostor,
+                "action_token": action_token(&state, &created.code, impostor).await,
+                "location_id": location_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.phase, GamePhase::Reveal);
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/round", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let reveal = round.reveal.expect("resolved round should be revealed");
+        assert_eq!(reveal.imposter_ids, vec![impostor]);
+        assert_eq!(reveal.location_id, location_id);
+        assert_eq!(reveal.assignments.len(), players.len());
+        let resolution = round.resolution.expect("round should be resolved");
+        assert_eq!(resolution.revealed_roles.len(), players.len() - 1);
+        assert!(resolution.revealed_roles.iter().all(|revealed| {
+            revealed.player_id != impostor
+                && reveal.assignments[&revealed.player_id].role.as_deref()
+                    == Some(revealed.role.as_str())
+        }));
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/next", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let next: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert_eq!(next.round_number, 2);
+        assert!(next.reveal.is_none());
+    }
+
+    #[test]
+    fn small_locations_repeat_roles_when_allowed() {
+        let content = GameContent::load().expect("content should load");
+        let location = LocationDefinition {
+            id: 9_000,
+            name: "Food Truck".into(),
+            roles: vec!["Chef".into(), "Cashier".into()],
+            tags: Vec::new(),
+        };
+        let players: HashMap<Uuid, Player> = ["Alice", "Bob", "Cara", "Dan", "Eve", "Finn"]
+            .into_iter()
+            .map(|name| {
+                let player = Player::new(name.into(), &NameDenylist::default()).unwrap();
+                (player.id, player)
+            })
+            .collect();
+        let mut rng = thread_rng();
+
+        let strict = GameRules::default();
+        let err = RoundState::new(
+            1,
+            location.clone(),
+            &players,
+            &strict,
+            &content,
+            &[],
+            &mut rng,
+        )
+        .err()
+        .expect("strict rules should reject a small location");
+        assert_eq!(err.code(), ErrorCode::LocationTooSmall);
+
+        let relaxed = GameRules {
+            allow_duplicate_roles: true,
+            ..GameRules::default()
+        };
+        let round =
+            RoundState::new(1, location, &players, &relaxed, &content, &[], &mut rng).unwrap();
+        let mut roles: Vec<String> = round
+            .civilian_roles()
+            .into_iter()
+            .map(|revealed| revealed.role)
+            .collect();
+        roles.sort();
+        roles.dedup();
+        assert_eq!(roles.len(), 5);
+        for expected in ["Chef", "Chef 2", "Cashier", "Cashier 2"] {
+            assert!(roles.iter().any(|role| role == expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn whoami_returns_only_the_callers_assignment() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let bob = Subscriber::Player(players[1]);
+        assert!(
+            private_assignment(&state, &created.code, &bob, players[1])
+                .await
+                .is_none()
+        );
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert!(
+            private_assignment(&state, &created.code, &bob, players[2])
+                .await
+                .is_none()
+        );
+        assert!(
+            private_assignment(&state, &created.code, &Subscriber::Spectator, players[1])
+                .await
+                .is_none()
+        );
+        match private_assignment(&state, &created.code, &bob, players[1]).await {
+            Some(GameEvent::Assignment { assignment }) => {
+                assert_eq!(assignment.round_number, 1);
+            }
+            _ => panic!("expected an assignment"),
+        }
+    }
+
+    #[test]
+    fn round_bursts_coalesce_to_the_latest_state() {
+        let round = |round_number: u32| GameEvent::Round {
+            round: serde_json::from_value(json!({
+                "round_number": round_number,
+                "turn_order": [],
+                "current_turn_player_id": null,
+                "next_turn_player_id": null,
+                "upcoming_turns": [],
+                "current_question": null,
+                "asked_questions": [],
+                "imposter_count": 1,
+                "started_at_ms": 0,
+                "deadline_ms": 0,
+                "resolution": null
+            }))
+            .unwrap(),
+        };
+        let round_number = |event: &GameEvent| match event {
+            GameEvent::Round { round: Some(round) } => round.round_number,
+            _ => panic!("expected a round event"),
+        };
+
+        let mut coalescer = RoundCoalescer::default();
+        let (ready, opened) = coalescer.push(round(1));
+        assert!(ready.is_empty() && opened);
+        let (ready, opened) = coalescer.push(round(2));
+        assert!(ready.is_empty() && !opened);
+
+        let (ready, _) = coalescer.push(GameEvent::Pong);
+        assert_eq!(ready.len(), 2);
+        assert_eq!(round_number(&ready[0]), 2);
+        assert!(matches!(ready[1], GameEvent::Pong));
+        assert!(!coalescer.is_pending());
+
+        coalescer.push(round(3));
+        coalescer.push(round(4));
+        let last = coalescer.flush().expect("latest round should be held");
+        assert_eq!(round_number(&last), 4);
+        assert!(coalescer.flush().is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn paused_rounds_freeze_the_clock() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(
+            &app,
+            Some(json!({ "round_time_seconds": 30 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let pause_uri = format!("/api/games/{}/round/pause", created.code);
+        let resume_uri = format!("/api/games/{}/round/resume", created.code);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &pause_uri,
+            Some(json!({ "host_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &pause_uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let paused: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(paused.paused);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": started.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, started.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_PAUSED");
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": players[1],
+                "action_token": action_token(&state, &created.code, players[1]).await,
+                "accused_player_id": players[2],
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_PAUSED");
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/skip", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_PAUSED");
+
+        tokio::time::sleep(Duration::from_secs(31)).await;
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            assert_eq!(game.phase, GamePhase::InRound);
+            // The paused span is measured in wall-clock time; pretend it lasted as long.
+            let round = game.current_round.as_mut().unwrap();
+            round.paused_at = Some(Instant::now() - Duration::from_secs(31));
+        }
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &resume_uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let resumed: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(!resumed.paused);
+        assert!(resumed.deadline_ms >= started.deadline_ms + 31_000);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &resume_uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ROUND_NOT_PAUSED");
+
+        let games = state.games.read().await;
+        assert!(games[&created.code].round_timer.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bots_fill_seats_and_take_their_turns() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "bot_fill": 2, "require_all_ready": true })),
+            &[],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/bots", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.players.len(), 3);
+        assert_eq!(lobby.players.iter().filter(|p| p.is_bot).count(), 2);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/ready", created.code),
+            Some(json!({
+                "player_id": created.player_id,
+                "action_token": created.action_token,
+                "ready": true,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        tokio::time::sleep(BOT_TURN_DELAY * 3).await;
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        let round = game.current_round.as_ref().unwrap();
+        assert_eq!(round.current_turn(), Some(created.player_id));
+        assert!(
+            round
+                .asked_questions
+                .iter()
+                .all(|asked| game.is_bot(&asked.asked_by))
+        );
+        assert!(game.bot_timer.is_none());
+    }
+
+    #[tokio::test]
+    async fn unknown_routes_and_methods_return_json_errors() {
+        let content = GameContent::load().expect("content should load");
+        let app = super::app_router(Arc::new(AppState::new(content)));
+
+        let (status, body) = send_json(&app, "GET", "/api/nope", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "ROUTE_NOT_FOUND");
+        assert!(body["message"].is_string());
+
+        let (status, body) = send_json(&app, "DELETE", "/api/status", None).await;
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(body["code"], "METHOD_NOT_ALLOWED");
+    }
+
+    #[tokio::test]
+    async fn round_state_counts_draws_per_player() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert_eq!(started.draw_counts.len(), players.len());
+        assert!(started.draw_counts.iter().all(|count| count.draws == 0));
+
+        let drawer = started.current_turn_player_id.unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": drawer,
+                "action_token": action_token(&state, &created.code, drawer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/round", created.code),
+            None,
+        )
+        .await;
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        for count in &round.draw_counts {
+            let expected = u32::from(count.player_id == drawer);
+            assert_eq!(count.draws, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn answers_can_arrive_over_the_socket() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": started.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, started.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+
+        let bob = Subscriber::Player(players[1]);
+        let spoofed = relay_answer(&state, &created.code, &bob, players[2], "spoofed").await;
+        assert_eq!(spoofed.unwrap_err().code(), ErrorCode::InvalidActionToken);
+        let anonymous = relay_answer(
+            &state,
+            &created.code,
+            &Subscriber::Anonymous,
+            players[1],
+            "anon",
+        )
+        .await;
+        assert!(anonymous.is_err());
+        let blank = relay_answer(&state, &created.code, &bob, players[1], "   ")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            blank.socket_event(),
+            GameEvent::Error {
+                code: ErrorCode::AnswerRequired,
+                fatal: false,
+                ..
+            }
+        ));
+        assert!(rx.try_recv().is_err());
+
+        relay_answer(&state, &created.code, &bob, players[1], " Lots of sand ")
+            .await
+            .unwrap();
+        match rx.try_recv().expect("round should be broadcast") {
+            GameEvent::Round { round: Some(round) } => {
+                let answers = &round.asked_questions.last().unwrap().answers;
+                assert_eq!(answers.len(), 1);
+                assert_eq!(answers[0].player_id, players[1]);
+                assert_eq!(answers[0].text, "Lots of sand");
+            }
+            _ => panic!("expected a round event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn majority_vote_replaces_the_current_question() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara", "Dan"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let original = started.current_question.unwrap().id;
+        let vote_uri = format!("/api/games/{}/round/skip-question", created.code);
+
+        for (index, voter) in players.iter().take(2).enumerate() {
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                &vote_uri,
+                Some(json!({
+                    "player_id": voter,
+                    "action_token": action_token(&state, &created.code, *voter).await,
+                })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            let round: RoundPublicState = serde_json::from_value(body).unwrap();
+            assert_eq!(round.skip_question_votes.len(), index + 1);
+            assert_eq!(round.current_question.unwrap().id, original);
+        }
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &vote_uri,
+            Some(json!({
+                "player_id": players[0],
+                "action_token": action_token(&state, &created.code, players[0]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ALREADY_VOTED");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &vote_uri,
+            Some(json!({
+                "player_id": players[2],
+                "action_token": action_token(&state, &created.code, players[2]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert_ne!(round.current_question.unwrap().id, original);
+        assert!(round.skip_question_votes.is_empty());
+        assert_eq!(round.current_turn_player_id, started.current_turn_player_id);
+        assert!(round.asked_questions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn seeded_games_play_out_identically() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let mut outcomes = Vec::new();
+        for _ in 0..2 {
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                "/api/games",
+                Some(json!({ "host_name": "Alice", "seed": 1234 })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::CREATED);
+            let created: CreateGameResponse = serde_json::from_value(body).unwrap();
+            for name in ["Bob", "Cara", "Dan"] {
+                let (status, _) = send_json(
+                    &app,
+                    "POST",
+                    &format!("/api/games/{}/join", created.code),
+                    Some(json!({ "player_name": name })),
+                )
+                .await;
+                assert_eq!(status, StatusCode::OK);
+            }
+            let (status, _) = send_json(
+                &app,
+                "POST",
+                &format!("/api/games/{}/start", created.code),
+                Some(json!({ "host_token": created.host_token })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            let round = game.current_round.as_ref().unwrap();
+            let name = |id: &Uuid| game.players[id].name.clone();
+            outcomes.push((
+                round.location.id,
+                round.current_question.as_ref().unwrap().id.clone(),
+                round.imposter_ids.iter().map(name).collect::<Vec<_>>(),
+                round.turn_order.iter().map(name).collect::<Vec<_>>(),
+            ));
+        }
+        assert_eq!(outcomes[0], outcomes[1]);
+    }
+
+    #[test]
+    fn category_weights_bias_question_draws() {
+        fn prompt(id: &str, category: &str) -> QuestionPrompt {
+            QuestionPrompt {
+                id: id.to_owned(),
+                text: id.to_owned(),
+                categories: vec![category.to_owned()],
+            }
+        }
+        let questions = vec![prompt("a1", "alpha"), prompt("b1", "beta")];
+        let content = GameContent {
+            locations: Vec::new(),
+            categories: GameContent::collect_categories(&questions),
+            category_labels: GameContent::collect_category_labels(&questions),
+            questions,
+            player_cap: DEFAULT_PLAYER_CAP,
+            translations: HashMap::new(),
+            base: None,
+        };
+
+        let weights = content
+            .normalize_category_weights(&HashMap::from([("Alpha".to_owned(), 9)]))
+            .unwrap();
+        assert_eq!(weights.get("alpha"), Some(&9));
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let alpha = (0..1000)
+            .filter(|_| {
+                content
+                    .random_question(&[], true, &HashSet::new(), &[], &weights, &mut rng)
+                    .is_some_and(|question| question.id == "a1")
+            })
+            .count();
+        assert!(alpha > 800, "expected alpha to dominate, got {alpha}");
+
+        let unknown = content.normalize_category_weights(&HashMap::from([("gamma".to_owned(), 2)]));
+        assert!(matches!(
+            unknown,
+            Err(AppError::BadRequest(ErrorCode::UnknownCategory, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn can_start_reports_every_blocker() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let rules = json!({ "require_all_ready": true });
+        let (created, _) = lobby_with_players(&app, Some(rules), &["Bob"]).await;
+        let uri = format!("/api/games/{}/can-start", created.code);
+
+        let (status, body) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let report: CanStartResponse = serde_json::from_value(body).unwrap();
+        assert!(!report.can_start);
+        assert_eq!(report.current_players, 2);
+        assert_eq!(report.min_players, 3);
+        assert_eq!(report.reasons.len(), 2);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NOT_ENOUGH_PLAYERS");
+        assert!(
+            body["message"]
+                .as_str()
+                .is_some_and(|message| message.ends_with(&report.reasons[0]))
+        );
+    }
+
+    #[tokio::test]
+    async fn session_leaderboard_spans_games() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+        let session_id = Uuid::new_v4();
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "session_id": session_id })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let first: CreateGameResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(first.session_id, Some(session_id));
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({
+                "host_name": "Alice",
+                "session_id": session_id,
+                "reconnect_token": first.reconnect_token
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let second: CreateGameResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(second.reconnect_token, first.reconnect_token);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/join", second.code),
+            Some(json!({ "player_name": "Al", "reconnect_token": first.reconnect_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "SESSION_PLAYER_IN_USE");
+
+        for created in [&first, &second] {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            for name in ["Bob", "Cara"] {
+                let player = Player::new(name.to_owned(), &NameDenylist::default()).unwrap();
+                game.players.insert(player.id, player);
+            }
+            let content = state.content();
+            game.begin_round(&content, None, None).unwrap();
+            game.expire_round(1).unwrap();
+        }
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/sessions/{session_id}/leaderboard"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let leaderboard: SessionLeaderboard = serde_json::from_value(body).unwrap();
+        assert_eq!(leaderboard.games_played, 2);
+        let alice = leaderboard
+            .players
+            .iter()
+            .find(|standing| standing.name == "Alice")
+            .unwrap();
+        assert_eq!(alice.rounds_played, 2);
+        // Bob and Cara joined each game fresh, so they count once per game.
+        assert_eq!(leaderboard.players.len(), 5);
+
+        let (status, _) = send_json(
+            &app,
+            "GET",
+            &format!("/api/sessions/{}/leaderboard", Uuid::new_v4()),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn misdirected_accusation_opens_a_last_chance() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "imposter_last_chance": true })),
+            &["Bob", "Cara", "Dan"],
+        )
+        .await;
+        let start_uri = format!("/api/games/{}/start", created.code);
+        let guess_uri = format!("/api/games/{}/round/guess", created.code);
+        let host = json!({ "host_token": created.host_token });
+
+        for round_number in 1..=2u32 {
+            let (status, _) = send_json(&app, "POST", &start_uri, Some(host.clone())).await;
+            assert_eq!(status, StatusCode::OK);
+            let (imposter_id, civilians, location_id) = {
+                let games = state.games.read().await;
+                let round = games[&created.code].current_round.clone().unwrap();
+                let civilians: Vec<Uuid> = round
+                    .turn_order
+                    .iter()
+                    .copied()
+                    .filter(|id| !round.is_imposter(id))
+                    .collect();
+                (round.imposter_ids[0], civilians, round.location.id)
+            };
+
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                &guess_uri,
+                Some(json!({
+                    "player_id": civilians[0],
+                    "action_token": action_token(&state, &created.code, civilians[0]).await,
+                    "accused_player_id": civilians[1],
+                })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            let guess: GuessResponse = serde_json::from_value(body).unwrap();
+            assert!(guess.resolution.is_none());
+
+            let (_, body) = send_json(
+                &app,
+                "GET",
+                &format!("/api/games/{}/round", created.code),
+                None,
+            )
+            .await;
+            let round: RoundPublicState = serde_json::from_value(body).unwrap();
+            assert!(round.last_chance_deadline_ms.is_some());
+            assert!(round.turn_deadline_ms.is_none());
+
+            let (status, body) = send_json(
+                &app,
+                "POST",
+                &format!("/api/games/{}/round/question", created.code),
+                Some(json!({
+                    "player_id": civilians[0],
+                    "action_token": action_token(&state, &created.code, civilians[0]).await,
+                })),
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(body["code"], "LAST_CHANCE_PENDING");
+
+            if round_number == 1 {
+                let (status, body) = send_json(
+                    &app,
+                    "POST",
+                    &guess_uri,
+                    Some(json!({
+                        "player_id": imposter_id,
+                        "action_token": action_token(&state, &created.code, imposter_id).await,
+                        "location_id": location_id,
+                    })),
+                )
+                .await;
+                assert_eq!(status, StatusCode::OK);
+                let guess: GuessResponse = serde_json::from_value(body).unwrap();
+                let resolution = guess.resolution.expect("the guess should end the round");
+                assert!(matches!(
+                    resolution.outcome,
+                    RoundOutcome::ImposterIdentifiedLocation { .. }
+                ));
+            } else {
+                tokio::time::sleep(LAST_CHANCE_WINDOW + Duration::from_secs(1)).await;
+                let games = state.games.read().await;
+                let game = &games[&created.code];
+                assert_eq!(game.phase, GamePhase::Reveal);
+                let resolution = game.last_round.as_ref().unwrap().resolution.clone();
+                assert!(matches!(
+                    resolution.outcome,
+                    RoundOutcome::CrewMisdirected { .. }
+                ));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn player_view_bundles_lobby_round_and_assignment() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, guests) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let token = action_token(&state, &created.code, guests[0]).await;
+        let uri = format!(
+            "/api/games/{}/me?player_id={}&action_token={token}",
+            created.code, guests[0]
+        );
+
+        let (status, body) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let view: PlayerView = serde_json::from_value(body).unwrap();
+        assert_eq!(view.lobby.player_count, 3);
+        assert!(view.round.is_none());
+        assert!(view.assignment.is_none());
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let view: PlayerView = serde_json::from_value(body).unwrap();
+        assert_eq!(view.lobby.phase, GamePhase::InRound);
+        assert_eq!(view.round.map(|round| round.round_number), Some(1));
+        assert_eq!(
+            view.assignment.map(|assignment| assignment.round_number),
+            Some(1)
+        );
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!(
+                "/api/games/{}/me?player_id={}&action_token={token}",
+                created.code,
+                Uuid::new_v4()
+            ),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "NOT_A_PLAYER");
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!(
+                "/api/games/{}/me?player_id={}&action_token={token}",
+                created.code, guests[1]
+            ),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "INVALID_ACTION_TOKEN");
+    }
+
+    #[tokio::test]
+    async fn admin_can_force_close_a_lobby() {
+        let content = GameContent::load().expect("content should load");
+        let state =
+            Arc::new(AppState::new(content).with_admin_token(Some("operator-secret".into())));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}", created.code);
+        let mut events = state.games.read().await[&created.code].events.subscribe();
+
+        let close = |token: Option<&str>, uri: &str| {
+            let mut builder = Request::builder().method("DELETE").uri(uri);
+            if let Some(token) = token {
+                builder = builder.header(ADMIN_TOKEN_HEADER, token);
+            }
+            app.clone().oneshot(builder.body(Body::empty()).unwrap())
+        };
+
+        let response = close(None, &uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = close(Some("wrong"), &uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = close(Some("operator-secret"), &uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(!state.games.read().await.contains_key(&created.code));
+        assert!(matches!(events.recv().await, Ok(GameEvent::Closed { .. })));
+
+        let response = close(Some("operator-secret"), &uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn idle_current_turn_player_is_skipped() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "idle_turn_grace_seconds": 5 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert_eq!(state.skip_idle_turns().await, 0);
+
+        let idle = {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            assert_eq!(game.rules.idle_turn_grace_seconds, Some(45));
+            let round = game.current_round.as_mut().unwrap();
+            round.turn_started_at -= Duration::from_secs(60);
+            let idle = round.current_turn().unwrap();
+            game.players.get_mut(&idle).unwrap().last_seen =
+                Some(Instant::now() - Duration::from_secs(90));
+            idle
+        };
+
+        assert_eq!(state.skip_idle_turns().await, 1);
+        let games = state.games.read().await;
+        let round = games[&created.code].current_round.as_ref().unwrap();
+        assert_ne!(round.current_turn(), Some(idle));
+    }
+
+    #[tokio::test]
+    async fn match_finishes_after_max_rounds() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) =
+            lobby_with_players(&app, Some(json!({ "max_rounds": 1 })), &["Bob", "Cara"]).await;
+        let host = json!({ "host_token": created.host_token });
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(host.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (imposter_id, civilian_id) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.clone().unwrap();
+            let civilian = round
+                .turn_order
+                .iter()
+                .copied()
+                .find(|id| !round.is_imposter(id))
+                .unwrap();
+            (round.imposter_ids[0], civilian)
+        };
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": civilian_id,
+                "action_token": action_token(&state, &created.code, civilian_id).await,
+                "accused_player_id": imposter_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.phase, GamePhase::Finished);
+        let standings = lobby
+            .final_scoreboard
+            .expect("finished lobbies carry standings");
+        assert_eq!(standings.len(), 3);
+        assert_eq!(standings[0].total_wins, 1);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/next", created.code),
+            Some(host),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "GAME_FINISHED");
+    }
+
+    #[tokio::test]
+    async fn question_catalog_filters_by_category() {
+        let content = GameContent::load().expect("content should load");
+        let total = content.questions.len();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (status, body) = send_json(&app, "GET", "/api/content/questions", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let all: QuestionCatalogResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(all.questions.len(), total);
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            "/api/content/questions?category=Roles&category=food",
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let filtered: QuestionCatalogResponse = serde_json::from_value(body).unwrap();
+        assert!(!filtered.questions.is_empty());
+        assert!(filtered.questions.len() < total);
+        assert!(filtered.questions.iter().all(|question| {
+            question
+                .categories
+                .iter()
+                .any(|category| category == "roles" || category == "food")
+        }));
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            "/api/content/questions?category=nonsense",
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_CATEGORY");
+    }
+
+    #[test]
+    fn avoid_repeat_imposter_rotates_the_role() {
+        let content = GameContent::load().expect("content should load");
+        let players = players_named(&["Alice", "Bob", "Cara", "Dan"]);
+        let rules = GameRules {
+            avoid_repeat_imposter: true,
+            ..GameRules::default()
+        }
+        .normalize(&content)
+        .unwrap();
+        let location = content.locations[0].clone();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let mut previous: Vec<Uuid> = Vec::new();
+        for round_number in 1..=20 {
+            let round = RoundState::new(
+                round_number,
+                location.clone(),
+                &players,
+                &rules,
+                &content,
+                &previous,
+                &mut rng,
+            )
+            .unwrap();
+            assert!(
+                round.imposter_ids.iter().all(|id| !previous.contains(id)),
+                "round {round_number} repeated an imposter"
+            );
+            previous = round.imposter_ids.clone();
+        }
+
+        // When nobody is eligible the rule yields rather than leaving the seat empty.
+        let trio = players_named(&["Alice", "Bob", "Cara"]);
+        let everyone: Vec<Uuid> = trio.keys().copied().collect();
+        let round =
+            RoundState::new(1, location, &trio, &rules, &content, &everyone, &mut rng).unwrap();
+        assert_eq!(round.imposter_ids.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn content_stats_summarize_the_catalog() {
+        let content = GameContent::load().expect("content should load");
+        let expected_categories = content.categories.len();
+        let expected_locations = content.locations.len();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (status, body) = send_json(&app, "GET", "/api/content/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let stats: ContentStats = serde_json::from_value(body).unwrap();
+        assert_eq!(stats.categories.len(), expected_categories);
+        assert_eq!(stats.locations.len(), expected_locations);
+        assert!(
+            stats
+                .categories
+                .iter()
+                .all(|category| category.questions > 0)
+        );
+        assert!(
+            stats
+                .locations
+                .iter()
+                .any(|location| location.supports_max_players)
+        );
+        assert!(stats.locations.iter().all(|location| {
+            location.supports_max_players
+                == (location.roles + 1 >= u32::from(stats.max_player_capacity))
+        }));
+    }
+
+    #[tokio::test]
+    async fn excluded_locations_never_enter_the_pool() {
+        let content = GameContent::load().expect("content should load");
+        let all_ids: Vec<u32> = content
+            .locations
+            .iter()
+            .map(|location| location.id)
+            .collect();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let excluded: Vec<u32> = all_ids.iter().copied().skip(1).collect();
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "excluded_location_ids": excluded, "location_pool_size": 5 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        {
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            assert!(
+                game.location_pool
+                    .iter()
+                    .all(|location| location.id == all_ids[0])
+            );
+        }
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "rules": { "excluded_location_ids": all_ids } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ALL_LOCATIONS_EXCLUDED");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "rules": { "excluded_location_ids": [u32::MAX] } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCATION");
+    }
+
+    #[tokio::test]
+    async fn answer_rule_holds_the_next_draw() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "require_answer_before_next": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let asker = round.current_turn_player_id.unwrap();
+        let draw_uri = format!("/api/games/{}/round/question", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &draw_uri,
+            Some(json!({
+                "player_id": asker,
+                "action_token": action_token(&state, &created.code, asker).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let asked: NextQuestionResponse = serde_json::from_value(body).unwrap();
+        let answerer = asked.next_turn_player_id;
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &draw_uri,
+            Some(json!({
+                "player_id": answerer,
+                "action_token": action_token(&state, &created.code, answerer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "ANSWER_PENDING");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/answer", created.code),
+            Some(json!({
+                "player_id": answerer,
+                "action_token": action_token(&state, &created.code, answerer).await,
+                "answer": "Busy as always",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(round.awaiting_answer_from.is_none());
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &draw_uri,
+            Some(json!({
+                "player_id": answerer,
+                "action_token": action_token(&state, &created.code, answerer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[test]
+    fn stream_protocol_negotiates_supported_versions() {
+        let offered = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::SEC_WEBSOCKET_PROTOCOL, value.parse().unwrap());
+            StreamProtocol::from_headers(&headers)
+        };
+
+        assert_eq!(
+            StreamProtocol::from_headers(&HeaderMap::new()),
+            StreamProtocol::Legacy
+        );
+        assert_eq!(StreamProtocol::Legacy.version(), Some(1));
+        assert_eq!(
+            offered("imposter.v9, imposter.v1"),
+            StreamProtocol::Negotiated(1)
+        );
+        let unsupported = offered("imposter.v9, chat");
+        assert_eq!(
+            unsupported,
+            StreamProtocol::Unsupported("imposter.v9".into())
+        );
+        assert_eq!(unsupported.version(), None);
+    }
+
+    #[tokio::test]
+    async fn large_snapshots_are_gzipped_only_when_allowed() {
+        use flate2::read::GzDecoder;
+        use std::io::Read;
+
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let mut snapshot = state.games.read().await[&created.code].snapshot();
+
+        assert!(matches!(
+            snapshot_message(snapshot.clone(), true),
+            Some(Message::Text(_))
+        ));
+
+        let players = snapshot.lobby.players.clone();
+        while serde_json::to_string(&snapshot).unwrap().len() < SNAPSHOT_COMPRESSION_THRESHOLD {
+            snapshot.lobby.players.extend(players.iter().cloned());
+        }
+        let Some(Message::Text(plain)) = snapshot_message(snapshot.clone(), false) else {
+            panic!("uncompressed snapshot should be text");
+        };
+        let Some(Message::Binary(bytes)) = snapshot_message(snapshot, true) else {
+            panic!("large snapshot should be compressed");
+        };
+        assert!(bytes.len() < plain.len());
+        let mut inflated = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut inflated)
+            .unwrap();
+        assert_eq!(inflated, plain);
+    }
+
+    #[tokio::test]
+    async fn host_transfer_rotates_the_token() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}/host/transfer", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let moved: TransferHostResponse = serde_json::from_value(body).unwrap();
+        let moved_token = moved.host_token.expect("the host keeps the rotated token");
+        assert_ne!(moved_token, created.host_token);
+        assert_eq!(moved.leader_id, created.player_id);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": moved_token, "new_host_id": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": moved_token, "new_host_id": players[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let handed: TransferHostResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(handed.leader_id, players[1]);
+        assert!(handed.host_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn requests_carry_a_request_id() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let response = app
+            .clone()
+            .oneshot(
                 Request::builder()
-                    .method("POST")
-                    .uri("/api/games")
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
+                    .uri("/healthz")
+                    .header(REQUEST_ID_HEADER, "client-chosen")
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "client-chosen");
 
-        assert_eq!(response.status(), StatusCode::CREATED);
-
-        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/healthz")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
             .await
             .unwrap();
-        let created: CreateGameResponse = serde_json::from_slice(&body_bytes).unwrap();
-        assert_eq!(created.rules.max_players, GameRules::default().max_players);
-        assert_eq!(state.games.read().await.len(), 1);
+        let generated = response.headers()[REQUEST_ID_HEADER].to_str().unwrap();
+        assert!(Uuid::parse_str(generated).is_ok());
+
+        assert_eq!(room_from_path("/api/games/abcd/round"), Some("abcd"));
+        assert_eq!(room_from_path("/api/games"), None);
+        assert_eq!(room_from_path("/api/content/stats"), None);
+    }
+
+    #[tokio::test]
+    async fn question_cap_forces_a_vote() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "max_questions_per_round": 2 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert_eq!(round.asked_total, 0);
+        assert_eq!(round.questions_remaining, Some(1));
+
+        let uri = format!("/api/games/{}/round/question", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "player_id": round.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, round.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let drawn: NextQuestionResponse = serde_json::from_value(body).unwrap();
+        assert!(drawn.question.is_some());
+        assert!(drawn.forced_vote_deadline_ms.is_none());
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "player_id": drawn.next_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, drawn.next_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let last: NextQuestionResponse = serde_json::from_value(body).unwrap();
+        assert!(last.question.is_none());
+        assert_eq!(last.asked_total, 2);
+        assert!(last.forced_vote_deadline_ms.is_some());
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "player_id": drawn.next_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, drawn.next_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "QUESTION_CAP_REACHED");
+
+        let mut games = state.games.write().await;
+        let game = games.get_mut(&created.code).unwrap();
+        let round_number = game.current_round.as_ref().unwrap().round_number;
+        let resolution = game
+            .expire_forced_vote(round_number)
+            .expect("lapsed vote should end the round");
+        assert_eq!(resolution.winner, RoundWinner::Imposter);
+        assert!(matches!(
+            resolution.outcome,
+            RoundOutcome::QuestionsExhausted { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn assignments_require_the_reconnect_token() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json(
+            &app,
+            "GET",
+            &format!(
+                "/api/games/{}/round/assignment/{}",
+                created.code, players[1]
+            ),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let uri = format!("/api/games/{}/round/my-assignment?token=", created.code);
+        let (status, body) =
+            send_json(&app, "GET", &format!("{uri}{}", Uuid::new_v4()), None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "INVALID_RECONNECT_TOKEN");
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("{uri}{}", created.reconnect_token),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let assignment: PlayerAssignmentView = serde_json::from_value(body).unwrap();
+        assert_eq!(assignment.round_number, 1);
+    }
+
+    #[tokio::test]
+    async fn public_player_ids_cannot_act_without_the_action_token() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let drawer = round.current_turn_player_id.unwrap();
+        let uri = format!("/api/games/{}/round/question", created.code);
+
+        let (status, _) = send_json(&app, "POST", &uri, Some(json!({ "player_id": drawer }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "player_id": drawer, "action_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "INVALID_ACTION_TOKEN");
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "player_id": drawer,
+                "action_token": action_token(&state, &created.code, drawer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[test]
+    fn join_order_mode_follows_seating() {
+        let content = GameContent::load().expect("content should load");
+        let mut players = players_named(&["Cara", "Alice", "Bob", "Dan"]);
+        let mut seated: Vec<Uuid> = players.keys().copied().collect();
+        seated.sort_by_key(|id| players[id].name.clone());
+        seated.reverse();
+        for (seq, id) in seated.iter().enumerate() {
+            players.get_mut(id).unwrap().join_seq = seq as u64;
+        }
+        let rules = GameRules {
+            turn_order_mode: TurnOrderMode::JoinOrder,
+            ..GameRules::default()
+        }
+        .normalize(&content)
+        .unwrap();
+        let location = content.locations[0].clone();
+        let mut rng = thread_rng();
+
+        let first = RoundState::new(
+            1,
+            location.clone(),
+            &players,
+            &rules,
+            &content,
+            &[],
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(first.turn_order, seated);
+
+        let second =
+            RoundState::new(2, location, &players, &rules, &content, &[], &mut rng).unwrap();
+        let mut rotated = seated.clone();
+        rotated.rotate_left(1);
+        assert_eq!(second.turn_order, rotated);
+    }
+
+    #[tokio::test]
+    async fn rules_updates_only_touch_sent_fields() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "public": true, "max_rounds": 5, "turn_time_seconds": 30 })),
+            &["Bob"],
+        )
+        .await;
+        let uri = format!("/api/games/{}", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "rules_version": 0,
+                "rules": { "round_time_seconds": 300 }
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.rules.round_time_seconds, 300);
+        assert!(lobby.rules.public);
+        assert_eq!(lobby.rules.max_rounds, Some(5));
+        assert_eq!(lobby.rules.turn_time_seconds, Some(30));
+
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "rules_version": lobby.rules_version,
+                "rules": { "max_rounds": null, "round_time_seconds": 1 }
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.rules.max_rounds, None);
+        assert_eq!(lobby.rules.round_time_seconds, 30);
+        assert_eq!(lobby.rules.turn_time_seconds, Some(30));
+
+        let full = serde_json::to_value(GameRules::default()).unwrap();
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "rules_version": lobby.rules_version,
+                "rules": full
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert!(!lobby.rules.public);
+        assert_eq!(lobby.rules.turn_time_seconds, None);
+        assert_eq!(lobby.rules.round_time_seconds, 120);
+    }
+
+    #[tokio::test]
+    async fn stale_rules_updates_conflict() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}", created.code);
+        let update = |rules_version: u32, rules: serde_json::Value| {
+            json!({
+                "host_token": created.host_token,
+                "rules_version": rules_version,
+                "rules": rules
+            })
+        };
+
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(update(0, json!({ "public": true }))),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.rules_version, 1);
+
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &uri,
+            Some(update(0, json!({ "public": false }))),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "RULES_VERSION_MISMATCH");
+
+        let (_, body) = send_json(&app, "GET", &uri, None).await;
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert!(lobby.rules.public);
+        assert_eq!(lobby.rules_version, 1);
+    }
+
+    #[tokio::test]
+    async fn current_question_is_served_on_its_own() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let uri = format!("/api/games/{}/round/question", created.code);
+        let (status, body) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NO_ACTIVE_ROUND");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+
+        let (status, body) = send_json(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let current: CurrentQuestionResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(current.round_number, started.round_number);
         assert_eq!(
-            state
-                .games
-                .read()
-                .await
-                .get(&created.code)
+            current.question.map(|question| question.id),
+            started.current_question.map(|question| question.id)
+        );
+        assert_eq!(
+            current.current_turn_player_id,
+            started.current_turn_player_id
+        );
+    }
+
+    #[tokio::test]
+    async fn host_can_hand_pick_the_imposter() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob", "Cara", "Dan"]).await;
+        let start_uri = format!("/api/games/{}/start", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &start_uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "forced_imposter_id": Uuid::new_v4(),
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_PLAYER_ID");
+
+        let pick = ids[2];
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &start_uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "forced_imposter_id": pick,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(started.imposter_chosen_by_host);
+
+        let games = state.games.read().await;
+        let round = games[&created.code].current_round.as_ref().unwrap();
+        assert_eq!(round.imposter_ids, vec![pick]);
+        assert!(matches!(
+            round.assignments[&pick],
+            PlayerRoleAssignment::Imposter
+        ));
+        let civilians = round
+            .assignments
+            .values()
+            .filter(|assignment| matches!(assignment, PlayerRoleAssignment::Civilian { .. }))
+            .count();
+        assert_eq!(civilians, ids.len() - 1);
+    }
+
+    #[tokio::test]
+    async fn wins_award_points_by_outcome() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "scoring": { "imposter_location_guess": 5, "crew_accusation": 500 } })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        assert_eq!(created.rules.scoring.imposter_location_guess, 5);
+        assert_eq!(
+            created.rules.scoring.crew_accusation,
+            MAX_POINTS_PER_OUTCOME
+        );
+        assert_eq!(created.rules.scoring.imposter_misdirection, 2);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (imposter, location_id) = {
+            let games = state.games.read().await;
+            let round = games[&created.code].current_round.as_ref().unwrap();
+            (round.imposter_ids[0], round.location.id)
+        };
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/guess", created.code),
+            Some(json!({
+                "player_id": imposter,
+                "action_token": action_token(&state, &created.code, imposter).await,
+                "location_id": location_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        for player in lobby.players {
+            let expected = if player.id == imposter { 5 } else { 0 };
+            assert_eq!(player.points, expected, "{}", player.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn lobby_closes_when_the_last_player_leaves() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) =
+            lobby_with_players(&app, Some(json!({ "bot_fill": 2 })), &["Bob"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/bots", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let mut events = state.games.read().await[&created.code].events.subscribe();
+        let leave_uri = format!("/api/games/{}/leave", created.code);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &leave_uri,
+            Some(json!({
+                "player_id": ids[1],
+                "action_token": action_token(&state, &created.code, ids[1]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(state.games.read().await.contains_key(&created.code));
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &leave_uri,
+            Some(json!({
+                "player_id": created.player_id,
+                "action_token": created.action_token,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!state.games.read().await.contains_key(&created.code));
+        loop {
+            match events.recv().await {
+                Ok(GameEvent::Closed { .. }) => break,
+                Ok(_) => continue,
+                Err(err) => panic!("expected a closed event, got {err:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn rematch_copies_rules_and_reseats_players() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(
+            &app,
+            Some(json!({ "round_time_seconds": 300, "public": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let uri = format!("/api/games/{}/rematch", created.code);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let mut events = state.games.read().await[&created.code].events.subscribe();
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token, "invite_players": true })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rematch: RematchResponse = serde_json::from_value(body).unwrap();
+        assert_ne!(rematch.game.code, created.code);
+        assert_eq!(rematch.game.rules.round_time_seconds, 300);
+        assert!(rematch.game.rules.public);
+        assert_eq!(rematch.game.reconnect_token, created.reconnect_token);
+        assert_eq!(rematch.player_ids.len(), ids.len());
+        assert_eq!(
+            rematch.player_ids[&created.player_id],
+            rematch.game.player_id
+        );
+        assert!(matches!(
+            events.try_recv(),
+            Ok(GameEvent::Rematch { code }) if code == rematch.game.code
+        ));
+
+        let bob_token = state.games.read().await[&created.code].players[&ids[1]].reconnect_token;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/reconnect", rematch.game.code),
+            Some(json!({ "reconnect_token": bob_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["player_id"], json!(rematch.player_ids[&ids[1]]));
+        assert!(state.games.read().await.contains_key(&created.code));
+    }
+
+    #[test]
+    fn monotonic_times_map_onto_the_wall_clock() {
+        let now = Instant::now();
+        let drift = instant_ms(now).abs_diff(timestamp_ms(SystemTime::now()));
+        assert!(drift < 1_000, "drift was {drift}ms");
+
+        let later = now + Duration::from_secs(90);
+        assert_eq!(instant_ms(later) - instant_ms(now), 90_000);
+        let restored = instant_at(wall_time(later));
+        assert!(restored.max(later) - restored.min(later) < Duration::from_millis(1));
+        assert!(instant_at(SystemTime::UNIX_EPOCH) <= now);
+    }
+
+    #[tokio::test]
+    async fn returning_players_get_their_wins_back() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(
+            &app,
+            Some(json!({ "restore_returning_players": true })),
+            &["Bob"],
+        )
+        .await;
+        let bob = ids[1];
+        let token = {
+            let mut games = state.games.write().await;
+            let player = games
+                .get_mut(&created.code)
                 .unwrap()
                 .players
-                .len(),
-            1
+                .get_mut(&bob)
+                .unwrap();
+            player.wins.crew = 2;
+            player.wins.points = 4;
+            player.reconnect_token
+        };
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leave", created.code),
+            Some(json!({
+                "player_id": bob,
+                "action_token": action_token(&state, &created.code, bob).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let join_uri = format!("/api/games/{}/join", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &join_uri,
+            Some(json!({ "player_name": "Robert", "reconnect_token": token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let joined: JoinGameResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(joined.reconnect_token, token);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &join_uri,
+            Some(json!({ "player_name": "Impostor", "reconnect_token": token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let second: JoinGameResponse = serde_json::from_value(body).unwrap();
+        assert_ne!(second.reconnect_token, token);
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        assert_eq!(game.players[&joined.player_id].wins.crew, 2);
+        assert_eq!(game.players[&joined.player_id].wins.points, 4);
+        assert_eq!(game.players[&second.player_id].wins.crew, 0);
+    }
+
+    #[tokio::test]
+    async fn abort_broadcasts_the_hosts_reason() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut rx = state.games.read().await[&created.code].events.subscribe();
+        let reason = format!(
+            " someone\n had   to leave{}",
+            "!".repeat(MAX_ABORT_REASON_LENGTH)
         );
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/abort", created.code),
+            Some(json!({ "host_token": created.host_token, "reason": reason })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        match rx.try_recv().expect("abort should be broadcast") {
+            GameEvent::Aborted { scope, reason } => {
+                assert!(matches!(scope, AbortScope::Round));
+                let reason = reason.expect("reason should be kept");
+                assert!(reason.starts_with("someone had to leave!"));
+                assert_eq!(reason.chars().count(), MAX_ABORT_REASON_LENGTH);
+            }
+            _ => panic!("expected an aborted event"),
+        }
+        assert!(matches!(rx.try_recv(), Ok(GameEvent::Lobby { .. })));
     }
 
     #[tokio::test]
-    async fn join_game_adds_player() {
+    async fn event_stream_forwards_broadcasts_as_sse() {
         let content = GameContent::load().expect("content should load");
         let state = Arc::new(AppState::new(content));
         let app = super::app_router(state.clone());
 
-        // Create a lobby to join.
-        let create_response = app
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}/events", created.code);
+        let (status, _) = send_json(
+            &app,
+            "GET",
+            &format!(
+                "{uri}?player_id={}&action_token={}",
+                created.player_id,
+                Uuid::new_v4()
+            ),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let response = app
             .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/games")
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
-                    .unwrap(),
-            )
+            .oneshot(Request::builder().uri(&uri).body(Body::empty()).unwrap())
             .await
             .unwrap();
-
-        let body_bytes = axum::body::to_bytes(create_response.into_body(), usize::MAX)
-            .await
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/event-stream"
+        );
+        let mut body = response.into_body().into_data_stream();
+        let first = body.next().await.unwrap().unwrap();
+        let first = String::from_utf8_lossy(&first);
+        assert!(first.starts_with("event: snapshot\n"), "{first}");
+        assert!(first.contains(&created.code.to_string()));
+
+        state.games.read().await[&created.code]
+            .events
+            .send(GameEvent::Closed {
+                reason: "done".into(),
+            })
             .unwrap();
-        let created: CreateGameResponse = serde_json::from_slice(&body_bytes).unwrap();
+        let closed = body.next().await.unwrap().unwrap();
+        assert!(String::from_utf8_lossy(&closed).starts_with("event: closed\n"));
+        assert!(body.next().await.is_none());
+    }
 
-        let join_uri = format!("/api/games/{}/join", created.code);
-        let join_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(join_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "player_name": "Bob" }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+    #[tokio::test]
+    async fn denied_names_are_refused_on_join_and_rename() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(
+            AppState::new(content).with_name_denylist(NameDenylist::new(["admin", "Host "])),
+        );
+        let app = super::app_router(state.clone());
 
-        assert_eq!(join_response.status(), StatusCode::OK);
+        let (created, players) = lobby_with_players(&app, None, &["Bob"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/join", created.code),
+            Some(json!({ "player_name": "  A.d M-i-N " })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NAME_NOT_ALLOWED");
+        assert_eq!(body["message"], "bad request: name not allowed");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/rename", created.code),
+            Some(json!({
+                "player_id": players[1],
+                "action_token": action_token(&state, &created.code, players[1]).await,
+                "name": "HOST",
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "NAME_NOT_ALLOWED");
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Hostess" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
 
-        let updated = state
-            .games
-            .read()
-            .await
-            .get(&created.code)
-            .unwrap()
-            .players
-            .len();
-        assert_eq!(updated, 2);
+    #[test]
+    fn server_player_cap_bounds_max_players() {
+        let content = GameContent::load().expect("content should load");
+        let mut big = content.locations[0].clone();
+        big.id = content.next_location_id();
+        big.roles = (0..49).map(|index| format!("Role {index}")).collect();
+        let content = Arc::new(content)
+            .with_custom(&[], &[big])
+            .with_player_cap(10);
+        assert_eq!(content.max_player_capacity(), 50);
+
+        let rules = GameRules {
+            max_players: 50,
+            ..GameRules::default()
+        }
+        .normalize(&content)
+        .unwrap();
+        assert_eq!(rules.max_players, 10);
+
+        let rules = GameRules {
+            max_players: 50,
+            ..GameRules::default()
+        }
+        .normalize(&content.with_player_cap(0))
+        .unwrap();
+        assert_eq!(rules.max_players, MIN_PLAYER_CAP);
     }
 
     #[tokio::test]
-    async fn host_can_start_game_without_readying() {
+    async fn host_can_pick_the_location_from_the_pool() {
         let content = GameContent::load().expect("content should load");
         let state = Arc::new(AppState::new(content));
         let app = super::app_router(state.clone());
 
-        let create_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/games")
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let start_uri = format!("/api/games/{}/start", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &start_uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "forced_location_id": u32::MAX,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCATION");
+
+        let pick = state.games.read().await[&created.code].location_pool[1].id;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &start_uri,
+            Some(json!({
+                "host_token": created.host_token,
+                "forced_location_id": pick,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        assert!(started.location_chosen_by_host);
+        assert!(!started.imposter_chosen_by_host);
 
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
-        let code = format!("{}", created.code);
-        let host_token = created.host_token;
+        let games = state.games.read().await;
+        let round = games[&created.code].current_round.as_ref().unwrap();
+        assert_eq!(round.location.id, pick);
+    }
 
-        let mut player_ids = vec![created.player_id];
-        for name in ["Bob", "Cara"] {
-            let join_uri = format!("/api/games/{}/join", code);
-            let join_response = app
-                .clone()
-                .oneshot(
-                    Request::builder()
-                        .method("POST")
-                        .uri(&join_uri)
-                        .header("content-type", "application/json")
-                        .body(Body::from(json!({ "player_name": name }).to_string()))
-                        .unwrap(),
-                )
-                .await
-                .unwrap();
-            assert_eq!(join_response.status(), StatusCode::OK);
-            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
-                .await
-                .unwrap();
-            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
-            player_ids.push(joined.player_id);
+    #[tokio::test]
+    async fn imposter_shortlist_narrows_as_play_goes_on() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let rules = json!({
+            "imposter_narrowing": true,
+            "narrowing_interval_seconds": 30,
+            "location_pool_size": 6,
+        });
+        let (created, ids) = lobby_with_players(&app, Some(rules), &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "forced_imposter_id": ids[1],
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            let round = game.current_round.as_mut().unwrap();
+            let pool = round.imposter_candidates.len();
+            assert_eq!(pool, 6);
+            assert_eq!(round.remaining_candidates().unwrap().len(), pool);
+            assert!(round.next_narrowing_in().unwrap() <= Duration::from_secs(30));
+
+            round.started_at -= Duration::from_secs(65);
+            let remaining = round.remaining_candidates().unwrap();
+            assert_eq!(remaining.len(), pool - 2);
+            assert!(
+                remaining
+                    .iter()
+                    .any(|option| option.id == round.location.id)
+            );
+
+            round.started_at -= Duration::from_secs(600);
+            assert_eq!(round.remaining_candidates().unwrap().len(), 2);
+            assert_eq!(round.next_narrowing_in(), None);
         }
 
-        let start_uri = format!("/api/games/{}/start", code);
-        let start_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&start_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(start_response.status(), StatusCode::OK);
-        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let round: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
-        assert_eq!(round.round_number, 1);
-        assert_eq!(round.turn_order.len(), player_ids.len());
+        let narrowed = GameEvent::LocationsNarrowed { round_number: 1 };
+        match personalize_event(&state, &created.code, Some(ids[1]), narrowed.clone()).await {
+            GameEvent::Assignment { assignment } => {
+                assert_eq!(assignment.candidate_locations.unwrap().len(), 2);
+            }
+            other => panic!("expected the imposter's assignment, got {other:?}"),
+        }
+        assert!(matches!(
+            personalize_event(&state, &created.code, Some(ids[2]), narrowed).await,
+            GameEvent::LocationsNarrowed { .. }
+        ));
     }
 
     #[tokio::test]
-    async fn host_can_abort_round() {
+    async fn retried_join_returns_the_original_seat() {
         let content = GameContent::load().expect("content should load");
         let state = Arc::new(AppState::new(content));
         let app = super::app_router(state.clone());
 
-        let create_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/games")
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let (created, _) = lobby_with_players(&app, None, &[]).await;
+        let join_uri = format!("/api/games/{}/join", created.code);
+        let join_id = Uuid::new_v4();
+        let payload = json!({ "player_name": "Bob", "client_join_id": join_id });
+
+        let (status, first) = send_json(&app, "POST", &join_uri, Some(payload.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, retry) = send_json(&app, "POST", &join_uri, Some(payload.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(retry, first);
+        assert_eq!(state.games.read().await[&created.code].players.len(), 2);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &join_uri,
+            Some(json!({ "player_name": "Bob", "client_join_id": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "DUPLICATE_PLAYER_NAME");
+
+        let (status, other) = send_json(
+            &app,
+            "POST",
+            &join_uri,
+            Some(json!({ "player_name": "Cara", "client_join_id": join_id })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_ne!(other["player_id"], first["player_id"]);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/ready", created.code),
+            Some(json!({
+                "player_id": first["player_id"],
+                "action_token": first["action_token"],
+                "ready": true,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send_json(&app, "POST", &join_uri, Some(payload)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "DUPLICATE_PLAYER_NAME");
+    }
 
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
-        let code = format!("{}", created.code);
-        let host_token = created.host_token;
+    #[tokio::test]
+    async fn lobbies_play_in_their_chosen_locale() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
 
-        let mut player_ids = vec![created.player_id];
-        for name in ["Bob", "Cara"] {
-            let join_uri = format!("/api/games/{}/join", code);
-            let join_response = app
-                .clone()
-                .oneshot(
-                    Request::builder()
-                        .method("POST")
-                        .uri(&join_uri)
-                        .header("content-type", "application/json")
-                        .body(Body::from(json!({ "player_name": name }).to_string()))
-                        .unwrap(),
-                )
-                .await
-                .unwrap();
-            assert_eq!(join_response.status(), StatusCode::OK);
-            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
-                .await
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "rules": { "locale": "xx" } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCALE");
+
+        let (created, _) =
+            lobby_with_players(&app, Some(json!({ "locale": "es-MX" })), &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let shared = state.content();
+        {
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            assert_eq!(game.rules.locale, "es");
+            let spanish = game.content(&shared);
+            let round = game.current_round.as_ref().unwrap();
+            let expected = spanish
+                .locations
+                .iter()
+                .find(|location| location.id == round.location.id)
                 .unwrap();
-            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
-            player_ids.push(joined.player_id);
+            assert_eq!(round.location.name, expected.name);
+            assert_ne!(
+                round.location.name,
+                shared.locations[round.location.id as usize - 1].name
+            );
+            let question = spanish.questions.iter().find(|q| q.id == "q001").unwrap();
+            assert!(question.text.starts_with('¿'));
         }
 
-        let start_uri = format!("/api/games/{}/start", code);
-        let start_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&start_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(start_response.status(), StatusCode::OK);
-        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let started_round: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
-        assert_eq!(started_round.round_number, 1);
-        assert_eq!(started_round.turn_order.len(), player_ids.len());
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/abort", created.code),
+            Some(json!({ "host_token": created.host_token, "scope": "round" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &format!("/api/games/{}", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "rules_version": 0,
+                "rules": { "locale": "en" }
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        assert!(!game.location_pool.is_empty());
+        assert!(game.location_pool.iter().all(|pooled| {
+            shared
+                .locations
+                .iter()
+                .any(|location| location.id == pooled.id && location.name == pooled.name)
+        }));
+    }
 
-        let abort_uri = format!("/api/games/{}/abort", code);
-        let abort_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&abort_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        json!({
-                            "host_token": host_token,
-                            "scope": "round"
-                        })
-                        .to_string(),
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(abort_response.status(), StatusCode::OK);
-        let abort_body = axum::body::to_bytes(abort_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let lobby: GameLobby = serde_json::from_slice(&abort_body).unwrap();
-        assert_eq!(lobby.phase, GamePhase::AwaitingNextRound);
+    #[tokio::test]
+    async fn reported_questions_reach_the_operator_view() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(
+            AppState::new(content)
+                .with_admin_token(Some("operator-secret".into()))
+                .with_report_limit(RateLimiter::per_minute(1)),
+        );
+        let app = super::app_router(state);
+
+        let report = |question: &str, reason: &str, ip: [u8; 4]| {
+            let request = Request::builder()
+                .method("POST")
+                .uri(format!("/api/content/questions/{question}/report"))
+                .header("content-type", "application/json")
+                .extension(ConnectInfo(SocketAddr::from((ip, 4000))))
+                .body(Body::from(json!({ "reason": reason }).to_string()))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        let status = |response: Result<Response, _>| response.map(|r| r.status()).unwrap();
+
+        assert_eq!(
+            status(report("q999", "bad", [10, 0, 0, 1]).await),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(report("q001", "   ", [10, 0, 0, 2]).await),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(report("q001", "too  vague", [10, 0, 0, 3]).await),
+            StatusCode::NO_CONTENT
+        );
+        assert_eq!(
+            status(report("q001", "unclear", [10, 0, 0, 3]).await),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            status(report("q001", "unclear", [10, 0, 0, 4]).await),
+            StatusCode::NO_CONTENT
+        );
+        assert_eq!(
+            status(report("q002", "duplicate", [10, 0, 0, 5]).await),
+            StatusCode::NO_CONTENT
+        );
 
-        let round_uri = format!("/api/games/{}/round", code);
-        let round_fetch = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("GET")
-                    .uri(&round_uri)
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(round_fetch.status(), StatusCode::BAD_REQUEST);
+        let (status, _) = send_json(&app, "GET", "/api/admin/question-reports", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
 
-        let next_round_uri = format!("/api/games/{}/round/next", code);
-        let next_round_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&next_round_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_token": host_token }).to_string()))
-                    .unwrap(),
-            )
-            .await
+        let request = Request::get("/api/admin/question-reports")
+            .header(ADMIN_TOKEN_HEADER, "operator-secret")
+            .body(Body::empty())
             .unwrap();
-        assert_eq!(next_round_response.status(), StatusCode::OK);
-        let next_round_body = axum::body::to_bytes(next_round_response.into_body(), usize::MAX)
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let resumed_round: RoundPublicState = serde_json::from_slice(&next_round_body).unwrap();
-        assert_eq!(resumed_round.round_number, 2);
-        assert_eq!(resumed_round.turn_order.len(), player_ids.len());
+        let body: QuestionReportsResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.questions.len(), 2);
+        assert_eq!(body.questions[0].question_id, "q001");
+        assert_eq!(body.questions[0].reports.count, 2);
+        assert_eq!(
+            body.questions[0].reports.recent_reasons,
+            vec!["too vague", "unclear"]
+        );
+        assert_eq!(body.questions[1].reports.count, 1);
+    }
+
+    #[test]
+    fn categories_without_questions_are_refused_when_set() {
+        let builtin = GameContent::load().expect("content should load");
+        let questions = vec![QuestionPrompt {
+            id: "a1".into(),
+            text: "a1".into(),
+            categories: vec!["alpha".into()],
+        }];
+        let content = GameContent {
+            locations: builtin.locations.clone(),
+            categories: vec!["alpha".into(), "ghost".into()],
+            category_labels: GameContent::collect_category_labels(&questions),
+            questions,
+            player_cap: DEFAULT_PLAYER_CAP,
+            translations: HashMap::new(),
+            base: None,
+        };
+
+        let rules = GameRules {
+            question_categories: vec!["Ghost".into()],
+            ..GameRules::default()
+        };
+        match rules.normalize(&content) {
+            Err(AppError::BadRequest(ErrorCode::NoQuestionsAvailable, _)) => {}
+            other => panic!("expected NO_QUESTIONS_AVAILABLE, got {other:?}"),
+        }
+
+        let rules = GameRules {
+            question_categories: vec!["alpha".into()],
+            ..GameRules::default()
+        };
+        assert!(rules.normalize(&content).is_ok());
     }
 
     #[tokio::test]
-    async fn imposter_wrong_location_guess_rewards_crew() {
+    async fn presence_roster_follows_connections() {
         let content = GameContent::load().expect("content should load");
         let state = Arc::new(AppState::new(content));
         let app = super::app_router(state.clone());
 
-        let create_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/games")
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "host_name": "Alice" }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let (created, ids) = lobby_with_players(&app, None, &["Bob"]).await;
+        let events = state.games.read().await[&created.code].events.clone();
+        let mut rx = events.subscribe();
+        let mut roster = || match rx.try_recv() {
+            Ok(GameEvent::Presence { connected }) => connected,
+            other => panic!("expected a presence event, got {other:?}"),
+        };
 
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
+        let alice = PresenceGuard::join(&state, &created.code, ids[0], events.clone());
+        assert_eq!(roster(), vec![ids[0]]);
+        let bob = PresenceGuard::join(&state, &created.code, ids[1], events.clone());
+        let bob_again = PresenceGuard::join(&state, &created.code, ids[1], events.clone());
+        roster();
+        let mut both = vec![ids[0], ids[1]];
+        both.sort_unstable();
+        assert_eq!(roster(), both);
+
+        drop(bob);
+        assert_eq!(roster(), both);
+        drop(bob_again);
+        assert_eq!(roster(), vec![ids[0]]);
+        drop(alice);
+        assert!(roster().is_empty());
+        assert!(state.presence.rooms.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn keepalive_pushes_back_lobby_expiry() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content).with_lobby_ttl(Duration::from_secs(600)));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob"]).await;
+        let uri = format!("/api/games/{}/keepalive", created.code);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "player_id": ids[1], "action_token": Uuid::new_v4() })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        state
+            .games
+            .write()
             .await
-            .unwrap();
-        let created: CreateGameResponse = serde_json::from_slice(&body).unwrap();
-        let code = format!("{}", created.code);
+            .get_mut(&created.code)
+            .unwrap()
+            .last_active -= Duration::from_secs(590);
+        assert_eq!(state.purge_expired_lobbies(state.lobby_ttl).await, 0);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({
+                "player_id": ids[1],
+                "action_token": action_token(&state, &created.code, ids[1]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let response: KeepaliveResponse = serde_json::from_value(body).unwrap();
+        let expires_at_ms = response.expires_at_ms.unwrap();
+        let expected = timestamp_ms(SystemTime::now() + Duration::from_secs(600));
+        assert!(expected.abs_diff(expires_at_ms) < 5_000);
 
-        let mut player_ids = vec![created.player_id];
-        for name in ["Bob", "Cara"] {
-            let join_uri = format!("/api/games/{}/join", code);
-            let join_response = app
-                .clone()
-                .oneshot(
-                    Request::builder()
-                        .method("POST")
-                        .uri(&join_uri)
-                        .header("content-type", "application/json")
-                        .body(Body::from(json!({ "player_name": name }).to_string()))
-                        .unwrap(),
-                )
-                .await
+        let games = state.games.read().await;
+        assert!(games[&created.code].last_active.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn guesses_wait_for_the_minimum_questions() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "min_questions_before_guess": 1, "gate_location_guess": true })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let (imposter_id, civilian_id, location_id) = {
+            let games = state.games.read().await;
+            let current = games[&created.code].current_round.clone().unwrap();
+            let civilian = current
+                .turn_order
+                .iter()
+                .copied()
+                .find(|id| !current.is_imposter(id))
                 .unwrap();
-            assert_eq!(join_response.status(), StatusCode::OK);
-            let join_body = axum::body::to_bytes(join_response.into_body(), usize::MAX)
-                .await
+            (current.imposter_ids[0], civilian, current.location.id)
+        };
+        let guess_uri = format!("/api/games/{}/round/guess", created.code);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": civilian_id,
+                "action_token": action_token(&state, &created.code, civilian_id).await,
+                "accused_player_id": imposter_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "TOO_FEW_QUESTIONS");
+        assert!(
+            body["message"]
+                .as_str()
+                .unwrap()
+                .contains("1 more question must")
+        );
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": imposter_id,
+                "action_token": action_token(&state, &created.code, imposter_id).await,
+                "location_id": location_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "TOO_FEW_QUESTIONS");
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": round.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, round.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &guess_uri,
+            Some(json!({
+                "player_id": civilian_id,
+                "action_token": action_token(&state, &created.code, civilian_id).await,
+                "accused_player_id": imposter_id,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let guess: GuessResponse = serde_json::from_value(body).unwrap();
+        assert!(guess.resolution.is_some());
+    }
+
+    #[tokio::test]
+    async fn expired_lobbies_tell_their_streams_why() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content).with_lobby_ttl(Duration::from_secs(600)));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let mut events = {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            game.last_active -= Duration::from_secs(601);
+            game.events.subscribe()
+        };
+        assert_eq!(state.purge_expired_lobbies(state.lobby_ttl).await, 1);
+
+        let (mut sender, mut sent) = futures::channel::mpsc::unbounded::<Message>();
+        let closed = stream_end(&events.recv().await).expect("a closed lobby ends the stream");
+        finish_stream(&mut sender, closed).await;
+        match sent.try_next().unwrap() {
+            Some(Message::Close(Some(frame))) => {
+                assert_eq!(frame.code, GAME_GONE_CLOSE_CODE);
+                assert!(frame.reason.contains("idle"));
+            }
+            other => panic!("expected a close frame, got {other:?}"),
+        }
+
+        let gone = stream_end(&events.recv().await).expect("a dropped channel ends the stream");
+        assert_eq!(gone, StreamEnd::Failed(StreamFailure::GameGone));
+        finish_stream(&mut sender, gone).await;
+        match sent.try_next().unwrap() {
+            Some(Message::Text(text)) => {
+                let event: serde_json::Value = serde_json::from_str(&text).unwrap();
+                assert_eq!(event["type"], "error");
+                assert_eq!(event["code"], "GAME_NOT_FOUND");
+                assert_eq!(event["fatal"], true);
+            }
+            other => panic!("expected an error event, got {other:?}"),
+        }
+        assert!(matches!(
+            sent.try_next().unwrap(),
+            Some(Message::Close(Some(frame))) if frame.code == GAME_GONE_CLOSE_CODE
+        ));
+        let internal = serde_json::to_value(StreamFailure::Internal.event()).unwrap();
+        assert_eq!(internal["fatal"], false);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn departures_pass_the_turn_on() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(
+            &app,
+            Some(json!({ "bot_fill": 2, "turn_time_seconds": 60 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (host, bob, cara) = (ids[0], ids[1], ids[2]);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/bots", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token, "forced_imposter_id": host })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (mut events, serial) = {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            let bots: Vec<Uuid> = game
+                .players
+                .values()
+                .filter(|player| player.is_bot)
+                .map(|player| player.id)
+                .collect();
+            game.cancel_turn_timer();
+            let round = game.current_round.as_mut().unwrap();
+            round.turn_order = vec![host, bob, cara, bots[0], bots[1]];
+            round.current_turn_index = 1;
+            (game.events.subscribe(), round.turn_serial)
+        };
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leave", created.code),
+            Some(json!({
+                "player_id": bob,
+                "action_token": action_token(&state, &created.code, bob).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        {
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            let round = game.current_round.as_ref().unwrap();
+            assert_eq!(round.current_turn(), Some(cara));
+            assert_ne!(round.turn_serial, serial);
+            assert!(game.turn_timer.is_some());
+        }
+        let mut announced = None;
+        while let Ok(event) = events.try_recv() {
+            if let GameEvent::TurnChanged {
+                current_turn_player_id,
+                ..
+            } = event
+            {
+                announced = Some(current_turn_player_id);
+            }
+        }
+        assert_eq!(announced, Some(cara));
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/kick", created.code),
+            Some(json!({ "host_token": created.host_token, "player_id": cara })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        tokio::time::sleep(BOT_TURN_DELAY * 3).await;
+        let games = state.games.read().await;
+        let round = games[&created.code].current_round.as_ref().unwrap();
+        assert_eq!(round.current_turn(), Some(host));
+        assert_eq!(round.asked_questions.len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pausing_holds_the_forced_vote() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "max_questions_per_round": 1 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let round: RoundPublicState = serde_json::from_value(body).unwrap();
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": round.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, round.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let drawn: NextQuestionResponse = serde_json::from_value(body).unwrap();
+        assert!(drawn.forced_vote_deadline_ms.is_some());
+
+        let control = json!({ "host_token": created.host_token });
+        let uri = format!("/api/games/{}/round/pause", created.code);
+        let (status, _) = send_json(&app, "POST", &uri, Some(control.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        tokio::time::sleep(FORCED_VOTE_WINDOW * 2).await;
+        {
+            let mut games = state.games.write().await;
+            let round = games
+                .get_mut(&created.code)
+                .unwrap()
+                .current_round
+                .as_mut()
                 .unwrap();
-            let joined: JoinGameResponse = serde_json::from_slice(&join_body).unwrap();
-            player_ids.push(joined.player_id);
+            assert!(round.is_active());
+            // Tokio's paused clock doesn't move `std::time::Instant`.
+            round.paused_at = Some(Instant::now() - FORCED_VOTE_WINDOW * 2);
         }
 
-        let start_uri = format!("/api/games/{}/start", code);
-        let start_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&start_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        json!({ "host_token": created.host_token }).to_string(),
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let uri = format!("/api/games/{}/round/resume", created.code);
+        let (status, _) = send_json(&app, "POST", &uri, Some(control)).await;
+        assert_eq!(status, StatusCode::OK);
+        {
+            let games = state.games.read().await;
+            let game = &games[&created.code];
+            let deadline = game
+                .current_round
+                .as_ref()
+                .unwrap()
+                .forced_vote_deadline
+                .unwrap();
+            assert!(deadline.saturating_duration_since(Instant::now()) > FORCED_VOTE_WINDOW);
+            assert!(game.round_timer.is_some());
+        }
 
-        assert_eq!(start_response.status(), StatusCode::OK);
-        let start_body = axum::body::to_bytes(start_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let round_state: RoundPublicState = serde_json::from_slice(&start_body).unwrap();
-        assert_eq!(round_state.round_number, 1);
-        assert_eq!(round_state.turn_order.len(), player_ids.len());
-        let current_turn = round_state
-            .current_turn_player_id
-            .expect("round should provide first turn");
+        tokio::time::sleep(FORCED_VOTE_WINDOW * 4).await;
+        let games = state.games.read().await;
+        let round = games[&created.code].current_round.as_ref().unwrap();
+        assert!(matches!(
+            round
+                .resolution
+                .as_ref()
+                .map(|resolution| &resolution.outcome),
+            Some(RoundOutcome::QuestionsExhausted { .. })
+        ));
+    }
 
-        let question_uri = format!("/api/games/{}/round/question", code);
-        let question_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&question_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(json!({ "player_id": current_turn }).to_string()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(question_response.status(), StatusCode::OK);
-        let question_body = axum::body::to_bytes(question_response.into_body(), usize::MAX)
+    #[tokio::test]
+    async fn departures_that_end_a_round_are_tallied() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content).with_lobby_ttl(Duration::from_secs(600)));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let session_id = Uuid::new_v4();
+        state
+            .games
+            .write()
             .await
-            .unwrap();
-        let next_question: NextQuestionResponse = serde_json::from_slice(&question_body).unwrap();
-        assert!(next_question.asked_total >= 1);
+            .get_mut(&created.code)
+            .unwrap()
+            .session_id = Some(session_id);
+        state.attach_session(session_id);
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token, "forced_imposter_id": ids[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leave", created.code),
+            Some(json!({
+                "player_id": ids[1],
+                "action_token": action_token(&state, &created.code, ids[1]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(state.metrics.crew_wins.load(Ordering::Relaxed), 1);
+        let leaderboard = state.session_leaderboard(session_id).unwrap();
+        assert!(
+            leaderboard
+                .players
+                .iter()
+                .all(|standing| standing.rounds_played == 1 && standing.crew_wins == 1)
+        );
+        assert_eq!(leaderboard.players.len(), 2);
 
-        let round_fetch_uri = format!("/api/games/{}/round", code);
-        let round_fetch_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("GET")
-                    .uri(&round_fetch_uri)
-                    .body(Body::empty())
-                    .unwrap(),
-            )
+        // The session outlives its lobby by one TTL, then goes too.
+        state
+            .games
+            .write()
             .await
-            .unwrap();
-        assert_eq!(round_fetch_response.status(), StatusCode::OK);
-        let round_fetch_body = axum::body::to_bytes(round_fetch_response.into_body(), usize::MAX)
+            .get_mut(&created.code)
+            .unwrap()
+            .last_active -= Duration::from_secs(601);
+        assert_eq!(state.purge_expired_lobbies(state.lobby_ttl).await, 1);
+        assert!(state.session_leaderboard(session_id).is_ok());
+        state
+            .sessions
+            .lock()
+            .unwrap()
+            .get_mut(&session_id)
+            .unwrap()
+            .last_active -= Duration::from_secs(601);
+        state.purge_expired_lobbies(state.lobby_ttl).await;
+        assert!(state.session_leaderboard(session_id).is_err());
+    }
+
+    #[tokio::test]
+    async fn host_can_kick_anyone_but_their_own_seat() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let uri = format!("/api/games/{}/kick", created.code);
+        let kick =
+            |player_id: Uuid| json!({ "host_token": created.host_token, "player_id": player_id });
+
+        let (status, body) = send_json(&app, "POST", &uri, Some(kick(ids[2]))).await;
+        assert_eq!(status, StatusCode::OK);
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert!(lobby.players.iter().all(|player| player.id != ids[2]));
+
+        let (status, body) = send_json(&app, "POST", &uri, Some(kick(ids[0]))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "CANNOT_KICK_HOST");
+
+        // Handing the crown on leaves the token holder's seat protected.
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leader", created.code),
+            Some(json!({ "host_token": created.host_token, "new_leader_id": ids[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send_json(&app, "POST", &uri, Some(kick(ids[0]))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "CANNOT_KICK_HOST");
+    }
+
+    #[tokio::test]
+    async fn new_host_claims_the_rotated_token() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/host/transfer", created.code),
+            Some(json!({ "host_token": created.host_token, "new_host_id": ids[1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["host_token"].is_null());
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/kick", created.code),
+            Some(json!({ "host_token": created.host_token, "player_id": ids[2] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let claim_uri = format!("/api/games/{}/host/claim", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &claim_uri,
+            Some(json!({ "player_id": ids[0], "action_token": created.action_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "NOT_HOST");
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &claim_uri,
+            Some(json!({
+                "player_id": ids[1],
+                "action_token": action_token(&state, &created.code, ids[1]).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let claimed: TransferHostResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(claimed.leader_id, ids[1]);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/kick", created.code),
+            Some(json!({ "host_token": claimed.host_token, "player_id": ids[2] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn seeded_lobbies_keep_their_seed() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content.clone()));
+        let app = super::app_router(state.clone());
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            "/api/games",
+            Some(json!({ "host_name": "Alice", "seed": 1234 })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: CreateGameResponse = serde_json::from_value(body).unwrap();
+        state
+            .games
+            .write()
             .await
-            .unwrap();
-        let refreshed_round: RoundPublicState = serde_json::from_slice(&round_fetch_body).unwrap();
-        assert_eq!(
-            refreshed_round.current_turn_player_id,
-            Some(next_question.next_turn_player_id)
-        );
+            .get_mut(&created.code)
+            .unwrap()
+            .round_counter = 2;
+
+        let path = std::env::temp_dir().join(format!("imposter-{}.json", Uuid::new_v4()));
+        assert_eq!(state.save_games(&path).await.unwrap(), 1);
+        let restored = Arc::new(AppState::new(content));
+        assert_eq!(restored.load_games(&path).await.unwrap(), 1);
+        let _ = std::fs::remove_file(&path);
+        {
+            let mut games = restored.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            assert_eq!(game.seed, Some(1234));
+            let expected: u64 = StdRng::seed_from_u64(1236).r#gen();
+            assert_eq!(game.rng.r#gen::<u64>(), expected);
+        }
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/rematch", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rematch: RematchResponse = serde_json::from_value(body).unwrap();
         assert_eq!(
-            refreshed_round.asked_questions.len(),
-            next_question.asked_total
+            state.games.read().await[&rematch.game.code].seed,
+            Some(1236)
         );
+    }
 
-        let mut assignments: HashMap<Uuid, PlayerAssignmentView> = HashMap::new();
-        for player_id in &player_ids {
-            let assign_uri = format!("/api/games/{}/round/assignment/{}", code, player_id);
-            let assignment_response = app
-                .clone()
-                .oneshot(
-                    Request::builder()
-                        .method("GET")
-                        .uri(&assign_uri)
-                        .body(Body::empty())
-                        .unwrap(),
-                )
-                .await
-                .unwrap();
-            assert_eq!(assignment_response.status(), StatusCode::OK);
-            let assignment_body = axum::body::to_bytes(assignment_response.into_body(), usize::MAX)
-                .await
-                .unwrap();
-            let assignment: PlayerAssignmentView =
-                serde_json::from_slice(&assignment_body).unwrap();
-            assignments.insert(*player_id, assignment);
+    #[tokio::test]
+    async fn custom_questions_keep_a_narrowed_selection() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let narrowed = vec![state.content().categories[0].clone()];
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            game.rules.question_categories = narrowed.clone();
+            game.custom_questions = (1..MAX_CUSTOM_QUESTIONS)
+                .map(|index| QuestionPrompt {
+                    id: format!("custom-{index}"),
+                    text: format!("Filler question {index}?"),
+                    categories: vec![CUSTOM_QUESTION_CATEGORY.to_owned()],
+                })
+                .collect();
         }
 
-        let (imposter_id, location_id) = assignments.iter().fold(
-            (None, None),
-            |(mut imposter, mut location), (player_id, assignment)| {
-                if assignment.is_imposter {
-                    imposter = Some(*player_id);
-                } else if location.is_none() {
-                    location = assignment.location_id;
-                }
-                (imposter, location)
-            },
+        let (status, _) = send_json(
+            &app,
+            "PATCH",
+            &format!("/api/games/{}/content/questions", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "questions": [
+                    { "text": "Which desk is yours?", "categories": ["Office Life"] },
+                    { "text": "which desk is yours?", "categories": ["Office Life"] },
+                    { "text": "Filler question 1?" }
+                ]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let games = state.games.read().await;
+        let game = &games[&created.code];
+        assert_eq!(game.custom_questions.len(), MAX_CUSTOM_QUESTIONS);
+        assert_eq!(game.rules.question_categories, narrowed);
+        let custom = game.custom_content.as_ref().unwrap();
+        assert!(Arc::ptr_eq(custom.base.as_ref().unwrap(), &state.content()));
+        assert_eq!(custom.questions.len(), MAX_CUSTOM_QUESTIONS);
+    }
+
+    #[tokio::test]
+    async fn location_tags_filter_custom_locations_too() {
+        let content = GameContent::load().expect("content should load");
+        let tag = content.location_tags()[0].clone();
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "location_tags": [tag.clone()] })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/content/locations", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "locations": [{ "name": "Break Room", "roles": ["Intern", "Manager"] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let games = state.games.read().await;
+        let pool = &games[&created.code].location_pool;
+        assert!(!pool.is_empty());
+        assert!(
+            pool.iter()
+                .all(|location| location.has_any_tag(std::slice::from_ref(&tag)))
         );
+    }
 
-        let imposter_id = imposter_id.expect("expected one imposter");
-        let location_id = location_id.expect("crew assignment should include location");
+    #[tokio::test]
+    async fn hosts_can_exclude_custom_locations() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
 
-        let locations_uri = format!("/api/games/{}/locations", code);
-        let locations_response = app
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/content/locations", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "locations": [{ "name": "Break Room", "roles": ["Intern", "Manager"] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let custom_id = serde_json::from_value::<CustomLocationsResponse>(body)
+            .unwrap()
+            .locations[0]
+            .id;
+
+        let rules_version = state.games.read().await[&created.code].rules_version;
+        let (status, body) = send_json(
+            &app,
+            "PATCH",
+            &format!("/api/games/{}", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "rules_version": rules_version,
+                "rules": { "excluded_location_ids": [custom_id] }
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let lobby: GameLobby = serde_json::from_value(body).unwrap();
+        assert_eq!(lobby.rules.excluded_location_ids, vec![custom_id]);
+    }
+
+    #[test]
+    fn admin_tokens_compare_by_every_byte() {
+        assert!(constant_time_eq(b"s3cret", b"s3cret"));
+        assert!(!constant_time_eq(b"s3cret", b"s3creT"));
+        assert!(!constant_time_eq(b"s3cret", b"s3cre"));
+        assert!(constant_time_eq(b"", b""));
+    }
+
+    #[tokio::test]
+    async fn spectators_are_counted_as_they_come_and_go() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob"]).await;
+        let mut events = state.games.read().await[&created.code].events.subscribe();
+
+        adjust_spectators(&state, &created.code, true).await;
+        adjust_spectators(&state, &created.code, true).await;
+        assert!(matches!(
+            events.try_recv(),
+            Ok(GameEvent::Spectators { count: 1 })
+        ));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(GameEvent::Spectators { count: 2 })
+        ));
+        let (_, body) = send_json(&app, "GET", &format!("/api/games/{}", created.code), None).await;
+        assert_eq!(body["spectator_count"], 2);
+
+        adjust_spectators(&state, &created.code, false).await;
+        assert!(matches!(
+            events.try_recv(),
+            Ok(GameEvent::Spectators { count: 1 })
+        ));
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn narrowing_runs_through_a_forced_vote() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let rules = json!({ "imposter_narrowing": true, "location_pool_size": 6 });
+        let (created, _) = lobby_with_players(&app, Some(rules), &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut games = state.games.write().await;
+        let game = games.get_mut(&created.code).unwrap();
+        let narrowing = game
+            .narrowing_timer
             .clone()
-            .oneshot(
-                Request::builder()
-                    .method("GET")
-                    .uri(&locations_uri)
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(locations_response.status(), StatusCode::OK);
-        let locations_body = axum::body::to_bytes(locations_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let location_list: LocationListResponse = serde_json::from_slice(&locations_body).unwrap();
-        assert!(!location_list.locations.is_empty());
-        let wrong_location_id = location_list
-            .locations
-            .iter()
-            .find(|option| option.id != location_id)
-            .map(|option| option.id)
-            .unwrap_or(location_id);
+            .expect("narrowing is scheduled");
+        game.current_round.as_mut().unwrap().forced_vote_deadline =
+            Some(Instant::now() + Duration::from_secs(30));
+        schedule_forced_vote_timer(&state, game);
+        assert!(game.round_timer.is_some());
+        assert!(game.narrowing_timer.is_some());
+        tokio::task::yield_now().await;
+        assert!(!narrowing.is_finished());
+
+        game.set_paused(true).unwrap();
+        assert!(game.narrowing_timer.is_none());
+        tokio::task::yield_now().await;
+        assert!(narrowing.is_finished());
+    }
 
-        assert_ne!(
-            location_id, wrong_location_id,
-            "need alternative location id"
+    #[tokio::test]
+    async fn content_catalogs_follow_the_requested_locale() {
+        let content = GameContent::load().expect("content should load");
+        let spanish = Arc::new(content.clone()).for_locale("es");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state);
+
+        let (status, body) =
+            send_json(&app, "GET", "/api/content/locations?locale=es-MX", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let catalog: LocationCatalogResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(catalog.locations[0].name, spanish.locations[0].name);
+
+        let (status, body) = send_json(&app, "GET", "/api/content/questions?locale=es", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let question = spanish.questions.iter().find(|q| q.id == "q001").unwrap();
+        assert!(
+            body["questions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|view| view["text"] == question.text.as_str())
         );
 
-        let guess_uri = format!("/api/games/{}/round/guess", code);
-        let guess_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(&guess_uri)
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        json!({
-                            "player_id": imposter_id,
-                            "location_id": wrong_location_id
-                        })
-                        .to_string(),
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let (status, body) = send_json(&app, "GET", "/api/content/locations?locale=xx", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "UNKNOWN_LOCALE");
+    }
 
-        assert_eq!(guess_response.status(), StatusCode::OK);
-        let guess_body = axum::body::to_bytes(guess_response.into_body(), usize::MAX)
-            .await
+    #[tokio::test]
+    async fn rematch_seats_the_host_device_not_the_leader() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, ids) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/leader", created.code),
+            Some(json!({ "host_token": created.host_token, "new_leader_id": ids[2] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let uri = format!("/api/games/{}/rematch", created.code);
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rematch: RematchResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(rematch.game.reconnect_token, created.reconnect_token);
+        assert_eq!(
+            rematch.player_ids[&created.player_id],
+            rematch.game.player_id
+        );
+
+        {
+            let mut games = state.games.write().await;
+            let game = games.get_mut(&created.code).unwrap();
+            game.host_player_id = Uuid::new_v4();
+            game.leader_id = Uuid::new_v4();
+        }
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &uri,
+            Some(json!({ "host_token": created.host_token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rematch: RematchResponse = serde_json::from_value(body).unwrap();
+        assert!(rematch.player_ids.contains_key(&created.player_id));
+    }
+
+    #[test]
+    fn bot_ballots_never_decide_a_majority_vote() {
+        let content = GameContent::load().expect("content should load");
+        let mut players = players_named(&["Alice", "Bob", "Cara"]);
+        for index in 0..5 {
+            let bot = Player::bot(format!("Bot {index}")).unwrap();
+            players.insert(bot.id, bot);
+        }
+        let id_of = |name: &str| players.values().find(|p| p.name == name).unwrap().id;
+        let (alice, bob, cara) = (id_of("Alice"), id_of("Bob"), id_of("Cara"));
+        let rules = GameRules {
+            require_majority_vote: true,
+            avoid_repeat_imposter: true,
+            allow_duplicate_roles: true,
+            ..GameRules::default()
+        }
+        .normalize(&content)
+        .unwrap();
+        let others: Vec<Uuid> = players.keys().copied().filter(|id| *id != alice).collect();
+        let location = content.locations[0].clone();
+        let mut round = RoundState::new(
+            1,
+            location,
+            &players,
+            &rules,
+            &content,
+            &others,
+            &mut thread_rng(),
+        )
+        .unwrap();
+        assert_eq!(round.imposter_ids, vec![alice]);
+        for bot_id in round.bot_ids.clone() {
+            round.votes.insert(bot_id, alice);
+        }
+
+        let pending = round
+            .resolve_guess(bob, GuessAction::AccusePlayer { accused_id: cara }, &rules)
             .unwrap();
-        let guess_result: GuessResponse = serde_json::from_slice(&guess_body).unwrap();
+        assert!(pending.is_none());
+        assert!(round.identified_imposters.is_empty());
+
+        let resolution = round
+            .resolve_guess(
+                cara,
+                GuessAction::AccusePlayer { accused_id: alice },
+                &rules,
+            )
+            .unwrap()
+            .expect("every human has voted");
+        assert!(matches!(
+            resolution.outcome,
+            RoundOutcome::VoteDeadlocked { .. }
+        ));
+    }
 
-        assert!(matches!(guess_result.resolution.winner, RoundWinner::Crew));
-        match guess_result.resolution.outcome {
-            RoundOutcome::ImposterFailedLocationGuess {
-                guessed_location_id,
-                actual_location_id,
-                ..
-            } => {
-                assert_eq!(guessed_location_id, wrong_location_id);
-                assert_eq!(actual_location_id, location_id);
-            }
-            other => panic!("unexpected outcome: {:?}", other),
+    #[tokio::test]
+    async fn skipped_turns_leave_the_question_cap_alone() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(
+            &app,
+            Some(json!({ "max_questions_per_round": 2 })),
+            &["Bob", "Cara"],
+        )
+        .await;
+        let host_body = json!({ "host_token": created.host_token });
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(host_body.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let skip_uri = format!("/api/games/{}/round/skip", created.code);
+        let mut round = None;
+        for _ in 0..2 {
+            let (status, body) = send_json(&app, "POST", &skip_uri, Some(host_body.clone())).await;
+            assert_eq!(status, StatusCode::OK);
+            round = Some(serde_json::from_value::<RoundPublicState>(body).unwrap());
         }
+        let round = round.unwrap();
+        assert_eq!(round.asked_questions.len(), 2);
+        assert_eq!(round.asked_total, 0);
+        assert_eq!(round.questions_remaining, Some(1));
+
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": round.current_turn_player_id,
+                "action_token":
+                    action_token(&state, &created.code, round.current_turn_player_id).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let drawn: NextQuestionResponse = serde_json::from_value(body).unwrap();
+        assert!(drawn.question.is_some());
+        assert_eq!(drawn.asked_total, 1);
+        assert!(drawn.forced_vote_deadline_ms.is_none());
+    }
 
-        let lobby_uri = format!("/api/games/{}", code);
-        let lobby_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("GET")
-                    .uri(&lobby_uri)
-                    .body(Body::empty())
-                    .unwrap(),
+    #[tokio::test]
+    async fn export_lists_each_resolved_round() {
+        async fn imposter_guesses(
+            app: &Router,
+            state: &SharedState,
+            code: &RoomCode,
+        ) -> (u32, Uuid, u32) {
+            let (round_number, impostor, location_id) = {
+                let games = state.games.read().await;
+                let round = games[code].current_round.as_ref().unwrap();
+                (round.round_number, round.imposter_ids[0], round.location.id)
+            };
+            let (status, _) = send_json(
+                app,
+                "POST",
+                &format!("/api/games/{}/round/guess", code),
+                Some(json!({
+                    "player_id": impostor,
+                    "action_token": action_token(state, code, impostor).await,
+                    "location_id": location_id,
+                })),
             )
-            .await
-            .unwrap();
-        assert_eq!(lobby_response.status(), StatusCode::OK);
-        let lobby_body = axum::body::to_bytes(lobby_response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let lobby: GameLobby = serde_json::from_slice(&lobby_body).unwrap();
-        assert_eq!(lobby.phase, GamePhase::AwaitingNextRound);
+            .await;
+            assert_eq!(status, StatusCode::OK);
+            (round_number, impostor, location_id)
+        }
 
-        for player in lobby.players {
-            if player.id == imposter_id {
-                assert_eq!(player.imposter_wins, 0);
-            } else {
-                assert_eq!(player.crew_wins, 1);
-            }
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, players) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let host_body = json!({ "host_token": created.host_token });
+        let (status, body) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/start", created.code),
+            Some(host_body.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let started: RoundPublicState = serde_json::from_value(body).unwrap();
+        let drawer = started.current_turn_player_id.unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/round/question", created.code),
+            Some(json!({
+                "player_id": drawer,
+                "action_token": action_token(&state, &created.code, drawer).await,
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let first = imposter_guesses(&app, &state, &created.code).await;
+
+        let next_uri = format!("/api/games/{}/round/next", created.code);
+        let (status, body) = send_json(&app, "POST", &next_uri, Some(host_body.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        let aborted: RoundPublicState = serde_json::from_value(body).unwrap();
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/abort", created.code),
+            Some(json!({ "host_token": created.host_token, "scope": "round" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_json(&app, "POST", &next_uri, Some(host_body)).await;
+        assert_eq!(status, StatusCode::OK);
+        let second = imposter_guesses(&app, &state, &created.code).await;
+
+        let (status, body) = send_json(
+            &app,
+            "GET",
+            &format!("/api/games/{}/export", created.code),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let export: GameExport = serde_json::from_value(body).unwrap();
+        assert_eq!(export.code, created.code);
+        assert_eq!(export.players.len(), players.len());
+        assert_eq!(export.rounds.len(), 2);
+        assert!(
+            export
+                .rounds
+                .iter()
+                .all(|round| round.round_number != aborted.round_number)
+        );
+        for (archive, (round_number, impostor, location_id)) in
+            export.rounds.iter().zip([first, second])
+        {
+            assert_eq!(archive.round_number, round_number);
+            assert_eq!(archive.imposter_ids, vec![impostor]);
+            assert_eq!(archive.location_id, location_id);
+            assert_eq!(archive.turn_order.len(), players.len());
+            assert!(matches!(archive.resolution.winner, RoundWinner::Imposter));
         }
+        let asked = &export.rounds[0].asked_questions;
+        assert_eq!(asked.len(), 1);
+        assert_eq!(asked[0].asked_by, drawer);
+        assert_eq!(
+            Some(&asked[0].id),
+            started.current_question.as_ref().map(|q| &q.id)
+        );
+        assert!(export.rounds[1].asked_questions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn custom_locations_land_only_when_the_rules_still_hold() {
+        let content = GameContent::load().expect("content should load");
+        let state = Arc::new(AppState::new(content));
+        let app = super::app_router(state.clone());
+
+        let (created, _) = lobby_with_players(&app, None, &["Bob", "Cara"]).await;
+        let version = state.games.read().await[&created.code].rules_version;
+        let (status, _) = send_json(
+            &app,
+            "POST",
+            &format!("/api/games/{}/content/locations", created.code),
+            Some(json!({
+                "host_token": created.host_token,
+                "locations": [{ "name": "Break Room", "roles": ["Intern", "Manager"] }]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let shared = state.content();
+        let mut games = state.games.write().await;
+        let game = games.get_mut(&created.code).unwrap();
+        assert_eq!(game.rules_version, version);
+        let locations = game.content(&shared).locations().count();
+        game.rules.question_categories = vec!["no-such-category".into()];
+        let added = game.add_custom_locations(
+            &shared,
+            vec![CustomLocationInput {
+                name: "Loading Dock".into(),
+                roles: vec!["Driver".into(), "Clerk".into()],
+            }],
+        );
+        assert!(matches!(
+            added,
+            Err(AppError::BadRequest(ErrorCode::UnknownCategory, _))
+        ));
+        assert_eq!(game.custom_locations.len(), 1);
+        assert_eq!(game.content(&shared).locations().count(), locations);
+        assert_eq!(game.rules_version, version);
     }
 }
@@ -6,9 +6,37 @@ export interface GameRules {
   allow_repeated_questions: boolean;
   location_pool_size: number;
   question_categories: string[];
-}
-
-export type GamePhase = "Lobby" | "InRound" | "AwaitingNextRound";
+  category_weights?: Record<string, number>;
+  public?: boolean;
+  allow_duplicate_roles?: boolean;
+  bot_fill?: number;
+  imposter_last_chance?: boolean;
+  idle_turn_grace_seconds?: number | null;
+  max_rounds?: number | null;
+  avoid_repeat_imposter?: boolean;
+  excluded_location_ids?: number[];
+  require_answer_before_next?: boolean;
+  max_questions_per_round?: number | null;
+  turn_order_mode?: "shuffled" | "join_order";
+  scoring?: ScoringRules;
+  restore_returning_players?: boolean;
+  imposter_narrowing?: boolean;
+  narrowing_interval_seconds?: number;
+  locale?: string;
+  min_questions_before_guess?: number;
+  gate_location_guess?: boolean;
+}
+
+export interface ScoringRules {
+  crew_accusation: number;
+  crew_location_miss: number;
+  crew_forfeit: number;
+  imposter_location_guess: number;
+  imposter_misdirection: number;
+  imposter_survival: number;
+}
+
+export type GamePhase = "Lobby" | "InRound" | "Reveal" | "AwaitingNextRound" | "Finished";
 export type RoundWinner = "Crew" | "Imposter";
 
 export type RoundOutcome =
@@ -45,6 +73,7 @@ export interface RoundResolution {
   winner: RoundWinner;
   outcome: RoundOutcome;
   ended_at_ms: number;
+  revealed_roles?: { player_id: string; role: string }[];
 }
 
 export interface RoundSummary {
@@ -64,6 +93,14 @@ export interface AskedQuestionView {
   categories: string[];
   asked_by: string;
   asked_at_ms: number;
+  skipped?: boolean;
+  answers?: AnswerView[];
+}
+
+export interface AnswerView {
+  player_id: string;
+  text: string;
+  at_ms: number;
 }
 
 export interface RoundPublicState {
@@ -72,8 +109,29 @@ export interface RoundPublicState {
   current_turn_player_id: string | null;
   current_question: QuestionView | null;
   asked_questions: AskedQuestionView[];
+  asked_total?: number;
+  questions_remaining?: number | null;
+  draw_counts?: { player_id: string; draws: number }[];
+  turns_taken?: Record<string, number>;
+  upcoming?: string[];
+  skip_question_votes?: string[];
   started_at_ms: number;
+  turn_deadline_ms?: number | null;
+  awaiting_answer_from?: string | null;
+  last_chance_deadline_ms?: number | null;
+  forced_vote_deadline_ms?: number | null;
+  paused?: boolean;
+  imposter_chosen_by_host?: boolean;
+  location_chosen_by_host?: boolean;
   resolution: RoundResolution | null;
+  reveal?: RoundReveal | null;
+}
+
+export interface RoundReveal {
+  imposter_ids: string[];
+  location_id: number;
+  location_name: string;
+  assignments: Record<string, PlayerAssignmentView>;
 }
 
 export type GameEvent =
@@ -81,6 +139,8 @@ export type GameEvent =
       type: "snapshot";
       lobby: GameLobby;
       round: RoundPublicState | null;
+      protocol_version?: number;
+      binary_encoding?: "gzip";
     }
   | {
       type: "lobby";
@@ -90,6 +150,68 @@ export type GameEvent =
       type: "round";
       round: RoundPublicState | null;
     }
+  | {
+      type: "kicked";
+      player_id: string;
+    }
+  | {
+      type: "question_drawn";
+      question: QuestionView;
+      asked_by: string;
+      next_turn_player_id: string;
+    }
+  | {
+      type: "chat";
+      player_id: string;
+      name: string;
+      text: string;
+      at_ms: number;
+    }
+  | {
+      type: "turn_changed";
+      current_turn_player_id: string;
+      round_number: number;
+    }
+  | {
+      type: "aborted";
+      scope: AbortScope;
+      reason: string | null;
+    }
+  | {
+      type: "vote_forced";
+      round_number: number;
+      deadline_ms: number;
+    }
+  | {
+      type: "rematch";
+      code: string;
+    }
+  | {
+      type: "closed";
+      reason: string;
+    }
+  | {
+      type: "presence";
+      connected: string[];
+    }
+  | {
+      type: "spectators";
+      count: number;
+    }
+  | {
+      type: "locations_narrowed";
+      round_number: number;
+    }
+  | {
+      type: "assignment";
+      assignment: PlayerAssignmentView;
+    }
+  | {
+      type: "error";
+      code: string;
+      message: string;
+      fatal: boolean;
+    }
   | {
       type: "pong";
     };
@@ -100,6 +222,7 @@ export interface PlayerAssignmentView {
   location_id: number | null;
   location_name: string | null;
   role: string | null;
+  candidate_locations?: LocationOption[] | null;
 }
 
 export interface PlayerSummary {
@@ -107,23 +230,45 @@ export interface PlayerSummary {
   name: string;
   crew_wins: number;
   imposter_wins: number;
+  points?: number;
+  last_seen_ms?: number | null;
+  connected?: boolean;
+  is_bot?: boolean;
 }
 
 export interface GameLobby {
   code: string;
   leader_id: string;
   rules: GameRules;
+  rules_version: number;
   players: PlayerSummary[];
   player_count: number;
+  spectator_count: number;
   created_at_ms: number;
   phase: GamePhase;
   last_round: RoundSummary | null;
   round_history: RoundSummary[];
+  total_rounds?: number;
+  final_scoreboard?: ScoreboardEntry[];
+}
+
+export interface ScoreboardEntry {
+  player_id: string;
+  name: string;
+  crew_wins: number;
+  imposter_wins: number;
+  total_wins: number;
+  rounds_played: number;
+  correct_accusations: number;
+  fastest_resolution_ms: number | null;
 }
 
 export interface CreateGamePayload {
   host_name: string;
   rules?: GameRules;
+  seed?: number;
+  session_id?: string;
+  reconnect_token?: string;
 }
 
 export interface CreateGameResponse {
@@ -131,15 +276,22 @@ export interface CreateGameResponse {
   host_token: string;
   leader_id: string;
   player_id: string;
+  reconnect_token: string;
+  action_token: string;
   rules: GameRules;
+  session_id?: string;
 }
 
 export interface JoinGamePayload {
   player_name: string;
+  reconnect_token?: string;
+  client_join_id?: string;
 }
 
 export interface JoinGameResponse {
   player_id: string;
+  reconnect_token: string;
+  action_token: string;
   code: string;
 }
 
@@ -149,13 +301,20 @@ export interface LocationOption {
 }
 
 export interface NextQuestionResponse {
-  question: QuestionView;
-  next_turn_player_id: string;
+  question: QuestionView | null;
+  next_turn_player_id: string | null;
   asked_total: number;
+  forced_vote_deadline_ms?: number;
+}
+
+export interface VoteTally {
+  accused_id: string;
+  votes: number;
 }
 
 export interface GuessResponse {
-  resolution: RoundResolution;
+  resolution: RoundResolution | null;
+  votes?: VoteTally[];
 }
 
 interface CategoriesResponse {
@@ -167,12 +326,14 @@ interface LocationListResponse {
 }
 
 interface ApiErrorBody {
+  code?: string;
   message?: string;
 }
 
 interface RequestError extends Error {
   status?: number;
   code?: "offline" | "network" | "http_error" | "not_found" | "conflict";
+  errorCode?: string;
 }
 
 async function request<T>(path: string, init: RequestInit): Promise<T> {
@@ -199,8 +360,10 @@ async function request<T>(path: string, init: RequestInit): Promise<T> {
   if (!response.ok) {
     let message = response.statusText || "Request failed";
     let bodyMessage: string | undefined;
+    let errorCode: string | undefined;
     try {
       const body = (await response.json()) as ApiErrorBody;
+      errorCode = body?.code;
       if (body?.message && body.message.trim().length) {
         message = body.message;
         bodyMessage = body.message;
@@ -210,6 +373,7 @@ async function request<T>(path: string, init: RequestInit): Promise<T> {
     }
     const error: RequestError = new Error(message);
     error.status = response.status;
+    error.errorCode = errorCode;
     if (response.status === 404) {
       error.code = "not_found";
       if (!bodyMessage) {
@@ -240,6 +404,41 @@ export async function createGame(payload: CreateGamePayload) {
   });
 }
 
+export interface PublicGameSummary {
+  code: string;
+  player_count: number;
+  max_players: number;
+  question_categories: string[];
+  created_at_ms: number;
+}
+
+export async function listPublicGames() {
+  const response = await request<{ games: PublicGameSummary[] }>("/api/games", {
+    method: "GET",
+  });
+  return response.games;
+}
+
+export interface SessionStanding {
+  name: string;
+  crew_wins: number;
+  imposter_wins: number;
+  total_wins: number;
+  rounds_played: number;
+}
+
+export interface SessionLeaderboard {
+  session_id: string;
+  games_played: number;
+  players: SessionStanding[];
+}
+
+export async function getSessionLeaderboard(sessionId: string) {
+  return request<SessionLeaderboard>(`/api/sessions/${sessionId}/leaderboard`, {
+    method: "GET",
+  });
+}
+
 export async function joinGame(code: string, payload: JoinGamePayload) {
   return request<JoinGameResponse>(`/api/games/${code}/join`, {
     method: "POST",
@@ -247,10 +446,15 @@ export async function joinGame(code: string, payload: JoinGamePayload) {
   });
 }
 
-export async function updateRules(code: string, hostToken: string, rules: GameRules) {
+export async function updateRules(
+  code: string,
+  hostToken: string,
+  rulesVersion: number,
+  rules: Partial<GameRules>,
+) {
   return request<GameLobby>(`/api/games/${code}`, {
     method: "PATCH",
-    body: JSON.stringify({ host_token: hostToken, rules }),
+    body: JSON.stringify({ host_token: hostToken, rules_version: rulesVersion, rules }),
   });
 }
 
@@ -266,10 +470,44 @@ export async function getRoundState(code: string) {
   });
 }
 
-export async function startGame(code: string, hostToken: string) {
+export interface CurrentQuestionResponse {
+  round_number: number;
+  question: QuestionView | null;
+  current_turn_player_id: string | null;
+}
+
+export async function getCurrentQuestion(code: string) {
+  return request<CurrentQuestionResponse>(`/api/games/${code}/round/question`, {
+    method: "GET",
+  });
+}
+
+export interface StartOverrides {
+  forcedImposterId?: string;
+  forcedLocationId?: number;
+}
+
+export async function startGame(code: string, hostToken: string, overrides: StartOverrides = {}) {
   return request<RoundPublicState>(`/api/games/${code}/start`, {
     method: "POST",
-    body: JSON.stringify({ host_token: hostToken }),
+    body: JSON.stringify({
+      host_token: hostToken,
+      forced_imposter_id: overrides.forcedImposterId,
+      forced_location_id: overrides.forcedLocationId,
+    }),
+  });
+}
+
+export interface CanStartReport {
+  can_start: boolean;
+  reasons: string[];
+  min_players: number;
+  current_players: number;
+}
+
+export async function canStartGame(code: string) {
+  return request<CanStartReport>(`/api/games/${code}/can-start`, {
+    method: "GET",
   });
 }
 
@@ -277,7 +515,7 @@ export type AbortScope = "round" | "game";
 
 export async function abortGame(
   code: string,
-  payload: { host_token: string; scope?: AbortScope },
+  payload: { host_token: string; scope?: AbortScope; reason?: string },
 ) {
   return request<GameLobby>(`/api/games/${code}/abort`, {
     method: "POST",
@@ -285,6 +523,44 @@ export async function abortGame(
   });
 }
 
+export interface TransferHostResponse {
+  /** Omitted when hosting moved to `new_host_id`; that player calls `claimHost`. */
+  host_token?: string;
+  leader_id: string;
+}
+
+export async function transferHost(
+  code: string,
+  payload: { host_token: string; new_host_id?: string },
+) {
+  return request<TransferHostResponse>(`/api/games/${code}/host/transfer`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
+/** Fetches the host token for a player who was made host by `transferHost`. */
+export async function claimHost(code: string, player: PlayerCredentials) {
+  return request<TransferHostResponse>(`/api/games/${code}/host/claim`, {
+    method: "POST",
+    body: JSON.stringify(player),
+  });
+}
+
+export interface RematchResponse extends CreateGameResponse {
+  player_ids: Record<string, string>;
+}
+
+export async function rematch(
+  code: string,
+  payload: { host_token: string; invite_players?: boolean },
+) {
+  return request<RematchResponse>(`/api/games/${code}/rematch`, {
+    method: "POST",
+    body: JSON.stringify(payload),
+  });
+}
+
 export async function startNextRound(code: string, hostToken: string) {
   return request<RoundPublicState>(`/api/games/${code}/round/next`, {
     method: "POST",
@@ -292,16 +568,51 @@ export async function startNextRound(code: string, hostToken: string) {
   });
 }
 
-export async function drawNextQuestion(code: string, playerId: string) {
+export async function setRoundPaused(code: string, hostToken: string, paused: boolean) {
+  return request<RoundPublicState>(
+    `/api/games/${code}/round/${paused ? "pause" : "resume"}`,
+    {
+      method: "POST",
+      body: JSON.stringify({ host_token: hostToken }),
+    },
+  );
+}
+
+/**
+ * Identifies the acting player. `player_id` is public, so every player action
+ * also carries the private `action_token` issued when they joined.
+ */
+export interface PlayerCredentials {
+  player_id: string;
+  action_token: string;
+}
+
+/** Resets the lobby's idle timer; `expires_at_ms` is null when lobbies never expire. */
+export async function keepLobbyAlive(code: string, player: PlayerCredentials) {
+  return request<{ expires_at_ms: number | null }>(`/api/games/${code}/keepalive`, {
+    method: "POST",
+    body: JSON.stringify(player),
+  });
+}
+
+export async function voteSkipQuestion(code: string, player: PlayerCredentials) {
+  return request<RoundPublicState>(`/api/games/${code}/round/skip-question`, {
+    method: "POST",
+    body: JSON.stringify(player),
+  });
+}
+
+export async function drawNextQuestion(code: string, player: PlayerCredentials) {
   return request<NextQuestionResponse>(`/api/games/${code}/round/question`, {
     method: "POST",
-    body: JSON.stringify({ player_id: playerId }),
+    body: JSON.stringify(player),
   });
 }
 
-type GuessPayload =
-  | { player_id: string; accused_player_id: string }
-  | { player_id: string; location_id: number };
+type GuessPayload = PlayerCredentials &
+  ({ accused_player_id: string } | { location_id: number }) & {
+    idempotency_key?: string;
+  };
 
 export async function submitGuess(code: string, payload: GuessPayload) {
   return request<GuessResponse>(`/api/games/${code}/round/guess`, {
@@ -310,13 +621,39 @@ export async function submitGuess(code: string, payload: GuessPayload) {
   });
 }
 
-export async function getAssignment(code: string, playerId: string) {
+export interface PlayerView {
+  lobby: GameLobby;
+  round: RoundPublicState | null;
+  assignment: PlayerAssignmentView | null;
+}
+
+export async function getPlayerView(code: string, player: PlayerCredentials) {
+  const query = new URLSearchParams({ ...player });
+  return request<PlayerView>(`/api/games/${code}/me?${query}`, {
+    method: "GET",
+  });
+}
+
+export async function getAssignment(code: string, reconnectToken: string) {
+  const query = new URLSearchParams({ token: reconnectToken });
   return request<PlayerAssignmentView>(
-    `/api/games/${code}/round/assignment/${playerId}`,
+    `/api/games/${code}/round/my-assignment?${query}`,
     { method: "GET" },
   );
 }
 
+export interface AssignmentsResponse {
+  round_number: number;
+  assignments: Record<string, PlayerAssignmentView>;
+}
+
+export async function getAllAssignments(code: string, hostToken: string) {
+  return request<AssignmentsResponse>(`/api/games/${code}/round/assignments`, {
+    method: "POST",
+    body: JSON.stringify({ host_token: hostToken }),
+  });
+}
+
 export async function getLocations(code: string) {
   const response = await request<LocationListResponse>(
     `/api/games/${code}/locations`,
@@ -332,7 +669,76 @@ export async function getCategories() {
   return response.categories;
 }
 
-export function buildGameStreamUrl(code: string) {
+export async function getQuestionCatalog(categories: string[] = []) {
+  const params = new URLSearchParams();
+  for (const category of categories) {
+    params.append("category", category);
+  }
+  const query = params.toString();
+  const response = await request<{ questions: QuestionView[] }>(
+    `/api/content/questions${query ? `?${query}` : ""}`,
+    { method: "GET" },
+  );
+  return response.questions;
+}
+
+export async function reportQuestion(questionId: string, reason: string) {
+  return request<void>(`/api/content/questions/${encodeURIComponent(questionId)}/report`, {
+    method: "POST",
+    body: JSON.stringify({ reason }),
+  });
+}
+
+export interface ContentStats {
+  question_count: number;
+  location_count: number;
+  max_player_capacity: number;
+  categories: { category: string; questions: number }[];
+  locations: { id: number; name: string; roles: number; supports_max_players: boolean }[];
+}
+
+export async function getContentStats() {
+  return request<ContentStats>(`/api/content/stats`, { method: "GET" });
+}
+
+/**
+ * Realtime subprotocols offered when opening the game stream, newest first.
+ * `imposter.v2` lets the server gzip large snapshots into binary frames, so
+ * it is only offered where the browser can decompress them.
+ */
+export const GAME_STREAM_PROTOCOLS =
+  typeof DecompressionStream !== "undefined"
+    ? ["imposter.v2", "imposter.v1"]
+    : ["imposter.v1"];
+
+/** Close code the server uses when it supports none of `GAME_STREAM_PROTOCOLS`. */
+export const UNSUPPORTED_PROTOCOL_CLOSE_CODE = 4406;
+
+/** Close code the server uses once the lobby behind a stream no longer exists. */
+export const GAME_GONE_CLOSE_CODE = 4404;
+
+/** Turns a realtime frame into JSON text, inflating gzip binary frames. */
+export async function decodeStreamFrame(data: string | ArrayBuffer) {
+  if (typeof data === "string") {
+    return data;
+  }
+  const stream = new Blob([data])
+    .stream()
+    .pipeThrough(new DecompressionStream("gzip"));
+  return new Response(stream).text();
+}
+
+/**
+ * Server-sent-events fallback for networks that block WebSocket upgrades.
+ * Read-only: each `GameEvent` arrives as an SSE event named after its `type`.
+ */
+export function buildGameEventsUrl(code: string, player?: PlayerCredentials) {
+  const url = new URL(buildGameStreamUrl(code, player).replace(/^ws/, "http"));
+  url.pathname = `/api/games/${code}/events`;
+  return url.toString();
+}
+
+export function buildGameStreamUrl(code: string, player?: PlayerCredentials) {
   const base =
     API_BASE && API_BASE.length
       ? API_BASE
@@ -343,6 +749,10 @@ export function buildGameStreamUrl(code: string) {
     throw new Error("Unable to resolve API base url for realtime stream");
   }
   const url = new URL(`/api/games/${code}/stream`, base);
+  if (player) {
+    url.searchParams.set("player_id", player.player_id);
+    url.searchParams.set("action_token", player.action_token);
+  }
   if (url.protocol === "https:") {
     url.protocol = "wss:";
   } else if (url.protocol === "http:") {
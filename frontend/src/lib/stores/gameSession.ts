@@ -1,7 +1,11 @@
 import { derived, writable } from "svelte/store";
 import {
   abortGame,
+  GAME_GONE_CLOSE_CODE,
+  GAME_STREAM_PROTOCOLS,
+  UNSUPPORTED_PROTOCOL_CLOSE_CODE,
   buildGameStreamUrl,
+  decodeStreamFrame,
   createGame,
   drawNextQuestion,
   getAssignment,
@@ -23,6 +27,7 @@ import {
   type LocationOption,
   type NextQuestionResponse,
   type PlayerAssignmentView,
+  type PlayerCredentials,
   type PlayerSummary,
   type RoundPublicState,
   type RoundSummary,
@@ -35,6 +40,10 @@ type NoticeType = "success" | "error" | "info";
 export type Session = {
   code: string;
   playerId: string;
+  /** Private key for this player's secret role; never shared with others. */
+  reconnectToken?: string;
+  /** Private proof of identity sent with every player action. */
+  actionToken?: string;
   hostToken?: string;
 };
 
@@ -283,6 +292,22 @@ export const createGameSessionStore = () => {
       case "round":
         applyRoundUpdate(event.round ?? null);
         break;
+      case "spectators":
+        updateState((state) => ({
+          ...state,
+          lobby: state.lobby ? { ...state.lobby, spectator_count: event.count } : null,
+        }));
+        break;
+      case "closed":
+        updateState((state) => ({ ...state, lastError: event.reason }));
+        break;
+      case "error":
+        if (event.fatal) {
+          updateState((state) => ({ ...state, lastError: event.message }));
+        } else {
+          updateRealtimeStatus(currentState.realtimeConnected, event.message);
+        }
+        break;
       case "pong":
       default:
         break;
@@ -298,7 +323,13 @@ export const createGameSessionStore = () => {
 
     let url: string;
     try {
-      url = buildGameStreamUrl(session.code);
+      // Sessions saved before action tokens existed follow along anonymously.
+      url = buildGameStreamUrl(
+        session.code,
+        session.actionToken
+          ? { player_id: session.playerId, action_token: session.actionToken }
+          : undefined,
+      );
     } catch (err) {
       updateRealtimeStatus(false, errorMessage(err));
       return;
@@ -306,7 +337,8 @@ export const createGameSessionStore = () => {
 
     manualDisconnect = false;
     try {
-      realtime = new WebSocket(url);
+      realtime = new WebSocket(url, GAME_STREAM_PROTOCOLS);
+      realtime.binaryType = "arraybuffer";
     } catch (err) {
       updateRealtimeStatus(false, errorMessage(err));
       scheduleReconnect(errorMessage(err));
@@ -330,16 +362,22 @@ export const createGameSessionStore = () => {
       });
     };
 
+    // Binary frames decode asynchronously, so chain every frame to keep
+    // events in the order the server sent them.
+    let frames = Promise.resolve();
     realtime.onmessage = (event) => {
-      if (typeof event.data !== "string") {
+      if (typeof event.data !== "string" && !(event.data instanceof ArrayBuffer)) {
         return;
       }
-      try {
-        const data = JSON.parse(event.data) as GameEvent;
-        handleRealtimeEvent(data);
-      } catch (err) {
-        updateRealtimeStatus(currentState.realtimeConnected, errorMessage(err));
-      }
+      const frame: string | ArrayBuffer = event.data;
+      frames = frames.then(async () => {
+        try {
+          const data = JSON.parse(await decodeStreamFrame(frame)) as GameEvent;
+          handleRealtimeEvent(data);
+        } catch (err) {
+          updateRealtimeStatus(currentState.realtimeConnected, errorMessage(err));
+        }
+      });
     };
 
     realtime.onerror = () => {
@@ -351,6 +389,16 @@ export const createGameSessionStore = () => {
       stopRealtimeHealthCheck();
       const reason = event.reason || null;
       updateRealtimeStatus(false, reason);
+      if (event.code === UNSUPPORTED_PROTOCOL_CLOSE_CODE) {
+        // Retrying cannot help until the client is updated; keep polling.
+        startFallbackPolling();
+        return;
+      }
+      if (event.code === GAME_GONE_CLOSE_CODE) {
+        // The lobby is gone; neither reconnecting nor polling can bring it back.
+        reconnectAttempts = 0;
+        return;
+      }
       if (manualDisconnect) {
         manualDisconnect = false;
         reconnectAttempts = 0;
@@ -408,6 +456,13 @@ export const createGameSessionStore = () => {
     }
   };
 
+  const playerCredentials = (session: Session): PlayerCredentials => {
+    if (!session.actionToken) {
+      throw new Error("Rejoin the lobby to play from this device");
+    }
+    return { player_id: session.playerId, action_token: session.actionToken };
+  };
+
   const getActiveSession = () => {
     const session = currentState.session ?? loadStoredSession();
     if (!session) {
@@ -633,6 +688,8 @@ export const createGameSessionStore = () => {
       const session: Session = {
         code: response.code,
         playerId: response.player_id,
+        reconnectToken: response.reconnect_token,
+        actionToken: response.action_token,
         hostToken: response.host_token,
       };
       saveStoredSession(session);
@@ -659,6 +716,8 @@ export const createGameSessionStore = () => {
       const session: Session = {
         code: response.code,
         playerId: response.player_id,
+        reconnectToken: response.reconnect_token,
+        actionToken: response.action_token,
       };
       saveStoredSession(session);
       updateState((state) => ({
@@ -698,7 +757,8 @@ export const createGameSessionStore = () => {
       throw new Error("Host token required to update rules");
     }
     return withAction(async () => {
-      const lobby = await updateRules(session.code, hostToken, rules);
+      const rulesVersion = currentState.lobby?.rules_version ?? 0;
+      const lobby = await updateRules(session.code, hostToken, rulesVersion, rules);
       updateState((state) => ({ ...state, lobby }));
       pushToast("success", "Rules updated");
       return lobby;
@@ -769,7 +829,10 @@ export const createGameSessionStore = () => {
   const fetchAssignment = async () => {
     const session = getActiveSession();
     return withAction(async () => {
-      const assignment = await getAssignment(session.code, session.playerId);
+      if (!session.reconnectToken) {
+        throw new Error("Rejoin the lobby to view your role on this device");
+      }
+      const assignment = await getAssignment(session.code, session.reconnectToken);
       updateState((state) => ({ ...state, assignment }));
       return assignment;
     });
@@ -789,7 +852,7 @@ export const createGameSessionStore = () => {
     return withAction(async () => {
       const response: NextQuestionResponse = await drawNextQuestion(
         session.code,
-        session.playerId,
+        playerCredentials(session),
       );
       if (!currentState.realtimeConnected) {
         await refreshRound({ silent: true }).catch(() => {
@@ -806,12 +869,12 @@ export const createGameSessionStore = () => {
       let result: GuessResponse | null = null;
       if (payload.accusedId) {
         result = await submitGuess(session.code, {
-          player_id: session.playerId,
+          ...playerCredentials(session),
           accused_player_id: payload.accusedId,
         });
       } else if (payload.locationId) {
         result = await submitGuess(session.code, {
-          player_id: session.playerId,
+          ...playerCredentials(session),
           location_id: payload.locationId,
         });
       } else {